@@ -0,0 +1,77 @@
+//! Example demonstrating border transitions.
+//!
+//! Hover over the buttons to animate their borders and outlines.
+
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .add_system(animate_on_hover)
+        .run();
+}
+
+#[derive(Component)]
+struct HoverEasing(Easing);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                flex_basis: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceAround,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::BLACK),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for easing in [Easing::CubicOut, Easing::ElasticOut, Easing::BounceOut] {
+                parent.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(120.), Val::Px(120.)),
+                            border: UiRect::all(Val::Px(4.)),
+                            ..Default::default()
+                        },
+                        background_color: Color::NAVY.into(),
+                        ..Default::default()
+                    },
+                    BorderColor(Color::GRAY),
+                    Outline::all(Color::NONE, Val::Px(0.)),
+                    HoverEasing(easing),
+                ));
+            }
+        });
+}
+
+fn animate_on_hover(
+    mut commands: Commands,
+    interaction_query: Query<(Entity, &Interaction, &HoverEasing), Changed<Interaction>>,
+) {
+    for (entity, interaction, HoverEasing(easing)) in interaction_query.iter() {
+        let (border, color, outline) = match interaction {
+            Interaction::None => (4., Color::GRAY, 0.),
+            _ => (12., Color::GOLD, 6.),
+        };
+        commands.entity(entity).insert(
+            BorderAnimation::new(
+                BorderProperty::BorderThickness(UiRect::all(Val::Px(border))),
+                0.6,
+                *easing,
+            )
+            .with(BorderProperty::BorderColor(color), 0.6, *easing)
+            .with(
+                BorderProperty::OutlineThickness(UiRect::all(Val::Px(outline))),
+                0.6,
+                *easing,
+            )
+            .with(BorderProperty::OutlineColor(color), 0.3, Easing::Linear),
+        );
+    }
+}
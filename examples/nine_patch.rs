@@ -0,0 +1,92 @@
+//! Example demonstrating nine-patch frames
+
+use bevy::prelude::*;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .run();
+}
+
+/// A 24x24 frame image with 8 pixel beveled edges, generated so the example doesn't need any assets.
+fn frame_image() -> Image {
+    let mut data = Vec::with_capacity(24 * 24 * 4);
+    for y in 0..24 {
+        for x in 0..24 {
+            let edge_distance = x.min(y).min(23 - x).min(23 - y);
+            let value = match edge_distance {
+                0 => 40,
+                1..=2 => 230,
+                3..=6 => 170,
+                7 => 60,
+                _ => 110,
+            };
+            // Stripe the edges so tiling is visible
+            let value = if (8..16).contains(&x) != (8..16).contains(&y) && (x + y) % 4 == 0 {
+                value - 30
+            } else {
+                value
+            };
+            data.extend_from_slice(&[value, value, value, 255]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: 24,
+            height: 24,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.spawn(Camera2dBundle::default());
+    let image = images.add(frame_image());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                flex_basis: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceAround,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::BLACK),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (tile, color, size) in [
+                (
+                    TileMode::Stretch,
+                    Color::WHITE,
+                    Size::new(Val::Px(200.), Val::Px(120.)),
+                ),
+                (
+                    TileMode::Repeat,
+                    Color::GOLD,
+                    Size::new(Val::Px(150.), Val::Px(250.)),
+                ),
+                (
+                    TileMode::Round,
+                    Color::CYAN,
+                    Size::new(Val::Px(250.), Val::Px(180.)),
+                ),
+            ] {
+                let nine_patch = NinePatch {
+                    color,
+                    ..NinePatch::new(image.clone(), SliceInsets::all(8.))
+                        .with_scale(2.)
+                        .with_tile(tile)
+                };
+                parent.spawn(FrameBundle::new(nine_patch, size));
+            }
+        });
+}
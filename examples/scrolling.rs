@@ -0,0 +1,142 @@
+//! Example showing borders and outlines inside a scrolling container.
+//!
+//! Scroll the list with the mouse wheel. Borders and outlines move with their nodes and are clipped by the
+//! container, and the hover highlight slides between items. The top and bottom of the frame light up while there are
+//! more items to scroll to in that direction.
+
+use bevy::input::mouse::MouseScrollUnit;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .add_systems((scroll_list, highlight_hovered))
+        .run();
+}
+
+#[derive(Component, Default)]
+struct ScrollingList {
+    position: f32,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn(SlidingOutline::new(3., Color::GOLD));
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::width(Val::Percent(100.)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            background_color: Color::BLACK.into(),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            // Bevy clips children to the border box of an `Overflow::Hidden` node, so the
+            // viewport is nested inside the bordered frame to keep items from covering the border
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(300.), Val::Percent(60.)),
+                            border: UiRect::all(Val::Px(6.)),
+                            ..Default::default()
+                        },
+                        background_color: Color::rgb(0.1, 0.1, 0.15).into(),
+                        ..Default::default()
+                    },
+                    BorderColor(Color::SILVER),
+                    ScrollIndicatorBorder::new(Color::GOLD),
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                flex_grow: 1.,
+                                overflow: Overflow::Hidden,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            // The moving list
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Column,
+                                            flex_grow: 1.,
+                                            max_size: Size::UNDEFINED,
+                                            padding: UiRect::all(Val::Px(12.)),
+                                            gap: Size::height(Val::Px(12.)),
+                                            ..Default::default()
+                                        },
+                                        ..Default::default()
+                                    },
+                                    ScrollingList::default(),
+                                ))
+                                .with_children(|parent| {
+                                    for i in 0..20 {
+                                        let hue = i as f32 * 18.;
+                                        parent.spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    size: Size::height(Val::Px(40.)),
+                                                    flex_shrink: 0.,
+                                                    border: UiRect::all(Val::Px(3.)),
+                                                    ..Default::default()
+                                                },
+                                                background_color: Color::hsl(hue, 0.5, 0.25).into(),
+                                                ..Default::default()
+                                            },
+                                            BorderColor(Color::hsl(hue, 0.8, 0.6)),
+                                            Outline::all(
+                                                Color::hsla(hue, 0.8, 0.6, 0.3),
+                                                Val::Px(4.),
+                                            ),
+                                        ));
+                                    }
+                                });
+                        });
+                });
+        });
+}
+
+fn scroll_list(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut list_query: Query<(&mut ScrollingList, &mut Style, &Parent, &Node)>,
+    node_query: Query<&Node>,
+) {
+    for mouse_wheel_event in mouse_wheel_events.iter() {
+        for (mut list, mut style, parent, list_node) in list_query.iter_mut() {
+            let dy = match mouse_wheel_event.unit {
+                MouseScrollUnit::Line => mouse_wheel_event.y * 20.,
+                MouseScrollUnit::Pixel => mouse_wheel_event.y,
+            };
+            let container_height = node_query.get(parent.get()).unwrap().size().y;
+            let max_scroll = (list_node.size().y - container_height).max(0.);
+            list.position = (list.position + dy).clamp(-max_scroll, 0.);
+            style.position.top = Val::Px(list.position);
+        }
+    }
+}
+
+fn highlight_hovered(
+    interaction_query: Query<(Entity, &Interaction), Changed<Interaction>>,
+    mut sliding_query: Query<&mut SlidingOutline>,
+) {
+    for (entity, interaction) in interaction_query.iter() {
+        if *interaction == Interaction::Hovered {
+            for mut sliding_outline in sliding_query.iter_mut() {
+                sliding_outline.target = Some(entity);
+            }
+        }
+    }
+}
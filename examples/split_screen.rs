@@ -0,0 +1,135 @@
+//! Example showing bordered HUDs for a split-screen game
+//!
+//! The world is drawn by two cameras, each filling half of the window. Bevy 0.10 draws the whole UI on every camera
+//! that shows UI, scaled to the camera's viewport, so the player cameras don't show UI and a third camera draws it
+//! over the whole window. The UI has a root node for each player's half of the screen, framing that player's view
+//! with a border and holding their own outlined health panel.
+
+use bevy::core_pipeline::clear_color::ClearColorConfig;
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::render::view::RenderLayers;
+use bevy::window::PrimaryWindow;
+use bevy::window::WindowResized;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .add_system(set_camera_viewports)
+        .run();
+}
+
+#[derive(Component)]
+struct PlayerCamera(u32);
+
+fn setup(mut commands: Commands) {
+    for (i, color) in [Color::ORANGE_RED, Color::TEAL].into_iter().enumerate() {
+        commands.spawn(SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(80.)),
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(-100. + 200. * i as f32, 0., 0.),
+            ..Default::default()
+        });
+    }
+
+    for player in 0..2 {
+        commands.spawn((
+            Camera2dBundle {
+                camera: Camera {
+                    order: player as isize,
+                    ..Default::default()
+                },
+                camera_2d: Camera2d {
+                    // Clearing clears the whole window, so only the first camera clears
+                    clear_color: if player == 0 {
+                        ClearColorConfig::Default
+                    } else {
+                        ClearColorConfig::None
+                    },
+                },
+                ..Default::default()
+            },
+            UiCameraConfig { show_ui: false },
+            PlayerCamera(player),
+        ));
+    }
+    commands.spawn((
+        Camera2dBundle {
+            camera: Camera {
+                order: 2,
+                ..Default::default()
+            },
+            camera_2d: Camera2d {
+                // Draw over the players' views
+                clear_color: ClearColorConfig::None,
+            },
+            ..Default::default()
+        },
+        // Keep the sprites out of the UI camera, Bevy 0.10's UI ignores render layers
+        RenderLayers::layer(1),
+    ));
+
+    for (i, color) in [Color::ORANGE_RED, Color::TEAL].into_iter().enumerate() {
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        position: UiRect::left(Val::Percent(50. * i as f32)),
+                        size: Size::new(Val::Percent(50.), Val::Percent(100.)),
+                        border: UiRect::all(Val::Px(4.)),
+                        padding: UiRect::all(Val::Px(12.)),
+                        align_items: AlignItems::FlexEnd,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                BorderColor(color),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(160.), Val::Px(24.)),
+                            border: UiRect::all(Val::Px(2.)),
+                            ..Default::default()
+                        },
+                        background_color: Color::DARK_GRAY.into(),
+                        ..Default::default()
+                    },
+                    BorderColor(Color::WHITE),
+                    Outline::all(color, Val::Px(2.)),
+                ));
+            });
+    }
+}
+
+/// Keeps each camera's viewport on its half of the window.
+fn set_camera_viewports(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut resize_events: EventReader<WindowResized>,
+    mut camera_query: Query<(&PlayerCamera, &mut Camera)>,
+    mut initialized: Local<bool>,
+) {
+    if resize_events.iter().last().is_none() && *initialized {
+        return;
+    }
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    *initialized = true;
+    let half_width = window.physical_width() / 2;
+    for (player, mut camera) in camera_query.iter_mut() {
+        camera.viewport = Some(Viewport {
+            physical_position: UVec2::new(player.0 * half_width, 0),
+            physical_size: UVec2::new(half_width, window.physical_height()),
+            ..Default::default()
+        });
+    }
+}
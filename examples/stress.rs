@@ -13,7 +13,7 @@ fn main() {
             ..Default::default()
         }))
         .add_plugin(LogDiagnosticsPlugin::default())
-        .add_plugin(FrameTimeDiagnosticsPlugin::default())
+        .add_plugin(FrameTimeDiagnosticsPlugin)
         .add_plugin(BordersPlugin)
         .add_startup_system(spawn_example)
         .run();
@@ -37,19 +37,23 @@ fn spawn_example(mut commands: Commands) {
         })
         .id();
 
+    // `cargo run --example stress -- --instanced` draws the cell borders with a single instanced draw call
+    let instanced = std::env::args().any(|arg| arg == "--instanced");
     for _ in 0..10_000 {
-        let child_id = commands
-            .spawn(BorderedNodeBundle {
-                style: Style {
-                    size: Size::new(Val::Px(5.), Val::Px(5.)),
-                    border: UiRect::all(Val::Px(1.)),
-                    ..Default::default()
-                },
-                background_color: Color::NAVY.into(),
-                border_color: Color::YELLOW.into(),
+        let mut child = commands.spawn(BorderedNodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(5.), Val::Px(5.)),
+                border: UiRect::all(Val::Px(1.)),
                 ..Default::default()
-            })
-            .id();
+            },
+            background_color: Color::NAVY.into(),
+            border_color: Color::YELLOW.into(),
+            ..Default::default()
+        });
+        if instanced {
+            child.insert(InstancedBorder);
+        }
+        let child_id = child.id();
         commands.entity(container_id).add_child(child_id);
     }
 }
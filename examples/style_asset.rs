@@ -0,0 +1,48 @@
+//! Example demonstrating border styles loaded from an asset file.
+//!
+//! Edit `assets/panel.border.ron` while the example is running to see the changes live.
+
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(AssetPlugin {
+            watch_for_changes: true,
+            ..Default::default()
+        }))
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let panel_style: Handle<BorderStyleAsset> = asset_server.load("panel.border.ron");
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                flex_basis: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceAround,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::BLACK),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for _ in 0..3 {
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(150.), Val::Px(150.)),
+                            ..Default::default()
+                        },
+                        background_color: Color::DARK_GRAY.into(),
+                        ..Default::default()
+                    },
+                    StyleHandle(panel_style.clone()),
+                ));
+            }
+        });
+}
@@ -0,0 +1,76 @@
+//! Example demonstrating textured border fills
+
+use bevy::prelude::*;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .run();
+}
+
+/// A 16x8 image with alternating light and dark stripes, generated so the example doesn't need any assets.
+fn stripes_image() -> Image {
+    let mut data = Vec::with_capacity(16 * 8 * 4);
+    for _ in 0..8 {
+        for x in 0..16 {
+            let value = if x < 8 { 255 } else { 96 };
+            data.extend_from_slice(&[value, value, value, 255]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: 16,
+            height: 8,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.spawn(Camera2dBundle::default());
+    let image = images.add(stripes_image());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                flex_basis: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceAround,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::BLACK),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (tile, color, orientation) in [
+                (TileMode::Stretch, Color::ORANGE, FillTransform::default()),
+                (TileMode::Repeat, Color::WHITE, FillTransform::default()),
+                (TileMode::Round, Color::CYAN, FillTransform::default()),
+                // The stripes are turned to run across every edge
+                (TileMode::Round, Color::LIME_GREEN, FillTransform::outward()),
+            ] {
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(150.), Val::Px(110.)),
+                            border: UiRect::all(Val::Px(12.)),
+                            ..Default::default()
+                        },
+                        background_color: Color::NAVY.into(),
+                        ..Default::default()
+                    },
+                    BorderColor(color),
+                    BorderFill::texture(image.clone(), tile),
+                    orientation,
+                ));
+            }
+        });
+}
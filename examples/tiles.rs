@@ -30,60 +30,9 @@ fn setup(mut commands: Commands) {
         .id();
 
     // all the different combinations of border edges
-    let borders = [
-        UiRect::default(),
-        UiRect::all(Val::Px(10.)),
-        UiRect::left(Val::Px(10.)),
-        UiRect::right(Val::Px(10.)),
-        UiRect::top(Val::Px(10.)),
-        UiRect::bottom(Val::Px(10.)),
-        UiRect::horizontal(Val::Px(10.)),
-        UiRect::vertical(Val::Px(10.)),
-        UiRect {
-            left: Val::Px(10.),
-            top: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            left: Val::Px(10.),
-            bottom: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            right: Val::Px(10.),
-            top: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            right: Val::Px(10.),
-            bottom: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            right: Val::Px(10.),
-            top: Val::Px(10.),
-            bottom: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            left: Val::Px(10.),
-            top: Val::Px(10.),
-            bottom: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            left: Val::Px(10.),
-            right: Val::Px(10.),
-            top: Val::Px(10.),
-            ..Default::default()
-        },
-        UiRect {
-            left: Val::Px(10.),
-            right: Val::Px(10.),
-            bottom: Val::Px(10.),
-            ..Default::default()
-        },
-    ];
+    let borders: Vec<UiRect> = (0..16)
+        .map(|bits| Sides::from_bits_truncate(bits).rect(Val::Px(10.)))
+        .collect();
 
     for i in 0..64 {
         let inner_spot = commands
@@ -0,0 +1,59 @@
+//! Example for running in a browser
+//!
+//! Build it for the web with `cargo run --example wasm --target wasm32-unknown-unknown` using
+//! `wasm-server-runner` as the target's runner, or with `wasm-bindgen`. The window fills the canvas's parent
+//! element, and the bordered panels are laid out with percentages so they follow the page size.
+
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "bevy_ui_borders".to_string(),
+                canvas: Some("#bevy".to_string()),
+                fit_canvas_to_parent: true,
+                // Let the browser handle keyboard shortcuts like reloading the page
+                prevent_default_event_handling: false,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.), Val::Percent(100.)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..Default::default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.12).into(),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (color, radius) in [(Color::ORANGE, 0.), (Color::CYAN, 16.), (Color::PINK, 40.)] {
+                parent.spawn((
+                    BorderedNodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Percent(20.), Val::Percent(30.)),
+                            border: UiRect::all(Val::Px(6.)),
+                            ..Default::default()
+                        },
+                        background_color: Color::DARK_GRAY.into(),
+                        border_color: color.into(),
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(radius)),
+                    Outline::all(Color::WHITE, Val::Px(2.)),
+                ));
+            }
+        });
+}
@@ -0,0 +1,48 @@
+//! Example demonstrating window frames
+//!
+//! Drag the title bars to move the windows and drag their borders to resize them.
+
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BordersPlugin)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    for (i, color) in [Color::MAROON, Color::DARK_GREEN, Color::MIDNIGHT_BLUE]
+        .into_iter()
+        .enumerate()
+    {
+        let offset = 60. + 80. * i as f32;
+        let parts = spawn_window_frame(
+            &mut commands,
+            WindowFrameBundle::new(Vec2::splat(offset), Vec2::new(300., 200.)),
+            24.,
+            color,
+        );
+        let close_button = commands
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.), Val::Percent(100.)),
+                        border: UiRect::all(Val::Px(2.)),
+                        ..Default::default()
+                    },
+                    background_color: Color::CRIMSON.into(),
+                    ..Default::default()
+                },
+                BorderColor(Color::WHITE),
+                WindowCloseButton,
+            ))
+            .id();
+        commands
+            .entity(parts.close_button_slot)
+            .add_child(close_button);
+    }
+}
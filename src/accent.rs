@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::geometry::border_edges;
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::Sides;
+use crate::UiDecoration;
+
+/// A thick colored strip along one edge of a UI node, like the colored bar on the left of a list item or
+/// notification.
+///
+/// The strip is drawn inside the node like a border on `edge`, but independently of `Style::border`, so it doesn't
+/// move the node's content. Combine sides to draw a strip along each of them.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct AccentBar {
+    pub edge: Sides,
+    /// Thickness of the strip, percentages are resolved against the width of the node's parent
+    pub thickness: Val,
+    pub color: Color,
+}
+
+impl Default for AccentBar {
+    fn default() -> Self {
+        Self {
+            edge: Sides::LEFT,
+            thickness: Val::Px(4.),
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl AccentBar {
+    pub fn new(edge: Sides, thickness: Val, color: Color) -> Self {
+        Self {
+            edge,
+            thickness,
+            color,
+        }
+    }
+}
+
+impl UiDecoration for AccentBar {
+    const LAYER: DecorationLayer = DecorationLayer::Border;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let thickness = context.resolve(self.thickness).max(0.);
+        let thicknesses = [Sides::LEFT, Sides::RIGHT, Sides::TOP, Sides::BOTTOM].map(|side| {
+            if self.edge.contains(side) {
+                thickness
+            } else {
+                0.
+            }
+        });
+        let (edges, _) = border_edges(context.size, thicknesses);
+        quads.extend(edges.into_iter().flatten().map(|rect| DecorationQuad {
+            rect,
+            color: self.color,
+        }));
+    }
+}
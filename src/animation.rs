@@ -0,0 +1,770 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::Border;
+use crate::BorderColor;
+use crate::BorderRadius;
+use crate::Outline;
+use crate::OutlineColor;
+use crate::OutlineOffset;
+
+/// Easing functions mapping linear progress in `0..=1` to eased progress.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ElasticIn,
+    ElasticOut,
+    BounceIn,
+    BounceOut,
+}
+
+impl Easing {
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadraticIn => t * t,
+            Easing::QuadraticOut => 1. - (1. - t) * (1. - t),
+            Easing::QuadraticInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(2) / 2.
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1. - (1. - t).powi(3),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            Easing::ElasticIn => 1. - Easing::ElasticOut.ease(1. - t),
+            Easing::ElasticOut => {
+                if t == 0. || t == 1. {
+                    t
+                } else {
+                    2f32.powf(-10. * t) * ((t * 10. - 0.75) * (2. * PI / 3.)).sin() + 1.
+                }
+            }
+            Easing::BounceIn => 1. - Easing::BounceOut.ease(1. - t),
+            Easing::BounceOut => {
+                const N: f32 = 7.5625;
+                const D: f32 = 2.75;
+                if t < 1. / D {
+                    N * t * t
+                } else if t < 2. / D {
+                    let t = t - 1.5 / D;
+                    N * t * t + 0.75
+                } else if t < 2.5 / D {
+                    let t = t - 2.25 / D;
+                    N * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D;
+                    N * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
+/// The color space that animated colors are interpolated in.
+///
+/// Interpolating in sRGB, the space colors are stored in, is cheap but fades between saturated colors pass through
+/// muddy grays. Linear RGB mixes light physically, and OKLab keeps perceived lightness and saturation even.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum ColorSpace {
+    #[default]
+    Srgb,
+    LinearRgb,
+    Oklab,
+}
+
+impl ColorSpace {
+    /// Interpolates between two colors in this color space. Alpha is always interpolated linearly.
+    pub fn lerp(self, start: Color, end: Color, t: f32) -> Color {
+        let alpha = start.a() + (end.a() - start.a()) * t;
+        match self {
+            ColorSpace::Srgb => lerp_color(start, end, t),
+            ColorSpace::LinearRgb => {
+                let [r, g, b] = lerp3(linear_rgb(start), linear_rgb(end), t);
+                Color::rgba_linear(r, g, b, alpha)
+            }
+            ColorSpace::Oklab => {
+                let lab = lerp3(
+                    linear_to_oklab(linear_rgb(start)),
+                    linear_to_oklab(linear_rgb(end)),
+                    t,
+                );
+                let [r, g, b] = oklab_to_linear(lab);
+                Color::rgba_linear(r, g, b, alpha)
+            }
+        }
+    }
+}
+
+fn linear_rgb(color: Color) -> [f32; 3] {
+    let [r, g, b, _] = color.as_linear_rgba_f32();
+    [r, g, b]
+}
+
+fn lerp3(start: [f32; 3], end: [f32; 3], t: f32) -> [f32; 3] {
+    [0, 1, 2].map(|i| start[i] + (end[i] - start[i]) * t)
+}
+
+/// Converts linear sRGB to OKLab, from Björn Ottosson's reference implementation.
+#[allow(clippy::excessive_precision)]
+pub fn linear_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+/// Converts OKLab to linear sRGB, the inverse of [`linear_to_oklab`]. Colors outside the sRGB gamut aren't clamped.
+#[allow(clippy::excessive_precision)]
+pub fn oklab_to_linear([lightness, a, b]: [f32; 3]) -> [f32; 3] {
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    [
+        4.0767416621 * l - 3.3077074785 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
+}
+
+/// An animatable property with its value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BorderProperty {
+    /// The node's `Style::border`, or the width of its [`Border`] if it has one
+    BorderThickness(UiRect),
+    /// The node's [`BorderColor`], or the color of its [`Border`] if it has one
+    BorderColor(Color),
+    /// The thickness of the node's [`Outline`]
+    OutlineThickness(UiRect),
+    /// The node's [`OutlineColor`]
+    OutlineColor(Color),
+    /// The node's [`OutlineOffset`]
+    OutlineOffset(Val),
+    /// The node's [`BorderRadius`], or the radius of its [`Border`] if it has one
+    Radius(BorderRadius),
+}
+
+impl BorderProperty {
+    /// Interpolates between two values of the same property.
+    ///
+    /// Thickness, offset and radius values with different units can't be interpolated and jump to `end` once `t` reaches 1.
+    pub fn lerp(self, end: Self, t: f32) -> Self {
+        self.lerp_in(end, t, ColorSpace::Srgb)
+    }
+
+    /// Interpolates between two values of the same property, interpolating colors in `color_space`.
+    pub fn lerp_in(self, end: Self, t: f32, color_space: ColorSpace) -> Self {
+        use BorderProperty::*;
+        match (self, end) {
+            (BorderThickness(a), BorderThickness(b)) => BorderThickness(lerp_rect(a, b, t)),
+            (BorderColor(a), BorderColor(b)) => BorderColor(color_space.lerp(a, b, t)),
+            (OutlineThickness(a), OutlineThickness(b)) => OutlineThickness(lerp_rect(a, b, t)),
+            (OutlineColor(a), OutlineColor(b)) => OutlineColor(color_space.lerp(a, b, t)),
+            (OutlineOffset(a), OutlineOffset(b)) => OutlineOffset(lerp_val(a, b, t)),
+            (Radius(a), Radius(b)) => Radius(lerp_radius(a, b, t)),
+            _ => end,
+        }
+    }
+
+    fn same_property(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+pub(crate) fn lerp_color(start: Color, end: Color, t: f32) -> Color {
+    let [r0, g0, b0, a0] = start.as_rgba_f32();
+    let [r1, g1, b1, a1] = end.as_rgba_f32();
+    Color::rgba(
+        r0 + (r1 - r0) * t,
+        g0 + (g1 - g0) * t,
+        b0 + (b1 - b0) * t,
+        a0 + (a1 - a0) * t,
+    )
+}
+
+pub(crate) fn lerp_val(start: Val, end: Val, t: f32) -> Val {
+    match (start, end) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a + (b - a) * t),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a + (b - a) * t),
+        _ if t < 1. => start,
+        _ => end,
+    }
+}
+
+pub(crate) fn lerp_rect(start: UiRect, end: UiRect, t: f32) -> UiRect {
+    UiRect {
+        left: lerp_val(start.left, end.left, t),
+        right: lerp_val(start.right, end.right, t),
+        top: lerp_val(start.top, end.top, t),
+        bottom: lerp_val(start.bottom, end.bottom, t),
+    }
+}
+
+pub(crate) fn lerp_radius(start: BorderRadius, end: BorderRadius, t: f32) -> BorderRadius {
+    BorderRadius {
+        top_left: lerp_val(start.top_left, end.top_left, t),
+        top_right: lerp_val(start.top_right, end.top_right, t),
+        bottom_right: lerp_val(start.bottom_right, end.bottom_right, t),
+        bottom_left: lerp_val(start.bottom_left, end.bottom_left, t),
+    }
+}
+
+/// A transition of one property to a target value.
+#[derive(Copy, Clone, Debug)]
+pub struct BorderTransition {
+    /// The property and the value it transitions to
+    pub target: BorderProperty,
+    /// Length of the transition in seconds
+    pub duration: f32,
+    pub easing: Easing,
+    /// The value of the property when the transition started, captured on its first update
+    pub start: Option<BorderProperty>,
+    /// Seconds since the transition started
+    pub elapsed: f32,
+}
+
+impl BorderTransition {
+    pub fn new(target: BorderProperty, duration: f32, easing: Easing) -> Self {
+        Self {
+            target,
+            duration,
+            easing,
+            start: None,
+            elapsed: 0.,
+        }
+    }
+
+    /// Linear progress of the transition in `0..=1`.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0. {
+            1.
+        } else {
+            (self.elapsed / self.duration).min(1.)
+        }
+    }
+}
+
+/// Transitions a UI node's border and outline properties to new values over time.
+///
+/// Each finished transition is removed and reported with a [`BorderAnimationFinished`] event.
+/// The component is removed once all of its transitions have finished.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderAnimation {
+    // `BorderProperty` holds `Val`s, which don't implement `FromReflect`
+    #[reflect(ignore)]
+    pub transitions: Vec<BorderTransition>,
+    /// The color space colors are interpolated in
+    pub color_space: ColorSpace,
+}
+
+impl BorderAnimation {
+    pub fn new(target: BorderProperty, duration: f32, easing: Easing) -> Self {
+        Self::default().with(target, duration, easing)
+    }
+
+    /// Adds another transition, replacing any existing transition of the same property.
+    pub fn with(mut self, target: BorderProperty, duration: f32, easing: Easing) -> Self {
+        self.transitions
+            .retain(|transition| !transition.target.same_property(&target));
+        self.transitions
+            .push(BorderTransition::new(target, duration, easing));
+        self
+    }
+
+    /// Interpolates colors in `color_space` instead of sRGB.
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+}
+
+/// Sent when a [`BorderTransition`] finishes.
+#[derive(Copy, Clone, Debug)]
+pub struct BorderAnimationFinished {
+    pub entity: Entity,
+    /// The property and the value it reached
+    pub property: BorderProperty,
+}
+
+/// A property value at a point in a [`BorderKeyframes`] timeline.
+#[derive(Copy, Clone, Debug)]
+pub struct Keyframe {
+    /// Seconds from the start of the timeline
+    pub time: f32,
+    pub value: BorderProperty,
+    /// Easing used when interpolating from the previous keyframe of the same property to this one
+    pub easing: Easing,
+}
+
+/// What a [`BorderKeyframes`] timeline does when it reaches its last keyframe.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum KeyframeRepeat {
+    /// Stop at the last keyframe
+    #[default]
+    Once,
+    /// Restart from the first keyframe
+    Loop,
+    /// Play backwards to the first keyframe, then forwards again
+    PingPong,
+}
+
+/// A timeline of keyframes animating a UI node's border and outline properties.
+///
+/// Keyframes for different properties can be mixed in one timeline, each property is interpolated
+/// between its own keyframes. A timeline that plays once is removed when it finishes, sending a
+/// [`BorderAnimationFinished`] event for each of its properties.
+///
+/// A slow breathing outline:
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_borders::*;
+/// let breathing = BorderKeyframes::new(KeyframeRepeat::PingPong)
+///     .with(0., BorderProperty::OutlineColor(Color::rgba(1., 1., 1., 0.2)), Easing::Linear)
+///     .with(1.5, BorderProperty::OutlineColor(Color::WHITE), Easing::QuadraticInOut);
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderKeyframes {
+    /// Keyframes sorted by time
+    #[reflect(ignore)]
+    keyframes: Vec<Keyframe>,
+    pub repeat: KeyframeRepeat,
+    /// Seconds since the timeline started
+    pub elapsed: f32,
+    /// The color space colors are interpolated in
+    pub color_space: ColorSpace,
+}
+
+impl BorderKeyframes {
+    pub fn new(repeat: KeyframeRepeat) -> Self {
+        Self {
+            repeat,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a keyframe at `time` seconds.
+    pub fn with(mut self, time: f32, value: BorderProperty, easing: Easing) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        self.keyframes.insert(
+            index,
+            Keyframe {
+                time,
+                value,
+                easing,
+            },
+        );
+        self
+    }
+
+    /// Interpolates colors in `color_space` instead of sRGB.
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    /// The time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes
+            .last()
+            .map(|keyframe| keyframe.time)
+            .unwrap_or(0.)
+    }
+
+    /// Whether a timeline that plays once has reached its last keyframe.
+    pub fn is_finished(&self) -> bool {
+        self.repeat == KeyframeRepeat::Once && self.duration() <= self.elapsed
+    }
+
+    /// The position in the timeline after `elapsed` seconds, accounting for repeats.
+    fn timeline_position(&self) -> f32 {
+        let duration = self.duration();
+        if duration <= 0. {
+            return duration;
+        }
+        match self.repeat {
+            KeyframeRepeat::Once => self.elapsed.min(duration),
+            KeyframeRepeat::Loop => self.elapsed.rem_euclid(duration),
+            KeyframeRepeat::PingPong => {
+                duration - (self.elapsed.rem_euclid(2. * duration) - duration).abs()
+            }
+        }
+    }
+
+    /// Samples every property in the timeline at the current position.
+    pub fn sample(&self) -> Vec<BorderProperty> {
+        let position = self.timeline_position();
+        let mut values: Vec<BorderProperty> = vec![];
+        for keyframe in &self.keyframes {
+            if values
+                .iter()
+                .any(|value| value.same_property(&keyframe.value))
+            {
+                continue;
+            }
+            let mut track = self
+                .keyframes
+                .iter()
+                .filter(|other| other.value.same_property(&keyframe.value));
+            let mut previous = track.next().unwrap_or(keyframe);
+            let mut value = previous.value;
+            for next in track {
+                if position < next.time {
+                    if previous.time <= position {
+                        let t = (position - previous.time) / (next.time - previous.time);
+                        value = previous.value.lerp_in(
+                            next.value,
+                            next.easing.ease(t),
+                            self.color_space,
+                        );
+                    }
+                    break;
+                }
+                previous = next;
+                value = next.value;
+            }
+            values.push(value);
+        }
+        values
+    }
+}
+
+/// Temporarily sets a UI node's border and outline colors to `color`, then restores them.
+///
+/// Added by [`flash_border`](crate::BorderCommandsExt::flash_border). The flash overrides any running animation of
+/// the colors, and a [`BorderFlashFinished`] event is sent when the colors are restored and the component removed.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderFlash {
+    pub color: Color,
+    /// Seconds the flash lasts
+    pub duration: f32,
+    /// Seconds since the flash started
+    pub elapsed: f32,
+    /// The border and outline colors to restore, read when the flash starts
+    #[reflect(ignore)]
+    original: Option<(Option<BorderProperty>, Option<BorderProperty>)>,
+}
+
+impl Default for BorderFlash {
+    fn default() -> Self {
+        Self::new(Color::WHITE, 0.25)
+    }
+}
+
+impl BorderFlash {
+    pub fn new(color: Color, duration: f32) -> Self {
+        Self {
+            color,
+            duration,
+            elapsed: 0.,
+            original: None,
+        }
+    }
+
+    /// Restarts the flash with a new color and duration, keeping the colors to restore from the current flash.
+    pub(crate) fn restart(&mut self, color: Color, duration: f32) {
+        self.color = color;
+        self.duration = duration;
+        self.elapsed = 0.;
+    }
+}
+
+/// Sent when a [`BorderFlash`] finishes and the node's colors have been restored.
+#[derive(Copy, Clone, Debug)]
+pub struct BorderFlashFinished {
+    pub entity: Entity,
+}
+
+/// Shakes a UI node's border and outline with a decaying oscillation, without moving the node or affecting layout.
+///
+/// Only the drawn border and outline are offset. The component is removed once the shake has finished.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderShake {
+    /// Largest offset in logical pixels, at the start of the shake
+    pub amplitude: f32,
+    /// Oscillations per second
+    pub frequency: f32,
+    /// Seconds the shake lasts
+    pub duration: f32,
+    /// Direction of the shake, horizontal by default
+    pub direction: Vec2,
+    /// Seconds since the shake started
+    pub elapsed: f32,
+}
+
+impl Default for BorderShake {
+    fn default() -> Self {
+        Self {
+            amplitude: 6.,
+            frequency: 12.,
+            duration: 0.4,
+            direction: Vec2::X,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl BorderShake {
+    pub fn new(amplitude: f32, frequency: f32, duration: f32) -> Self {
+        Self {
+            amplitude,
+            frequency,
+            duration,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_direction(mut self, direction: Vec2) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.duration <= self.elapsed
+    }
+
+    /// The current offset of the border and outline in logical pixels.
+    pub fn offset(&self) -> Vec2 {
+        if self.duration <= 0. || self.is_finished() {
+            return Vec2::ZERO;
+        }
+        let decay = 1. - self.elapsed / self.duration;
+        let phase = std::f32::consts::TAU * self.frequency * self.elapsed;
+        self.direction.normalize_or_zero() * self.amplitude * decay * decay * phase.sin()
+    }
+}
+
+/// The border and outline components of a node that animations read and write.
+pub(crate) type AnimatedComponents<'a> = (
+    &'a mut Style,
+    Option<&'a mut Border>,
+    Option<&'a mut BorderColor>,
+    Option<&'a mut Outline>,
+    Option<&'a mut OutlineColor>,
+    Option<&'a mut OutlineOffset>,
+    Option<&'a mut BorderRadius>,
+);
+
+/// The query item for [`AnimatedComponents`].
+pub(crate) type AnimatedItem<'w> = (
+    Mut<'w, Style>,
+    Option<Mut<'w, Border>>,
+    Option<Mut<'w, BorderColor>>,
+    Option<Mut<'w, Outline>>,
+    Option<Mut<'w, OutlineColor>>,
+    Option<Mut<'w, OutlineOffset>>,
+    Option<Mut<'w, BorderRadius>>,
+);
+
+/// Reads the current value of a property, `None` if the node doesn't have the component.
+pub(crate) fn read_property(
+    (style, border, border_color, outline, outline_color, outline_offset, radius): &AnimatedItem,
+    property: &BorderProperty,
+) -> Option<BorderProperty> {
+    Some(match property {
+        BorderProperty::BorderThickness(_) => BorderProperty::BorderThickness(
+            border
+                .as_ref()
+                .map(|border| border.width)
+                .unwrap_or(style.border),
+        ),
+        BorderProperty::BorderColor(_) => match border {
+            Some(border) => BorderProperty::BorderColor(border.color),
+            None => BorderProperty::BorderColor(border_color.as_ref()?.0),
+        },
+        BorderProperty::OutlineThickness(_) => {
+            BorderProperty::OutlineThickness(outline.as_ref()?.thickness)
+        }
+        BorderProperty::OutlineColor(_) => BorderProperty::OutlineColor(outline_color.as_ref()?.0),
+        BorderProperty::OutlineOffset(_) => {
+            BorderProperty::OutlineOffset(outline_offset.as_ref()?.0)
+        }
+        BorderProperty::Radius(_) => BorderProperty::Radius(match border {
+            Some(border) => border.radius,
+            None => **radius.as_ref()?,
+        }),
+    })
+}
+
+/// Writes a property value to the node's components, ignoring properties the node doesn't have components for.
+pub(crate) fn write_property(
+    (style, border, border_color, outline, outline_color, outline_offset, radius): &mut AnimatedItem,
+    value: BorderProperty,
+) {
+    match value {
+        BorderProperty::BorderThickness(thickness) => match border {
+            Some(border) => border.width = thickness,
+            None => style.border = thickness,
+        },
+        BorderProperty::BorderColor(color) => match (border, border_color) {
+            (Some(border), _) => border.color = color,
+            (None, Some(border_color)) => border_color.0 = color,
+            (None, None) => {}
+        },
+        BorderProperty::OutlineThickness(thickness) => {
+            if let Some(outline) = outline {
+                outline.thickness = thickness;
+            }
+        }
+        BorderProperty::OutlineColor(color) => {
+            if let Some(outline_color) = outline_color {
+                outline_color.0 = color;
+            }
+        }
+        BorderProperty::OutlineOffset(offset) => {
+            if let Some(outline_offset) = outline_offset {
+                outline_offset.0 = offset;
+            }
+        }
+        BorderProperty::Radius(value) => match (border, radius) {
+            (Some(border), _) => border.radius = value,
+            (None, Some(radius)) => **radius = value,
+            (None, None) => {}
+        },
+    }
+}
+
+pub(crate) fn animate_borders(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut finished_events: EventWriter<BorderAnimationFinished>,
+    mut animation_query: Query<(Entity, &mut BorderAnimation, AnimatedComponents)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut animation, mut components) in animation_query.iter_mut() {
+        let color_space = animation.color_space;
+        animation.transitions.retain_mut(|transition| {
+            let Some(start) = transition
+                .start
+                .or_else(|| read_property(&components, &transition.target))
+            else {
+                // The node doesn't have the component for this property
+                return false;
+            };
+            transition.start = Some(start);
+            transition.elapsed += delta;
+
+            let progress = transition.progress();
+            let value = start.lerp_in(
+                transition.target,
+                transition.easing.ease(progress),
+                color_space,
+            );
+            write_property(&mut components, value);
+            if progress < 1. {
+                return true;
+            }
+            finished_events.send(BorderAnimationFinished {
+                entity,
+                property: transition.target,
+            });
+            false
+        });
+        if animation.transitions.is_empty() {
+            commands.entity(entity).remove::<BorderAnimation>();
+        }
+    }
+}
+
+pub(crate) fn animate_border_keyframes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut finished_events: EventWriter<BorderAnimationFinished>,
+    mut keyframes_query: Query<(Entity, &mut BorderKeyframes, AnimatedComponents)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut keyframes, mut components) in keyframes_query.iter_mut() {
+        keyframes.elapsed += delta;
+        let values = keyframes.sample();
+        for &value in &values {
+            write_property(&mut components, value);
+        }
+        if keyframes.is_finished() {
+            for property in values {
+                finished_events.send(BorderAnimationFinished { entity, property });
+            }
+            commands.entity(entity).remove::<BorderKeyframes>();
+        }
+    }
+}
+
+pub(crate) fn flash_borders(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut finished_events: EventWriter<BorderFlashFinished>,
+    mut flash_query: Query<(Entity, &mut BorderFlash, AnimatedComponents)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut flash, mut components) in flash_query.iter_mut() {
+        let (border_color, outline_color) = *flash.original.get_or_insert_with(|| {
+            (
+                read_property(&components, &BorderProperty::BorderColor(Color::NONE)),
+                read_property(&components, &BorderProperty::OutlineColor(Color::NONE)),
+            )
+        });
+        flash.elapsed += delta;
+        if flash.elapsed < flash.duration {
+            write_property(&mut components, BorderProperty::BorderColor(flash.color));
+            write_property(&mut components, BorderProperty::OutlineColor(flash.color));
+            continue;
+        }
+        for original in [border_color, outline_color].into_iter().flatten() {
+            write_property(&mut components, original);
+        }
+        finished_events.send(BorderFlashFinished { entity });
+        commands.entity(entity).remove::<BorderFlash>();
+    }
+}
+
+/// The transform of a node's border and outline, offset by its shake if it has one.
+pub(crate) fn shaken_transform(transform: &GlobalTransform, shake: Option<&BorderShake>) -> Mat4 {
+    let matrix = transform.compute_matrix();
+    match shake {
+        Some(shake) => Mat4::from_translation(shake.offset().extend(0.)) * matrix,
+        None => matrix,
+    }
+}
+
+pub(crate) fn shake_borders(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut shake_query: Query<(Entity, &mut BorderShake)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut shake) in shake_query.iter_mut() {
+        shake.elapsed += delta;
+        if shake.is_finished() {
+            commands.entity(entity).remove::<BorderShake>();
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use crate::tooltip::push_rounded_rect;
+use crate::DecorationContext;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// A corner of a UI node.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum BadgeCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// A small disc drawn over one corner of a UI node's border, like an unread count or notification dot.
+///
+/// The disc is centered on the corner of the middle of the border, or on the corner of the node if it has no border.
+/// Its edge is built from strips one logical pixel tall.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct CornerBadge {
+    pub corner: BadgeCorner,
+    /// Radius of the disc in logical pixels
+    pub radius: f32,
+    pub color: Color,
+    /// Moves the disc away from its corner in logical pixels, with positive y pointing down
+    pub offset: Vec2,
+}
+
+impl Default for CornerBadge {
+    fn default() -> Self {
+        Self {
+            corner: BadgeCorner::TopRight,
+            radius: 5.,
+            color: Color::RED,
+            offset: Vec2::ZERO,
+        }
+    }
+}
+
+impl CornerBadge {
+    pub fn new(corner: BadgeCorner, radius: f32, color: Color) -> Self {
+        Self {
+            corner,
+            radius,
+            color,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// The center of the disc, in the node's local space.
+    pub fn center(&self, context: &DecorationContext) -> Vec2 {
+        let outer = context.node_rect();
+        let inner = context.inner_rect();
+        let middle = Rect {
+            min: 0.5 * (outer.min + inner.min),
+            max: 0.5 * (outer.max + inner.max),
+        };
+        let corner = match self.corner {
+            BadgeCorner::TopLeft => middle.min,
+            BadgeCorner::TopRight => Vec2::new(middle.max.x, middle.min.y),
+            BadgeCorner::BottomLeft => Vec2::new(middle.min.x, middle.max.y),
+            BadgeCorner::BottomRight => middle.max,
+        };
+        corner + self.offset
+    }
+}
+
+impl UiDecoration for CornerBadge {
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        if self.radius <= 0. || !self.radius.is_finite() {
+            return;
+        }
+        let center = self.center(context);
+        push_rounded_rect(
+            Rect::from_center_half_size(center, Vec2::splat(self.radius)),
+            self.radius,
+            self.color,
+            quads,
+        );
+    }
+}
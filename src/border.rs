@@ -0,0 +1,928 @@
+use std::ops::BitAnd;
+use std::ops::BitOr;
+use std::ops::BitOrAssign;
+use std::ops::Not;
+
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+use bevy::utils::tracing::info_span;
+use bevy::utils::HashSet;
+use bevy::window::PrimaryWindow;
+
+use crate::animation::shaken_transform;
+use crate::extracted_quad;
+use crate::fill::extract_edges;
+use crate::geometry::border_edges;
+use crate::geometry::is_negative;
+use crate::geometry::is_non_finite;
+use crate::geometry::resolve_thickness;
+use crate::radius::extract_rounded_ring;
+use crate::radius::local_shape;
+use crate::stroke::extract_stroke_quads;
+use crate::BorderColor;
+use crate::BorderFill;
+use crate::BorderRadius;
+use crate::BorderRounding;
+use crate::BorderShake;
+use crate::CornerStyle;
+use crate::DefaultBorderFill;
+use crate::Disabled;
+use crate::DisabledBorder;
+use crate::FillTransform;
+use crate::InstancedBorder;
+use crate::MinVisibleThickness;
+use crate::SafeAreaBorder;
+use crate::SafeAreaInsets;
+use crate::StrokePath;
+
+/// How a UI node's border is drawn.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Reflect, FromReflect)]
+pub enum BorderStyle {
+    /// A single solid line
+    #[default]
+    Solid,
+    /// No border is drawn and the border takes up no space in the layout
+    None,
+    /// Only the corners of the border are drawn, as brackets reaching `length` logical pixels along each side
+    /// from the corner, like a selection reticle. The border takes up its full space in the layout.
+    CornerBrackets { length: f32 },
+}
+
+/// The shape a UI node's border is drawn in.
+///
+/// The border keeps its rectangular size in the layout, only the drawn geometry changes.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub enum BorderShape {
+    /// The node's border box, shaped by its [`BorderRadius`] or [`CornerStyle`]
+    #[default]
+    Rectangle,
+    /// A regular polygon stretched to fill the node's bounds, like a hexagonal tile.
+    ///
+    /// The first corner points up and the polygon is turned clockwise by `rotation` radians. The border is drawn
+    /// as a stroke along the polygon's edges, as thick as the node's thickest border edge. Polygons with fewer
+    /// than three sides aren't drawn.
+    Polygon { sides: u32, rotation: f32 },
+}
+
+impl BorderShape {
+    /// A hexagon with its corners pointing up and down.
+    pub fn hexagon() -> Self {
+        BorderShape::Polygon {
+            sides: 6,
+            rotation: 0.,
+        }
+    }
+
+    /// The corners of the polygon fitted to `rect`, in clockwise order.
+    ///
+    /// `None` for [`BorderShape::Rectangle`] or a polygon with fewer than three sides.
+    pub fn polygon_points(&self, rect: Rect) -> Option<Vec<Vec2>> {
+        let BorderShape::Polygon { sides, rotation } = *self else {
+            return None;
+        };
+        if sides < 3 {
+            return None;
+        }
+        let unit_points: Vec<Vec2> = (0..sides)
+            .map(|i| {
+                let angle = rotation + std::f32::consts::TAU * i as f32 / sides as f32
+                    - std::f32::consts::FRAC_PI_2;
+                Vec2::from_angle(angle)
+            })
+            .collect();
+        let (min, max) = unit_points.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), point| (min.min(*point), max.max(*point)),
+        );
+        let scale = rect.size() / (max - min).max(Vec2::splat(f32::EPSILON));
+        Some(
+            unit_points
+                .into_iter()
+                .map(|point| rect.min + (point - min) * scale)
+                .collect(),
+        )
+    }
+
+    /// The stroke that draws a border of the given thickness inside a node of the given size, in the node's local
+    /// space. `None` if the border isn't a polygon.
+    pub fn stroke(&self, size: Vec2, thickness: f32, color: Color) -> Option<StrokePath> {
+        let rect = Rect::from_center_size(Vec2::ZERO, size).inset(-0.5 * thickness);
+        self.polygon_points(rect)
+            .map(|points| StrokePath::polygon(points, thickness, color))
+    }
+}
+
+/// A UI node's border width, color, style and corner radius in a single component.
+///
+/// The plugin keeps the node's `Style::border`, [`BorderColor`] and [`BorderRadius`] in sync with this component,
+/// so they should not be modified directly while it is present. A node without a [`BorderRadius`] is only given one
+/// once `radius` is rounded.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Border {
+    pub width: UiRect,
+    pub color: Color,
+    pub style: BorderStyle,
+    pub radius: BorderRadius,
+}
+
+impl Border {
+    pub fn new(width: UiRect, color: Color) -> Self {
+        Self {
+            width,
+            color,
+            style: BorderStyle::Solid,
+            radius: BorderRadius::default(),
+        }
+    }
+
+    pub fn all(width: Val, color: Color) -> Self {
+        Self::new(UiRect::all(width), color)
+    }
+
+    /// A border of the same width on each of `sides`, and no border on the others.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_borders::*;
+    /// let rules = Border::on(Sides::TOP | Sides::BOTTOM, Val::Px(2.), Color::RED);
+    /// assert_eq!(rules.width, UiRect::vertical(Val::Px(2.)));
+    /// ```
+    pub fn on(sides: Sides, width: Val, color: Color) -> Self {
+        Self::new(sides.rect(width), color)
+    }
+
+    pub fn with_radius(mut self, radius: BorderRadius) -> Self {
+        self.radius = radius;
+        self
+    }
+}
+
+/// A set of a UI node's edges, combined with `|`.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash, Reflect)]
+pub struct Sides(u8);
+
+impl Sides {
+    pub const NONE: Self = Self(0);
+    pub const LEFT: Self = Self(1);
+    pub const RIGHT: Self = Self(1 << 1);
+    pub const TOP: Self = Self(1 << 2);
+    pub const BOTTOM: Self = Self(1 << 3);
+    /// The left and right sides
+    pub const HORIZONTAL: Self = Self(Self::LEFT.0 | Self::RIGHT.0);
+    /// The top and bottom sides
+    pub const VERTICAL: Self = Self(Self::TOP.0 | Self::BOTTOM.0);
+    pub const ALL: Self = Self(Self::HORIZONTAL.0 | Self::VERTICAL.0);
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// The sides set in `bits`, ignoring bits that don't correspond to a side.
+    ///
+    /// Every combination of sides is `Sides::from_bits_truncate(n)` for an `n` in `0..16`.
+    pub const fn from_bits_truncate(bits: u8) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// A rect that is `value` on each of these sides and the default on the others, like `UiRect::left`.
+    pub fn rect(self, value: Val) -> UiRect {
+        let side = |side| {
+            if self.contains(side) {
+                value
+            } else {
+                Val::DEFAULT
+            }
+        };
+        UiRect::new(
+            side(Self::LEFT),
+            side(Self::RIGHT),
+            side(Self::TOP),
+            side(Self::BOTTOM),
+        )
+    }
+}
+
+impl BitOr for Sides {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Sides {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Sides {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Not for Sides {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+}
+
+/// Decomposes each changed [`Border`] into the node's `Style::border`, [`BorderColor`] and [`BorderRadius`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_borders(
+    mut commands: Commands,
+    mut border_query: Query<
+        (
+            Entity,
+            &Border,
+            &mut Style,
+            Option<&mut BorderColor>,
+            Option<&mut BorderRadius>,
+        ),
+        Changed<Border>,
+    >,
+) {
+    for (entity, border, mut style, border_color, radius) in border_query.iter_mut() {
+        let width = match border.style {
+            BorderStyle::Solid | BorderStyle::CornerBrackets { .. } => border.width,
+            BorderStyle::None => UiRect::all(Val::Px(0.)),
+        };
+        if style.border != width {
+            style.border = width;
+        }
+        match border_color {
+            Some(mut border_color) => {
+                if border_color.0 != border.color {
+                    border_color.0 = border.color;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(BorderColor(border.color));
+            }
+        }
+        match radius {
+            Some(mut radius) => {
+                if *radius != border.radius {
+                    *radius = border.radius;
+                }
+            }
+            None => {
+                if border.radius != BorderRadius::default() {
+                    commands.entity(entity).insert(border.radius);
+                }
+            }
+        }
+    }
+}
+
+/// The border geometry of a UI node, in the node's local space.
+///
+/// This component is automatically managed by the [`BordersPlugin`](crate::BordersPlugin).
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct CalculatedBorder {
+    /// The left, right, top and bottom edges of the border, `None` if an edge has no area.
+    pub edges: [Option<Rect>; 4],
+    /// The area enclosed by the border.
+    pub inner: Rect,
+    /// The left, right, top and bottom thickness of the border in logical pixels, after clamping and rounding.
+    ///
+    /// Edges are trimmed so they don't overlap, so on nodes too small for their border the edge rects can be thinner.
+    pub resolved: [f32; 4],
+}
+
+/// What percentage border thicknesses are resolved against when the border is drawn.
+///
+/// Without this component percentages are resolved against the width of the node's parent. This only changes how
+/// the border is drawn, the UI layout still reserves space for the border resolved against the parent's width.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub enum BorderRelativeTo {
+    /// The width of the node's parent
+    #[default]
+    ParentWidth,
+    /// The width of the node
+    SelfWidth,
+    /// The smaller of the node's width and height
+    SelfMinDimension,
+    /// The width of the primary window
+    Viewport,
+}
+
+impl BorderRelativeTo {
+    /// The length in logical pixels that a thickness of 100% resolves to.
+    pub fn basis(self, node_size: Vec2, parent_width: f32, viewport_width: f32) -> f32 {
+        match self {
+            BorderRelativeTo::ParentWidth => parent_width,
+            BorderRelativeTo::SelfWidth => node_size.x,
+            BorderRelativeTo::SelfMinDimension => node_size.min_element(),
+            BorderRelativeTo::Viewport => viewport_width,
+        }
+    }
+}
+
+/// What percentage border and outline thicknesses are resolved against on a node without a parent, when they would
+/// otherwise be resolved against the width of the node's parent.
+///
+/// By default root nodes use the logical width of the primary window, so full-screen panels can have percentage
+/// borders.
+#[derive(Resource, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub enum ParentWidthFallback {
+    /// Zero, logging a warning naming the node the first time one of its percentage thicknesses resolves to nothing
+    Warn,
+    /// Zero, without a warning
+    Zero,
+    /// The logical width of the primary window
+    #[default]
+    Viewport,
+    /// The width of the node
+    SelfWidth,
+}
+
+impl ParentWidthFallback {
+    /// The length in logical pixels used in place of the missing parent's width.
+    pub fn width(self, node_size: Vec2, viewport_width: f32) -> f32 {
+        match self {
+            ParentWidthFallback::Warn | ParentWidthFallback::Zero => 0.,
+            ParentWidthFallback::Viewport => viewport_width,
+            ParentWidthFallback::SelfWidth => node_size.x,
+        }
+    }
+
+    /// Whether a root node with these thicknesses should be warned about.
+    pub(crate) fn should_warn(self, values: [Val; 4]) -> bool {
+        self == ParentWidthFallback::Warn
+            && values
+                .iter()
+                .any(|value| matches!(value, Val::Percent(percent) if *percent != 0.))
+    }
+}
+
+/// Forces the border and outline geometry of a UI node to be recalculated this frame, then removes itself.
+///
+/// Geometry is normally only recalculated when the node's components or its parent's size change. Insert this when
+/// something the geometry depends on changed without the plugin noticing.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct RequestBorderRecalculation;
+
+pub(crate) fn remove_recalculation_requests(
+    mut commands: Commands,
+    request_query: Query<Entity, With<RequestBorderRecalculation>>,
+) {
+    for entity in request_query.iter() {
+        commands
+            .entity(entity)
+            .remove::<RequestBorderRecalculation>();
+    }
+}
+
+/// The part of a border edge that is drawn, from `start` to `end` as fractions of the length of the node's side.
+///
+/// Fractions run from left to right along the top and bottom edges and from top to bottom along the left and right
+/// edges, so spans on adjacent edges meet at the corner they share.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub struct EdgeSpan {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl Default for EdgeSpan {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+impl EdgeSpan {
+    /// The whole edge
+    pub const FULL: Self = Self { start: 0., end: 1. };
+    /// Nothing of the edge
+    pub const EMPTY: Self = Self { start: 0., end: 0. };
+
+    pub const fn new(start: f32, end: f32) -> Self {
+        Self { start, end }
+    }
+
+    /// A span covering `fraction` of the edge around its middle.
+    pub fn centered(fraction: f32) -> Self {
+        let margin = 0.5 * (1. - fraction.clamp(0., 1.));
+        Self::new(margin, 1. - margin)
+    }
+
+    /// The part of the range from `min` to `max` covered by the span.
+    pub fn apply(self, min: f32, max: f32) -> (f32, f32) {
+        let length = max - min;
+        (
+            min + self.start.clamp(0., 1.) * length,
+            min + self.end.clamp(0., 1.) * length,
+        )
+    }
+}
+
+/// Draws only part of each of a UI node's border edges, like a tab underline or a single corner bracket.
+///
+/// The edges are trimmed in the node's [`CalculatedBorder`], so the layout is unchanged. Borders with a
+/// [`BorderRadius`] are drawn with square ends when they have spans.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderSpans {
+    pub left: EdgeSpan,
+    pub right: EdgeSpan,
+    pub top: EdgeSpan,
+    pub bottom: EdgeSpan,
+}
+
+impl BorderSpans {
+    pub const fn new(left: EdgeSpan, right: EdgeSpan, top: EdgeSpan, bottom: EdgeSpan) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// The same span on every edge.
+    pub const fn all(span: EdgeSpan) -> Self {
+        Self::new(span, span, span, span)
+    }
+
+    /// Only part of the top edge is drawn, the other edges are whole.
+    pub const fn top(span: EdgeSpan) -> Self {
+        Self::new(EdgeSpan::FULL, EdgeSpan::FULL, span, EdgeSpan::FULL)
+    }
+
+    /// Only part of the bottom edge is drawn, the other edges are whole.
+    pub const fn bottom(span: EdgeSpan) -> Self {
+        Self::new(EdgeSpan::FULL, EdgeSpan::FULL, EdgeSpan::FULL, span)
+    }
+
+    /// The spans of the two halves of corner brackets reaching `length` along each side from the corners of a node
+    /// of the given size: the top and left ends of the edges, then the bottom and right ends.
+    pub fn corner_brackets(size: Vec2, length: f32) -> [Self; 2] {
+        let fraction = |side: f32| {
+            if 0. < side {
+                (length / side).clamp(0., 0.5)
+            } else {
+                0.5
+            }
+        };
+        let (x, y) = (fraction(size.x), fraction(size.y));
+        [
+            Self::new(
+                EdgeSpan::new(0., y),
+                EdgeSpan::new(0., y),
+                EdgeSpan::new(0., x),
+                EdgeSpan::new(0., x),
+            ),
+            Self::new(
+                EdgeSpan::new(1. - y, 1.),
+                EdgeSpan::new(1. - y, 1.),
+                EdgeSpan::new(1. - x, 1.),
+                EdgeSpan::new(1. - x, 1.),
+            ),
+        ]
+    }
+
+    /// Trims left, right, top and bottom edge rects to their spans along the sides of `rect`.
+    pub fn trim(&self, rect: Rect, edges: [Option<Rect>; 4]) -> [Option<Rect>; 4] {
+        let spans = [self.left, self.right, self.top, self.bottom];
+        let mut trimmed = edges;
+        for (i, edge) in trimmed.iter_mut().enumerate() {
+            let Some(mut rect_edge) = *edge else {
+                continue;
+            };
+            if i < 2 {
+                let (start, end) = spans[i].apply(rect.min.y, rect.max.y);
+                rect_edge.min.y = rect_edge.min.y.max(start);
+                rect_edge.max.y = rect_edge.max.y.min(end);
+            } else {
+                let (start, end) = spans[i].apply(rect.min.x, rect.max.x);
+                rect_edge.min.x = rect_edge.min.x.max(start);
+                rect_edge.max.x = rect_edge.max.x.min(end);
+            }
+            *edge = (rect_edge.min.x < rect_edge.max.x && rect_edge.min.y < rect_edge.max.y)
+                .then_some(rect_edge);
+        }
+        trimmed
+    }
+}
+
+/// Limits the drawn thickness of each border edge, applied after percentages are resolved.
+///
+/// Edges with no thickness are left undrawn, so a percentage border never disappears on a small window or
+/// becomes absurdly thick on a large one. Like [`BorderRelativeTo`], this only changes how the border is drawn.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderThicknessClamp {
+    /// Minimum thickness in logical pixels
+    pub min_px: f32,
+    /// Maximum thickness in logical pixels
+    pub max_px: f32,
+}
+
+impl Default for BorderThicknessClamp {
+    fn default() -> Self {
+        Self {
+            min_px: 0.,
+            max_px: f32::INFINITY,
+        }
+    }
+}
+
+impl BorderThicknessClamp {
+    pub fn new(min_px: f32, max_px: f32) -> Self {
+        Self { min_px, max_px }
+    }
+
+    /// Clamps a resolved thickness, leaving edges with no thickness at zero.
+    pub fn clamp(&self, thickness: f32) -> f32 {
+        if thickness <= 0. {
+            0.
+        } else {
+            thickness.max(self.min_px).min(self.max_px)
+        }
+    }
+}
+
+/// Marks viewport relative borders as changed when the primary window is resized.
+pub(crate) fn update_viewport_relative_borders(
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut relative_to_query: Query<&mut BorderRelativeTo>,
+) {
+    if !window_query
+        .get_single()
+        .is_ok_and(|window| window.is_changed())
+    {
+        return;
+    }
+    for mut relative_to in relative_to_query.iter_mut() {
+        if *relative_to == BorderRelativeTo::Viewport {
+            relative_to.set_changed();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn insert_calculated_borders(
+    mut commands: Commands,
+    border_query: Query<
+        Entity,
+        (
+            Or<(With<BorderColor>, With<BorderFill>)>,
+            Without<CalculatedBorder>,
+        ),
+    >,
+) {
+    for entity in border_query.iter() {
+        commands.entity(entity).insert(CalculatedBorder::default());
+    }
+}
+
+/// Removes the border geometry from nodes that no longer have a border color or fill.
+#[allow(clippy::type_complexity)]
+pub(crate) fn remove_calculated_borders(
+    mut commands: Commands,
+    mut removed_colors: RemovedComponents<BorderColor>,
+    mut removed_fills: RemovedComponents<BorderFill>,
+    border_query: Query<
+        (),
+        (
+            With<CalculatedBorder>,
+            Without<BorderColor>,
+            Without<BorderFill>,
+        ),
+    >,
+) {
+    for entity in removed_colors.iter().chain(removed_fills.iter()) {
+        if border_query.contains(entity) {
+            commands.entity(entity).remove::<CalculatedBorder>();
+        }
+    }
+}
+
+/// Generates the border geometry
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn calculate_borders(
+    rounding: Res<BorderRounding>,
+    min_thickness: Res<MinVisibleThickness>,
+    safe_area: Res<SafeAreaInsets>,
+    mut removed_safe_areas: RemovedComponents<SafeAreaBorder>,
+    ui_scale: Res<UiScale>,
+    parent_query: Query<Ref<Node>, With<Children>>,
+    parent_width_fallback: Res<ParentWidthFallback>,
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut warned_non_finite: Local<HashSet<Entity>>,
+    mut warned_negative: Local<HashSet<Entity>>,
+    mut warned_root_percent: Local<HashSet<Entity>>,
+    mut border_query: Query<(
+        Entity,
+        Ref<Node>,
+        Ref<Style>,
+        &mut CalculatedBorder,
+        Option<Ref<Parent>>,
+        Option<Ref<BorderRelativeTo>>,
+        Option<Ref<BorderThicknessClamp>>,
+        Option<Ref<BorderSpans>>,
+        Option<&RequestBorderRecalculation>,
+        Option<(Ref<SafeAreaBorder>, Ref<Transform>)>,
+    )>,
+) {
+    let _span = info_span!("bevy_ui_borders::calculate_borders").entered();
+    let (viewport_size, window_scale_factor) = window_query
+        .get_single()
+        .map(|window| {
+            (
+                Vec2::new(window.width(), window.height()),
+                window.scale_factor(),
+            )
+        })
+        .unwrap_or((Vec2::ZERO, 1.));
+    let viewport_width = viewport_size.x;
+    // Root nodes may resolve percentages against the window's width
+    let fallback_changed = parent_width_fallback.is_changed()
+        || (*parent_width_fallback == ParentWidthFallback::Viewport
+            && window_query
+                .get_single()
+                .is_ok_and(|window| window.is_changed()));
+    let removed_safe_areas: HashSet<Entity> = removed_safe_areas.iter().collect();
+    for (
+        entity,
+        node,
+        style,
+        mut calculated_border,
+        parent,
+        relative_to,
+        clamp,
+        spans,
+        request,
+        safe,
+    ) in border_query.iter_mut()
+    {
+        // Only root nodes are drawn inside the safe area
+        let safe = safe.filter(|_| parent.is_none());
+        let changed = request.is_some()
+            || removed_safe_areas.contains(&entity)
+            || node.is_changed()
+            || style.is_changed()
+            || calculated_border.is_added()
+            || parent.as_ref().is_some_and(Ref::is_changed)
+            // Percentage thickness depends on the size of the parent
+            || parent.as_ref().is_some_and(|parent| {
+                parent_query
+                    .get(parent.get())
+                    .is_ok_and(|parent_node| parent_node.is_changed())
+            })
+            || relative_to.as_ref().is_some_and(Ref::is_changed)
+            || clamp.as_ref().is_some_and(Ref::is_changed)
+            || spans.as_ref().is_some_and(Ref::is_changed)
+            || (parent.is_none() && fallback_changed)
+            || safe.as_ref().is_some_and(|(marker, transform)| {
+                marker.is_changed() || transform.is_changed() || safe_area.is_changed()
+            });
+        if !changed && !rounding.is_changed() && !min_thickness.is_changed() {
+            continue;
+        }
+
+        let values = [
+            style.border.left,
+            style.border.right,
+            style.border.top,
+            style.border.bottom,
+        ];
+        let relative_to = relative_to.as_deref().copied().unwrap_or_default();
+        let parent_width = parent
+            .and_then(|parent| parent_query.get(parent.get()).ok())
+            .map(|parent_node| parent_node.size().x)
+            .unwrap_or_else(|| {
+                if relative_to == BorderRelativeTo::ParentWidth
+                    && parent_width_fallback.should_warn(values)
+                    && warned_root_percent.insert(entity)
+                {
+                    warn!("Percentage border thickness on {entity:?} resolves to zero as it has no parent, set the `ParentWidthFallback` resource to choose another width");
+                }
+                parent_width_fallback.width(node.size(), viewport_width)
+            });
+        let basis = relative_to.basis(node.size(), parent_width, viewport_width);
+        let resolved = values.map(|value| {
+            if is_non_finite(value) && warned_non_finite.insert(entity) {
+                warn!("Ignoring non-finite border thickness {value:?} on {entity:?}");
+            }
+            if is_negative(value) && warned_negative.insert(entity) {
+                warn!("Clamping negative border thickness {value:?} on {entity:?} to zero");
+            }
+            let thickness = resolve_thickness(value, basis);
+            let thickness = clamp
+                .as_ref()
+                .map_or(thickness, |clamp| clamp.clamp(thickness));
+            let rounded = rounding.apply(thickness, window_scale_factor as f32);
+            min_thickness.apply(thickness, rounded, window_scale_factor as f32)
+        });
+        calculated_border.resolved = resolved;
+
+        // The part of the node the border is drawn around, in the node's local space
+        let rect = match safe {
+            Some((_, transform)) => {
+                // Root nodes are positioned in window space
+                let center = transform.translation.truncate();
+                let node_rect = Rect::from_center_size(center, node.size());
+                let safe_rect = safe_area.safe_rect(viewport_size / ui_scale.scale as f32);
+                let rect = node_rect.intersect(safe_rect);
+                Rect {
+                    min: rect.min - center,
+                    max: rect.max.max(rect.min) - center,
+                }
+            }
+            None => Rect::from_center_size(Vec2::ZERO, node.size()),
+        };
+        let (edges, inner) = border_edges(rect.size(), resolved);
+        let offset = rect.center();
+        let translate = |edge: Rect| Rect {
+            min: edge.min + offset,
+            max: edge.max + offset,
+        };
+        calculated_border.edges = edges.map(|edge| edge.map(translate));
+        calculated_border.inner = translate(inner);
+        if let Some(spans) = spans {
+            calculated_border.edges = spans.trim(rect, calculated_border.edges);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn extract_uinode_borders(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    default_fill: Extract<Res<DefaultBorderFill>>,
+    disabled_border: Extract<Res<DisabledBorder>>,
+    images: Extract<Res<Assets<Image>>>,
+    atlases: Extract<Res<Assets<TextureAtlas>>>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &CalculatedBorder,
+                Option<&BorderColor>,
+                Option<&BorderFill>,
+                Option<&BorderShake>,
+                Option<&BorderRadius>,
+                Option<&CornerStyle>,
+                Option<&BorderSpans>,
+                Option<&Border>,
+                Option<&FillTransform>,
+                Option<&BorderShape>,
+                Option<&Disabled>,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            (Without<CalculatedSize>, Without<InstancedBorder>),
+        >,
+    >,
+) {
+    let _span = info_span!("bevy_ui_borders::extract_uinode_borders").entered();
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        if let Ok((
+            node,
+            global_transform,
+            calculated_border,
+            border_color,
+            border_fill,
+            shake,
+            radius,
+            corner_style,
+            spans,
+            border,
+            fill_transform,
+            shape,
+            disabled,
+            visibility,
+            clip,
+        )) = uinode_query.get(*entity)
+        {
+            let color = match (border_color, border_fill) {
+                (Some(border_color), _) => border_color.0,
+                (None, Some(_)) => Color::WHITE,
+                (None, None) => continue,
+            };
+            let color = match disabled {
+                Some(_) => disabled_border.color(color),
+                None => color,
+            };
+
+            // Skip invisible borders
+            if !visibility.is_visible()
+                || color.a() == 0.
+                || node.size().x <= 0.
+                || node.size().y <= 0.
+            {
+                continue;
+            }
+
+            let texture = match border_fill {
+                Some(fill) => match fill.resolve(&images, &atlases) {
+                    Some(texture) => Some(texture),
+                    // Skip loading images
+                    None => continue,
+                },
+                None => default_fill
+                    .0
+                    .as_ref()
+                    .and_then(|fill| fill.resolve(&images, &atlases)),
+            };
+
+            let transform = shaken_transform(global_transform, shake);
+            if disabled.is_some() {
+                for edge in disabled_border.edges(calculated_border.edges) {
+                    extracted_uinodes.uinodes.push(extracted_quad(
+                        stack_index,
+                        transform,
+                        edge,
+                        color,
+                        None,
+                        clip.map(|clip| clip.clip),
+                    ));
+                }
+                continue;
+            }
+            if let Some(shape) = shape {
+                if *shape != BorderShape::Rectangle {
+                    let thickness = calculated_border.resolved.into_iter().fold(0., f32::max);
+                    if let Some(stroke) = shape.stroke(node.size(), thickness, color) {
+                        extract_stroke_quads(
+                            &mut extracted_uinodes,
+                            stack_index,
+                            transform,
+                            stroke.quads(),
+                            color,
+                            clip.map(|clip| clip.clip),
+                        );
+                    }
+                    continue;
+                }
+            }
+            if let Some(BorderStyle::CornerBrackets { length }) = border.map(|border| border.style)
+            {
+                let rect = Rect::from_center_size(Vec2::ZERO, node.size());
+                for spans in BorderSpans::corner_brackets(node.size(), length) {
+                    extract_edges(
+                        &mut extracted_uinodes,
+                        stack_index,
+                        transform,
+                        &spans.trim(rect, calculated_border.edges),
+                        color,
+                        texture.as_ref(),
+                        fill_transform.copied().unwrap_or_default().edges(),
+                        clip.map(|clip| clip.clip),
+                    );
+                }
+                continue;
+            }
+            if let (Some(outer), None, None) = (
+                local_shape(node.size(), radius, corner_style),
+                border_fill,
+                spans,
+            ) {
+                extract_rounded_ring(
+                    &mut extracted_uinodes,
+                    stack_index,
+                    transform,
+                    &outer,
+                    &outer.inset(calculated_border.resolved),
+                    color,
+                    clip.map(|clip| clip.clip),
+                );
+                continue;
+            }
+
+            extract_edges(
+                &mut extracted_uinodes,
+                stack_index,
+                transform,
+                &calculated_border.edges,
+                color,
+                texture.as_ref(),
+                fill_transform.copied().unwrap_or_default().edges(),
+                clip.map(|clip| clip.clip),
+            );
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::BorderColor;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::NinePatch;
+use crate::Outline;
+use crate::OutlineColor;
+use crate::Progress;
+use crate::ProgressFill;
+
+/// The basic UI node but with a Border and Outline
+///
+/// Useful as a container for a variety of child nodes.
+#[derive(Bundle, Clone, Debug)]
+pub struct BorderedNodeBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// Describes the style including flexbox settings
+    pub style: Style,
+    /// The background color, which serves as a "fill" for this node
+    pub background_color: BackgroundColor,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `nodebundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `NodeBundle`, use the properties of the [`Style`] component.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub computed_visibility: ComputedVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+    /// The color of the node's border.
+    pub border_color: BorderColor,
+    /// The thickness of the outline
+    pub outline: Outline,
+    /// The color of the outline
+    pub outline_color: OutlineColor,
+    /// The border geometry
+    ///
+    /// This field is automatically managed by the border calculation system.
+    pub calculated_border: CalculatedBorder,
+    /// The outline geometry
+    ///
+    /// This field is automatically managed by the outline calculation system.
+    pub calculated_outline: CalculatedOutline,
+}
+
+impl Default for BorderedNodeBundle {
+    fn default() -> Self {
+        BorderedNodeBundle {
+            // Transparent background
+            background_color: Color::NONE.into(),
+            node: Default::default(),
+            style: Default::default(),
+            focus_policy: Default::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            computed_visibility: Default::default(),
+            z_index: Default::default(),
+            border_color: Color::WHITE.into(),
+            outline: Default::default(),
+            outline_color: Color::WHITE.into(),
+            calculated_border: Default::default(),
+            calculated_outline: Default::default(),
+        }
+    }
+}
+
+/// Add a border bundle to a ui node to draw its border
+#[derive(Bundle, Copy, Clone, Default)]
+pub struct BorderBundle {
+    /// The color of the node's border.
+    pub border_color: BorderColor,
+    /// The thickness of the node's outline
+    pub outline: Outline,
+    /// The color of the node's outline
+    pub outline_color: OutlineColor,
+    /// The border geometry
+    pub calculated_border: CalculatedBorder,
+    /// The outline geometry
+    pub calculated_outline: CalculatedOutline,
+}
+
+/// Add an outline bundle to a ui node to draw an outline around it
+#[derive(Bundle, Copy, Clone, Default, Debug)]
+pub struct OutlineBundle {
+    /// The thickness of the node's outline
+    pub outline: Outline,
+    /// The color of the node's outline
+    pub outline_color: OutlineColor,
+    /// The outline geometry
+    pub calculated_outline: CalculatedOutline,
+}
+
+impl OutlineBundle {
+    /// An outline with the given color and thickness for each edge.
+    pub fn new(color: Color, thickness: UiRect) -> Self {
+        Self {
+            outline: Outline { thickness },
+            outline_color: OutlineColor(color),
+            ..Default::default()
+        }
+    }
+
+    /// An outline with the given color and the same thickness for every edge.
+    pub fn all(color: Color, thickness: Val) -> Self {
+        Self::new(color, UiRect::all(thickness))
+    }
+}
+
+/// A UI node drawn as a nine-patch frame cut from a single image.
+#[derive(Bundle, Clone, Debug)]
+pub struct FrameBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// Describes the style including flexbox settings
+    pub style: Style,
+    /// The nine-patch image drawn as the node's frame
+    pub nine_patch: NinePatch,
+    /// The background color, drawn under the frame
+    pub background_color: BackgroundColor,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `FrameBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `FrameBundle`, use the properties of the [`Style`] component.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub computed_visibility: ComputedVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+}
+
+impl Default for FrameBundle {
+    fn default() -> Self {
+        FrameBundle {
+            // Transparent background
+            background_color: Color::NONE.into(),
+            node: Default::default(),
+            style: Default::default(),
+            nine_patch: Default::default(),
+            focus_policy: Default::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            computed_visibility: Default::default(),
+            z_index: Default::default(),
+        }
+    }
+}
+
+impl FrameBundle {
+    /// A frame of the given size drawn with `nine_patch`, with its border set to the size of the nine-patch's
+    /// insets so the node's children are laid out inside the frame.
+    pub fn new(nine_patch: NinePatch, size: Size) -> Self {
+        let insets = nine_patch.insets;
+        let scale = nine_patch.scale;
+        Self {
+            style: Style {
+                size,
+                border: UiRect::new(
+                    Val::Px(insets.left * scale),
+                    Val::Px(insets.right * scale),
+                    Val::Px(insets.top * scale),
+                    Val::Px(insets.bottom * scale),
+                ),
+                ..Default::default()
+            },
+            nine_patch,
+            ..Default::default()
+        }
+    }
+}
+
+/// A bordered track for a progress bar, like a health or experience bar.
+///
+/// The plugin gives the node a fill child, marked with [`ProgressFillNode`](crate::ProgressFillNode), sized to the
+/// [`Progress`] fraction of the area enclosed by the border. Set the [`Progress`] to move the fill.
+#[derive(Bundle, Clone, Debug)]
+pub struct ProgressFrameBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// Describes the style including flexbox settings
+    pub style: Style,
+    /// The background color, drawn under the fill
+    pub background_color: BackgroundColor,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `ProgressFrameBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `ProgressFrameBundle`, use the properties of the [`Style`] component.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub computed_visibility: ComputedVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+    /// The color of the node's border.
+    pub border_color: BorderColor,
+    /// The border geometry
+    ///
+    /// This field is automatically managed by the border calculation system.
+    pub calculated_border: CalculatedBorder,
+    /// How full the bar is, from 0 to 1
+    pub progress: Progress,
+    /// The color of the fill
+    pub fill: ProgressFill,
+}
+
+impl Default for ProgressFrameBundle {
+    fn default() -> Self {
+        ProgressFrameBundle {
+            background_color: Color::DARK_GRAY.into(),
+            node: Default::default(),
+            style: Style {
+                size: Size::new(Val::Px(200.), Val::Px(20.)),
+                border: UiRect::all(Val::Px(2.)),
+                ..Default::default()
+            },
+            focus_policy: Default::default(),
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            computed_visibility: Default::default(),
+            z_index: Default::default(),
+            border_color: Color::WHITE.into(),
+            calculated_border: Default::default(),
+            progress: Default::default(),
+            fill: Default::default(),
+        }
+    }
+}
+
+impl ProgressFrameBundle {
+    /// A progress bar of the given size with the same border thickness on every edge.
+    pub fn new(size: Size, border: Val, border_color: Color, fill_color: Color) -> Self {
+        Self {
+            style: Style {
+                size,
+                border: UiRect::all(border),
+                ..Default::default()
+            },
+            border_color: BorderColor(border_color),
+            fill: ProgressFill(fill_color),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_progress(mut self, progress: f32) -> Self {
+        self.progress = Progress(progress);
+        self
+    }
+}
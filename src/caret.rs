@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// Clips a rect measured from the top left corner of the node's content to the content, in the node's local space.
+fn content_rect(context: &DecorationContext, offset: Vec2, size: Vec2) -> Option<Rect> {
+    let inner = context.inner_rect();
+    let rect = Rect::from_corners(inner.min + offset, inner.min + offset + size).intersect(inner);
+    (!rect.is_empty()).then_some(rect)
+}
+
+/// A blinking text caret drawn inside a UI node, a building block for text input widgets.
+///
+/// `offset` is the position of the top of the caret bar in logical pixels from the top left corner of the area
+/// enclosed by the node's border, usually measured from the laid out text. The bar is clipped to that area.
+/// Call [`TextCaret::reset_blink`] on each edit to keep the caret visible while the user types.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct TextCaret {
+    pub offset: Vec2,
+    /// Height of the bar in logical pixels, usually the line height
+    pub height: f32,
+    /// Width of the bar in logical pixels
+    pub width: f32,
+    pub color: Color,
+    /// Seconds for a full on and off blink cycle, the caret doesn't blink if 0
+    pub blink_period: f32,
+    #[reflect(ignore)]
+    elapsed: f32,
+}
+
+impl Default for TextCaret {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            height: 16.,
+            width: 1.,
+            color: Color::WHITE,
+            blink_period: 1.,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl TextCaret {
+    pub fn new(height: f32, color: Color) -> Self {
+        Self {
+            height,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// Moves the caret and restarts its blink, so it stays visible while the user types.
+    pub fn move_to(&mut self, offset: Vec2) {
+        self.offset = offset;
+        self.reset_blink();
+    }
+
+    /// Restarts the blink cycle with the caret shown.
+    pub fn reset_blink(&mut self) {
+        self.elapsed = 0.;
+    }
+
+    /// Is the caret in the visible half of its blink cycle.
+    pub fn is_visible(&self) -> bool {
+        self.blink_period <= 0.
+            || self.elapsed.rem_euclid(self.blink_period) < 0.5 * self.blink_period
+    }
+
+    /// The caret bar in the node's local space, `None` if it lies outside the node's content.
+    pub fn rect(&self, context: &DecorationContext) -> Option<Rect> {
+        content_rect(context, self.offset, Vec2::new(self.width, self.height))
+    }
+}
+
+impl UiDecoration for TextCaret {
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        if !self.is_visible() {
+            return;
+        }
+        if let Some(rect) = self.rect(context) {
+            quads.push(DecorationQuad {
+                rect,
+                color: self.color,
+            });
+        }
+    }
+}
+
+/// Highlights selected text inside a UI node, drawn beneath the node's border and children.
+///
+/// Each rect is given by its top left corner and size in logical pixels, measured like [`TextCaret::offset`].
+/// Multi-line selections are usually one rect per line.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct SelectionHighlight {
+    pub rects: Vec<Rect>,
+    pub color: Color,
+}
+
+impl SelectionHighlight {
+    pub fn new(color: Color) -> Self {
+        Self {
+            rects: Vec::new(),
+            color,
+        }
+    }
+
+    /// Adds a rect with its top left corner at `offset`.
+    pub fn with(mut self, offset: Vec2, size: Vec2) -> Self {
+        self.rects.push(Rect::from_corners(offset, offset + size));
+        self
+    }
+}
+
+impl UiDecoration for SelectionHighlight {
+    const LAYER: DecorationLayer = DecorationLayer::Below;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        quads.extend(self.rects.iter().filter_map(|rect| {
+            content_rect(context, rect.min, rect.size()).map(|rect| DecorationQuad {
+                rect,
+                color: self.color,
+            })
+        }));
+    }
+}
+
+pub(crate) fn blink_carets(time: Res<Time>, mut caret_query: Query<&mut TextCaret>) {
+    for mut caret in caret_query.iter_mut() {
+        if 0. < caret.blink_period {
+            caret.elapsed = (caret.elapsed + time.delta_seconds()).rem_euclid(caret.blink_period);
+        }
+    }
+}
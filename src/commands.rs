@@ -0,0 +1,220 @@
+use bevy::ecs::system::EntityCommand;
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::world::EntityMut;
+use bevy::prelude::*;
+
+use crate::selection::OutlineBeforeCursor;
+use crate::selection::OutlineBeforeSelection;
+use crate::Border;
+use crate::BorderAnimation;
+use crate::BorderColor;
+use crate::BorderColorRef;
+use crate::BorderCorners;
+use crate::BorderFill;
+use crate::BorderFlash;
+use crate::BorderKeyframes;
+use crate::BorderRadius;
+use crate::BorderRelativeTo;
+use crate::BorderShake;
+use crate::BorderShape;
+use crate::BorderSpans;
+use crate::BorderThicknessClamp;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::ContrastBorder;
+use crate::ContrastOutline;
+use crate::CornerStyle;
+use crate::FillTransform;
+use crate::FocusOutline;
+use crate::InstancedBorder;
+use crate::InstancedOutline;
+use crate::Outline;
+use crate::OutlineColor;
+use crate::OutlineColorRef;
+use crate::OutlineFill;
+use crate::OutlineOffset;
+use crate::OutlineRelativeTo;
+use crate::OutlineSegments;
+use crate::OutlineShape;
+use crate::OutlineUnits;
+use crate::RequestBorderRecalculation;
+use crate::SafeAreaBorder;
+
+/// Every component that only affects a node's border.
+type BorderComponents = (
+    (Border, BorderColor, BorderColorRef, ContrastBorder),
+    (BorderFill, BorderCorners, BorderSpans, BorderShape),
+    (BorderRelativeTo, BorderThicknessClamp, SafeAreaBorder),
+    (BorderShake, InstancedBorder, CalculatedBorder),
+);
+
+/// Every component that only affects a node's outline.
+type OutlineComponents = (
+    (Outline, OutlineColor, OutlineColorRef, ContrastOutline),
+    (OutlineFill, OutlineSegments, OutlineShape, OutlineOffset),
+    (OutlineRelativeTo, OutlineUnits, InstancedOutline),
+    (FocusOutline, OutlineBeforeCursor, OutlineBeforeSelection),
+    CalculatedOutline,
+);
+
+/// Components shared by the border and outline, removed once a node has neither.
+type SharedComponents = (FillTransform, BorderRadius, CornerStyle);
+
+/// Removes `SharedComponents` if the entity is left with no border or outline.
+fn remove_unused_shared_components(entity: &mut EntityMut) {
+    let has_border = entity.contains::<Border>()
+        || entity.contains::<BorderColor>()
+        || entity.contains::<BorderFill>();
+    if !has_border && !entity.contains::<Outline>() {
+        entity.remove::<SharedComponents>();
+    }
+}
+
+/// Removes every border component from an entity and sets its `Style::border` to zero.
+///
+/// The [`FillTransform`], [`BorderRadius`] and [`CornerStyle`] also shape the outline, so they are only removed if the
+/// entity has no outline either.
+pub struct RemoveBorder;
+
+impl EntityCommand for RemoveBorder {
+    fn write(self, id: Entity, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(id) else {
+            return;
+        };
+        entity.remove::<BorderComponents>();
+        remove_unused_shared_components(&mut entity);
+        if let Some(mut style) = entity.get_mut::<Style>() {
+            style.border = UiRect::all(Val::Px(0.));
+        }
+    }
+}
+
+/// Removes every outline component from an entity, including a [`FocusOutline`] that would otherwise recolor it.
+///
+/// The [`FillTransform`], [`BorderRadius`] and [`CornerStyle`] also shape the border, so they are only removed if the
+/// entity has no border either.
+pub struct RemoveOutline;
+
+impl EntityCommand for RemoveOutline {
+    fn write(self, id: Entity, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(id) else {
+            return;
+        };
+        entity.remove::<OutlineComponents>();
+        remove_unused_shared_components(&mut entity);
+    }
+}
+
+/// Sets an entity's border thickness, keeping its `Style::border` and any [`Border`] in sync.
+///
+/// Changing only one of them leaves the drawn border out of step with the layout, and the plugin overwrites
+/// `Style::border` with the [`Border`]'s width the next time the [`Border`] changes.
+pub struct SetBorderThickness {
+    pub thickness: UiRect,
+}
+
+impl EntityCommand for SetBorderThickness {
+    fn write(self, id: Entity, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(id) else {
+            return;
+        };
+        if let Some(mut border) = entity.get_mut::<Border>() {
+            border.width = self.thickness;
+        }
+        if let Some(mut style) = entity.get_mut::<Style>() {
+            style.border = self.thickness;
+        }
+    }
+}
+
+/// Sets an entity's border color, keeping its [`BorderColor`] and any [`Border`] in sync.
+pub struct SetBorderColor {
+    pub color: Color,
+}
+
+impl EntityCommand for SetBorderColor {
+    fn write(self, id: Entity, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(id) else {
+            return;
+        };
+        if let Some(mut border) = entity.get_mut::<Border>() {
+            border.color = self.color;
+        }
+        match entity.get_mut::<BorderColor>() {
+            Some(mut border_color) => border_color.0 = self.color,
+            None => {
+                entity.insert(BorderColor(self.color));
+            }
+        }
+    }
+}
+
+/// Flashes an entity's border and outline colors, see [`BorderFlash`].
+///
+/// Flashing an entity that is already flashing restarts the flash, and the colors from before the first flash are
+/// restored at the end.
+pub struct FlashBorder {
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl EntityCommand for FlashBorder {
+    fn write(self, id: Entity, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(id) else {
+            return;
+        };
+        match entity.get_mut::<BorderFlash>() {
+            Some(mut flash) => flash.restart(self.color, self.duration),
+            None => {
+                entity.insert(BorderFlash::new(self.color, self.duration));
+            }
+        }
+    }
+}
+
+/// Extension trait for removing borders and outlines along with all of their related components, for changing borders
+/// without desyncing them from the layout, and for flashing them.
+///
+/// Animations are removed along with borders and outlines, since they would otherwise keep writing to the removed
+/// properties.
+pub trait BorderCommandsExt {
+    /// Removes the entity's border, see [`RemoveBorder`].
+    fn remove_border(&mut self) -> &mut Self;
+
+    /// Removes the entity's outline, see [`RemoveOutline`].
+    fn remove_outline(&mut self) -> &mut Self;
+
+    /// Sets the entity's `Style::border` and the width of its [`Border`], see [`SetBorderThickness`].
+    fn set_border_thickness(&mut self, thickness: UiRect) -> &mut Self;
+
+    /// Sets the entity's [`BorderColor`] and the color of its [`Border`], see [`SetBorderColor`].
+    fn set_border_color(&mut self, color: Color) -> &mut Self;
+
+    /// Sets the entity's border and outline colors to `color` for `duration` seconds, then restores them.
+    /// See [`FlashBorder`].
+    fn flash_border(&mut self, color: Color, duration: f32) -> &mut Self;
+}
+
+impl<'w, 's, 'a> BorderCommandsExt for EntityCommands<'w, 's, 'a> {
+    fn remove_border(&mut self) -> &mut Self {
+        self.remove::<(BorderAnimation, BorderKeyframes, RequestBorderRecalculation)>()
+            .add(RemoveBorder)
+    }
+
+    fn remove_outline(&mut self) -> &mut Self {
+        self.remove::<(BorderAnimation, BorderKeyframes, RequestBorderRecalculation)>()
+            .add(RemoveOutline)
+    }
+
+    fn set_border_thickness(&mut self, thickness: UiRect) -> &mut Self {
+        self.add(SetBorderThickness { thickness })
+    }
+
+    fn set_border_color(&mut self, color: Color) -> &mut Self {
+        self.add(SetBorderColor { color })
+    }
+
+    fn flash_border(&mut self, color: Color, duration: f32) -> &mut Self {
+        self.add(FlashBorder { color, duration })
+    }
+}
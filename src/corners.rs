@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+
+use crate::extracted_quad;
+
+/// Decorations drawn at each of the four corners of a UI node, on top of its border.
+///
+/// The image is drawn as-is at the top left corner and mirrored for the other three corners,
+/// so it should be authored as a top left corner piece.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderCorners {
+    /// Top left corner piece
+    pub image: Handle<Image>,
+    /// Size of each corner piece in logical pixels
+    pub size: Vec2,
+}
+
+impl BorderCorners {
+    pub fn new(image: Handle<Image>, size: Vec2) -> Self {
+        Self { image, size }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn extract_uinode_corners(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    images: Extract<Res<Assets<Image>>>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &BorderCorners,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            Without<CalculatedSize>,
+        >,
+    >,
+) {
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        if let Ok((node, global_transform, corners, visibility, clip)) = uinode_query.get(*entity) {
+            // Skip invisible nodes and loading images
+            if !visibility.is_visible()
+                || corners.size.x <= 0.
+                || corners.size.y <= 0.
+                || node.size().x <= 0.
+                || node.size().y <= 0.
+            {
+                continue;
+            }
+            let Some(image_size) = images.get(&corners.image).map(|image| image.size()) else {
+                continue;
+            };
+
+            let transform = global_transform.compute_matrix();
+            let half_size = 0.5 * node.size();
+            let source = Rect {
+                min: Vec2::ZERO,
+                max: image_size,
+            };
+            for (flip_x, flip_y) in [(false, false), (true, false), (false, true), (true, true)] {
+                let x = if flip_x {
+                    half_size.x - corners.size.x
+                } else {
+                    -half_size.x
+                };
+                let y = if flip_y {
+                    half_size.y - corners.size.y
+                } else {
+                    -half_size.y
+                };
+                let min = Vec2::new(x, y);
+                let mut extracted_corner = extracted_quad(
+                    stack_index,
+                    transform,
+                    Rect {
+                        min,
+                        max: min + corners.size,
+                    },
+                    Color::WHITE,
+                    Some((&corners.image, source, image_size)),
+                    clip.map(|clip| clip.clip),
+                );
+                extracted_corner.flip_x = flip_x;
+                extracted_corner.flip_y = flip_y;
+                extracted_uinodes.uinodes.push(extracted_corner);
+            }
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use bevy::asset::AssetLoader;
+use bevy::asset::BoxedFuture;
+use bevy::asset::LoadContext;
+use bevy::asset::LoadedAsset;
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+
+use crate::style_asset::apply_style;
+use crate::Border;
+use crate::BorderStyleAsset;
+use crate::Radius;
+use crate::ShadowStyle;
+use crate::Thickness;
+
+/// A rule from a [`BorderStylesheet`], applied to every UI node with a matching [`StyleClass`].
+#[derive(Clone, Debug)]
+pub struct StyleRule {
+    /// The class name from the rule's selector, without the leading `.`
+    pub class: String,
+    pub style: BorderStyleAsset,
+}
+
+/// A stylesheet parsed from a subset of CSS.
+///
+/// Only class selectors (`.panel`, or `.panel, .card` lists) are supported, with the properties
+/// `border`, `border-width`, `border-color`, `border-radius`, `outline`, `outline-width`, `outline-color` and
+/// `box-shadow`.
+/// Line styles other than `solid`, `none` and `hidden` are drawn solid, and elliptical `border-radius` values
+/// aren't supported. `box-shadow` offsets and spread are ignored; a shadow is drawn as an
+/// [`OutlineShadow`](crate::OutlineShadow), or an [`InsetShadow`](crate::InsetShadow) with the `inset` keyword.
+/// Any other properties are ignored with a warning.
+///
+/// ```css
+/// .panel {
+///     border: 4px solid #ffaa00;
+///     border-radius: 8px;
+///     outline: 2px solid white;
+///     box-shadow: inset 0 0 12px rgba(0, 0, 0, 0.6);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, TypeUuid)]
+#[uuid = "0d8e3e7a-5f0c-4c47-a1f7-2e4b6c9b1d53"]
+pub struct BorderStylesheet {
+    pub rules: Vec<StyleRule>,
+}
+
+impl BorderStylesheet {
+    /// Parses a stylesheet from CSS source.
+    pub fn parse(source: &str) -> Result<Self, CssError> {
+        let source = strip_comments(source);
+        let mut rules = vec![];
+        let mut rest = source.as_str();
+        while let Some(open) = rest.find('{') {
+            let close = rest[open..]
+                .find('}')
+                .map(|close| open + close)
+                .ok_or(CssError::UnclosedBlock)?;
+            let mut style = BorderStyleAsset::default();
+            for declaration in rest[open + 1..close].split(';') {
+                let declaration = declaration.trim();
+                if declaration.is_empty() {
+                    continue;
+                }
+                let (property, value) = declaration
+                    .split_once(':')
+                    .ok_or_else(|| CssError::InvalidDeclaration(declaration.to_string()))?;
+                parse_declaration(&mut style, property.trim(), value.trim())?;
+            }
+            for selector in rest[..open].split(',') {
+                let selector = selector.trim();
+                let class = selector
+                    .strip_prefix('.')
+                    .filter(|class| is_identifier(class))
+                    .ok_or_else(|| CssError::UnsupportedSelector(selector.to_string()))?;
+                rules.push(StyleRule {
+                    class: class.to_string(),
+                    style: style.clone(),
+                });
+            }
+            rest = &rest[close + 1..];
+        }
+        if !rest.trim().is_empty() {
+            return Err(CssError::UnclosedBlock);
+        }
+        Ok(Self { rules })
+    }
+
+    /// The combined style for a node with the given classes, later rules overriding earlier ones.
+    pub fn style_for<'a>(
+        &self,
+        classes: impl IntoIterator<Item = &'a str> + Clone,
+    ) -> BorderStyleAsset {
+        let mut style = BorderStyleAsset::default();
+        for rule in &self.rules {
+            if classes.clone().into_iter().any(|class| class == rule.class) {
+                style.merge(&rule.style);
+            }
+        }
+        style
+    }
+}
+
+/// Errors from parsing a [`BorderStylesheet`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum CssError {
+    UnclosedBlock,
+    InvalidDeclaration(String),
+    UnsupportedSelector(String),
+    InvalidValue { property: String, value: String },
+}
+
+impl std::fmt::Display for CssError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssError::UnclosedBlock => write!(f, "unclosed or unopened declaration block"),
+            CssError::InvalidDeclaration(declaration) => {
+                write!(f, "invalid declaration `{declaration}`")
+            }
+            CssError::UnsupportedSelector(selector) => {
+                write!(
+                    f,
+                    "unsupported selector `{selector}`, only class selectors are supported"
+                )
+            }
+            CssError::InvalidValue { property, value } => {
+                write!(f, "invalid value `{value}` for property `{property}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CssError {}
+
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map(|end| &rest[start + 2 + end + 2..])
+            .unwrap_or("");
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Splits a value on whitespace, keeping parenthesized groups such as `rgba(0, 0, 0, 0.5)` together.
+fn tokens(value: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut depth = 0;
+    let mut start = None;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    tokens.push(&value[start..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(start) = start {
+        tokens.push(&value[start..]);
+    }
+    tokens
+}
+
+fn parse_declaration(
+    style: &mut BorderStyleAsset,
+    property: &str,
+    value: &str,
+) -> Result<(), CssError> {
+    let invalid = || CssError::InvalidValue {
+        property: property.to_string(),
+        value: value.to_string(),
+    };
+    match property {
+        "border" => {
+            let (width, color) = parse_line(value).ok_or_else(invalid)?;
+            style.border = Some(Thickness::All(width));
+            style.border_color = color.or(style.border_color);
+        }
+        "border-width" => style.border = Some(parse_edges(value).ok_or_else(invalid)?),
+        "border-color" => style.border_color = Some(parse_color(value).ok_or_else(invalid)?),
+        "border-radius" => style.radius = Some(parse_corners(value).ok_or_else(invalid)?),
+        "outline" => {
+            let (width, color) = parse_line(value).ok_or_else(invalid)?;
+            style.outline = Some(Thickness::All(width));
+            style.outline_color = color.or(style.outline_color);
+        }
+        "outline-width" => style.outline = Some(parse_edges(value).ok_or_else(invalid)?),
+        "outline-color" => style.outline_color = Some(parse_color(value).ok_or_else(invalid)?),
+        "box-shadow" => {
+            if value == "none" {
+                return Ok(());
+            }
+            let mut tokens = tokens(value);
+            let inset = tokens.first() == Some(&"inset");
+            if inset {
+                tokens.remove(0);
+            }
+            let color = tokens.pop().and_then(parse_color).ok_or_else(invalid)?;
+            let lengths: Option<Vec<_>> = tokens.into_iter().map(parse_px).collect();
+            let lengths = lengths.filter(|lengths| (2..=4).contains(&lengths.len()));
+            let blur = lengths.ok_or_else(invalid)?.get(2).copied().unwrap_or(0.);
+            let shadow = Some(ShadowStyle { color, blur });
+            if inset {
+                style.inset_shadow = shadow;
+            } else {
+                style.outline_shadow = shadow;
+            }
+        }
+        _ => warn!("Ignoring unsupported CSS property `{property}`"),
+    }
+    Ok(())
+}
+
+/// Parses a `<width> <style> <color>` shorthand, with the parts in any order.
+fn parse_line(value: &str) -> Option<(Val, Option<Color>)> {
+    let mut width = Val::Px(3.);
+    let mut color = None;
+    let mut hidden = false;
+    for token in tokens(value) {
+        match token {
+            "none" | "hidden" => hidden = true,
+            "solid" => {}
+            "dashed" | "dotted" | "double" | "groove" | "ridge" | "inset" | "outset" => {
+                warn!("Drawing unsupported CSS line style `{token}` as `solid`");
+            }
+            "thin" => width = Val::Px(1.),
+            "medium" => width = Val::Px(3.),
+            "thick" => width = Val::Px(5.),
+            _ => {
+                if let Some(length) = parse_length(token) {
+                    width = length;
+                } else {
+                    color = Some(parse_color(token)?);
+                }
+            }
+        }
+    }
+    if hidden {
+        width = Val::Px(0.);
+    }
+    Some((width, color))
+}
+
+/// Parses one to four lengths in CSS `top right bottom left` order.
+fn parse_edges(value: &str) -> Option<Thickness> {
+    let lengths: Option<Vec<_>> = tokens(value).into_iter().map(parse_length).collect();
+    let (top, right, bottom, left) = match lengths?.as_slice() {
+        [all] => return Some(Thickness::All(*all)),
+        [vertical, horizontal] => (*vertical, *horizontal, *vertical, *horizontal),
+        [top, horizontal, bottom] => (*top, *horizontal, *bottom, *horizontal),
+        [top, right, bottom, left] => (*top, *right, *bottom, *left),
+        _ => return None,
+    };
+    Some(Thickness::Edges {
+        left,
+        right,
+        top,
+        bottom,
+    })
+}
+
+/// Parses one to four lengths in CSS `top-left top-right bottom-right bottom-left` order.
+fn parse_corners(value: &str) -> Option<Radius> {
+    let lengths: Option<Vec<_>> = tokens(value).into_iter().map(parse_length).collect();
+    let (top_left, top_right, bottom_right, bottom_left) = match lengths?.as_slice() {
+        [all] => return Some(Radius::All(*all)),
+        [main, cross] => (*main, *cross, *main, *cross),
+        [top_left, cross, bottom_right] => (*top_left, *cross, *bottom_right, *cross),
+        [top_left, top_right, bottom_right, bottom_left] => {
+            (*top_left, *top_right, *bottom_right, *bottom_left)
+        }
+        _ => return None,
+    };
+    Some(Radius::Corners {
+        top_left,
+        top_right,
+        bottom_right,
+        bottom_left,
+    })
+}
+
+fn parse_length(token: &str) -> Option<Val> {
+    if token == "0" {
+        Some(Val::Px(0.))
+    } else if let Some(percent) = token.strip_suffix('%') {
+        percent.parse().ok().map(Val::Percent)
+    } else {
+        parse_px(token).map(Val::Px)
+    }
+}
+
+fn parse_px(token: &str) -> Option<f32> {
+    if token == "0" {
+        return Some(0.);
+    }
+    token.strip_suffix("px")?.parse().ok()
+}
+
+fn parse_color(token: &str) -> Option<Color> {
+    if let Some(hex) = token.strip_prefix('#') {
+        return Color::hex(hex).ok();
+    }
+    if let Some(arguments) = token
+        .strip_prefix("rgba(")
+        .or_else(|| token.strip_prefix("rgb("))
+    {
+        let components: Option<Vec<f32>> = arguments
+            .strip_suffix(')')?
+            .split(',')
+            .map(|component| component.trim().parse().ok())
+            .collect();
+        return match components?.as_slice() {
+            [r, g, b] => Some(Color::rgb_u8(*r as u8, *g as u8, *b as u8)),
+            [r, g, b, a] => Some(Color::rgba_u8(
+                *r as u8,
+                *g as u8,
+                *b as u8,
+                (a * 255.) as u8,
+            )),
+            _ => None,
+        };
+    }
+    Some(match token {
+        "transparent" => Color::NONE,
+        "black" => Color::BLACK,
+        "white" => Color::WHITE,
+        "gray" | "grey" => Color::GRAY,
+        "silver" => Color::SILVER,
+        "red" => Color::RED,
+        "maroon" => Color::MAROON,
+        "orange" => Color::ORANGE,
+        "yellow" => Color::YELLOW,
+        "lime" => Color::LIME_GREEN,
+        "green" => Color::GREEN,
+        "teal" => Color::TEAL,
+        "cyan" | "aqua" => Color::CYAN,
+        "blue" => Color::BLUE,
+        "navy" => Color::NAVY,
+        "purple" => Color::PURPLE,
+        "fuchsia" | "magenta" => Color::FUCHSIA,
+        "pink" => Color::PINK,
+        _ => return None,
+    })
+}
+
+/// The class names used to match a UI node against the rules of the [`ActiveStylesheet`].
+#[derive(Component, Clone, Debug, Default)]
+pub struct StyleClass(pub Vec<String>);
+
+impl StyleClass {
+    pub fn new(class: impl Into<String>) -> Self {
+        Self(vec![class.into()])
+    }
+
+    pub fn with(mut self, class: impl Into<String>) -> Self {
+        self.0.push(class.into());
+        self
+    }
+}
+
+/// The stylesheet applied to every UI node with a [`StyleClass`].
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ActiveStylesheet(pub Handle<BorderStylesheet>);
+
+#[derive(Default)]
+pub struct BorderStylesheetLoader;
+
+impl AssetLoader for BorderStylesheetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let stylesheet = BorderStylesheet::parse(std::str::from_utf8(bytes)?)?;
+            load_context.set_default_asset(LoadedAsset::new(stylesheet));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["css"]
+    }
+}
+
+/// Applies the active stylesheet to nodes whose classes changed, or to every node if the stylesheet changed.
+pub(crate) fn apply_stylesheet(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<BorderStylesheet>>,
+    active_stylesheet: Option<Res<ActiveStylesheet>>,
+    stylesheets: Res<Assets<BorderStylesheet>>,
+    mut class_query: Query<(Entity, Ref<StyleClass>, &mut Style, Option<&mut Border>)>,
+) {
+    let Some(active_stylesheet) = active_stylesheet else {
+        return;
+    };
+    let stylesheet_changed = active_stylesheet.is_changed()
+        || asset_events.iter().any(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                *handle == active_stylesheet.0
+            }
+            AssetEvent::Removed { .. } => false,
+        });
+    let Some(stylesheet) = stylesheets.get(&active_stylesheet.0) else {
+        return;
+    };
+
+    for (entity, classes, style, border) in class_query.iter_mut() {
+        if !stylesheet_changed && !classes.is_changed() {
+            continue;
+        }
+        let style_asset = stylesheet.style_for(classes.0.iter().map(String::as_str));
+        apply_style(&mut commands.entity(entity), style, border, &style_asset);
+    }
+}
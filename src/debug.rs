@@ -0,0 +1,465 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::render::RenderApp;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::RenderUiSystem;
+use bevy::ui::UiStack;
+
+use crate::extracted_quad;
+use crate::geometry::edge_rects;
+use crate::geometry::outline_edges;
+use crate::geometry::resolve_thickness;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::DecorationLayer;
+use crate::FocusOutline;
+use crate::Focused;
+
+/// How [`DebugOutlinePlugin`] colors each node's outline.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Reflect, FromReflect)]
+pub enum DebugOutlineColor {
+    /// The same color for every node
+    Single(Color),
+    /// A different hue for each level of the UI hierarchy
+    #[default]
+    Depth,
+    /// Red for nodes that are clipped by an ancestor, green otherwise
+    Clipped,
+}
+
+/// Colors of the regions drawn for each node's boxes, in the style of browser developer tools.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub struct DebugBoxColors {
+    /// The area covered by the node's border
+    pub border: Color,
+    /// The area between the border and the content box
+    pub padding: Color,
+    /// The area inside the padding
+    pub content: Color,
+}
+
+impl Default for DebugBoxColors {
+    fn default() -> Self {
+        Self {
+            border: Color::rgba_u8(253, 221, 155, 128),
+            padding: Color::rgba_u8(195, 208, 139, 128),
+            content: Color::rgba_u8(140, 182, 192, 128),
+        }
+    }
+}
+
+/// Colors of the wireframes drawn around the plugin's calculated geometry.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub struct DebugGeometryColors {
+    /// Wireframe around each edge of a [`CalculatedBorder`] and its inner rect
+    pub border: Color,
+    /// Wireframe around each edge of a [`CalculatedOutline`]
+    pub outline: Color,
+}
+
+impl Default for DebugGeometryColors {
+    fn default() -> Self {
+        Self {
+            border: Color::FUCHSIA,
+            outline: Color::CYAN,
+        }
+    }
+}
+
+/// Configures the debug outlines drawn by [`DebugOutlinePlugin`].
+#[derive(Resource, Copy, Clone, Debug, Reflect)]
+#[reflect(Resource, Default)]
+pub struct DebugOutlineSettings {
+    pub enabled: bool,
+    /// Thickness of each outline in logical pixels
+    pub thickness: f32,
+    pub color: DebugOutlineColor,
+    /// If set, each node's border, padding and content boxes are filled with these colors
+    pub boxes: Option<DebugBoxColors>,
+    /// If set, the border and outline rects calculated by the plugin are drawn as wireframes, even where the border or
+    /// outline is transparent or missing from the extracted UI nodes
+    pub geometry: Option<DebugGeometryColors>,
+}
+
+impl Default for DebugOutlineSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            thickness: 1.,
+            color: DebugOutlineColor::Depth,
+            boxes: None,
+            geometry: None,
+        }
+    }
+}
+
+/// Outlines focusable UI nodes and labels each with its position in the navigation order, for auditing keyboard and
+/// gamepad navigation. Drawn by [`DebugOutlinePlugin`] while `enabled` is set.
+///
+/// The order is taken from `order` if it isn't empty, otherwise every node with a [`FocusOutline`] is numbered in
+/// UI stack order, which follows the hierarchy for nodes without a [`ZIndex`].
+/// Each number is drawn in a badge over the top left corner of its node, and the [`Focused`] node's badge is
+/// highlighted.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource, Default)]
+pub struct FocusOrderOverlay {
+    pub enabled: bool,
+    /// The focusable nodes in navigation order
+    pub order: Vec<Entity>,
+    pub outline: Color,
+    pub badge: Color,
+    pub focused_badge: Color,
+    pub digits: Color,
+    /// Size of each pixel of the digits in logical pixels
+    pub scale: f32,
+}
+
+impl Default for FocusOrderOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            order: Vec::new(),
+            outline: Color::ORANGE,
+            badge: Color::ORANGE,
+            focused_badge: Color::RED,
+            digits: Color::BLACK,
+            scale: 2.,
+        }
+    }
+}
+
+/// Draws a thin outline around every UI node, for debugging layouts.
+///
+/// It can also fill each node's border, padding and content boxes with translucent colors, and draw wireframes of the
+/// calculated border and outline geometry.
+/// The outlines are drawn above all UI nodes and ignore clipping.
+/// Configure them with the [`DebugOutlineSettings`] resource. The [`FocusOrderOverlay`] resource shows the navigation
+/// order of focusable nodes.
+pub struct DebugOutlinePlugin;
+
+impl Plugin for DebugOutlinePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DebugOutlineColor>()
+            .register_type::<DebugBoxColors>()
+            .register_type::<DebugGeometryColors>()
+            .register_type::<DebugOutlineSettings>()
+            .register_type::<FocusOrderOverlay>()
+            .init_resource::<DebugOutlineSettings>()
+            .init_resource::<FocusOrderOverlay>();
+        add_debug_extraction(app);
+    }
+}
+
+/// Adds the systems extracting the debug overlays to the app's render sub-app, if it has one.
+pub(crate) fn add_debug_extraction(app: &mut App) {
+    let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+        return;
+    };
+    render_app.add_systems(
+        (extract_debug_outlines, extract_focus_order_overlay)
+            .chain()
+            .after(RenderUiSystem::ExtractNode)
+            .after(DecorationLayer::Above)
+            .in_schedule(ExtractSchedule),
+    );
+}
+
+/// Shrinks `rect` by the resolved thickness of each edge of `edges`.
+fn inset_rect(rect: Rect, edges: UiRect, parent_width: f32) -> Rect {
+    let min = rect.min
+        + Vec2::new(
+            resolve_thickness(edges.left, parent_width),
+            resolve_thickness(edges.top, parent_width),
+        );
+    let max = rect.max
+        - Vec2::new(
+            resolve_thickness(edges.right, parent_width),
+            resolve_thickness(edges.bottom, parent_width),
+        );
+    Rect {
+        min,
+        max: max.max(min),
+    }
+}
+
+/// The border, padding and content regions of a node in its local space.
+///
+/// The border and padding regions are each split into their four edges.
+fn box_regions(size: Vec2, style: &Style, parent_width: f32) -> ([Rect; 4], [Rect; 4], Rect) {
+    let node_rect = Rect::from_center_size(Vec2::ZERO, size);
+    let padding_box = inset_rect(node_rect, style.border, parent_width);
+    let content_box = inset_rect(padding_box, style.padding, parent_width);
+    (
+        edge_rects(
+            node_rect.min,
+            node_rect.max,
+            padding_box.min,
+            padding_box.max,
+        ),
+        edge_rects(
+            padding_box.min,
+            padding_box.max,
+            content_box.min,
+            content_box.max,
+        ),
+        content_box,
+    )
+}
+
+/// The four sides of a wireframe drawn just inside `rect`.
+fn wireframe(rect: Rect, thickness: f32) -> [Rect; 4] {
+    let inset = Vec2::splat(thickness).min(0.5 * rect.size());
+    edge_rects(rect.min, rect.max, rect.min + inset, rect.max - inset)
+}
+
+/// Number of ancestors of `entity`.
+fn depth(entity: Entity, parent_query: &Query<&Parent>) -> usize {
+    parent_query.iter_ancestors(entity).count()
+}
+
+#[allow(clippy::type_complexity)]
+fn extract_debug_outlines(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    settings: Extract<Res<DebugOutlineSettings>>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            &Style,
+            &ComputedVisibility,
+            Option<&CalculatedClip>,
+            Option<&CalculatedBorder>,
+            Option<&CalculatedOutline>,
+        )>,
+    >,
+    parent_query: Extract<Query<&Parent>>,
+    parent_node_query: Extract<Query<&Node>>,
+) {
+    if !settings.enabled {
+        return;
+    }
+
+    // Draw above every UI node
+    let stack_index = ui_stack.uinodes.len();
+    for entity in ui_stack.uinodes.iter() {
+        let Ok((node, global_transform, style, visibility, clip, border, outline)) =
+            uinode_query.get(*entity)
+        else {
+            continue;
+        };
+        if !visibility.is_visible() {
+            continue;
+        }
+
+        let transform = global_transform.compute_matrix();
+
+        if let Some(colors) = settings.boxes {
+            let parent_width = parent_query
+                .get(*entity)
+                .ok()
+                .and_then(|parent| parent_node_query.get(parent.get()).ok())
+                .map(|parent_node| parent_node.size().x)
+                .unwrap_or(0.);
+            let (border, padding, content) = box_regions(node.size(), style, parent_width);
+            let regions = border
+                .into_iter()
+                .map(|rect| (rect, colors.border))
+                .chain(padding.into_iter().map(|rect| (rect, colors.padding)))
+                .chain([(content, colors.content)]);
+            for (rect, color) in regions {
+                if rect.is_empty() {
+                    continue;
+                }
+                extracted_uinodes.uinodes.push(extracted_quad(
+                    stack_index,
+                    transform,
+                    rect,
+                    color,
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        if settings.thickness <= 0. {
+            continue;
+        }
+
+        if let Some(colors) = settings.geometry {
+            let border_rects = border
+                .into_iter()
+                .flat_map(|border| border.edges.into_iter().flatten().chain([border.inner]))
+                .map(|rect| (rect, colors.border));
+            let outline_rects = outline
+                .into_iter()
+                .flat_map(|outline| outline.edges.into_iter().flatten())
+                .map(|rect| (rect, colors.outline));
+            for (rect, color) in border_rects.chain(outline_rects) {
+                for side in wireframe(rect, settings.thickness) {
+                    if side.is_empty() {
+                        continue;
+                    }
+                    extracted_uinodes.uinodes.push(extracted_quad(
+                        stack_index,
+                        transform,
+                        side,
+                        color,
+                        None,
+                        None,
+                    ));
+                }
+            }
+        }
+        let color = match settings.color {
+            DebugOutlineColor::Single(color) => color,
+            DebugOutlineColor::Depth => {
+                Color::hsl((depth(*entity, &parent_query) * 47 % 360) as f32, 0.9, 0.6)
+            }
+            DebugOutlineColor::Clipped => {
+                if clip.is_some() {
+                    Color::RED
+                } else {
+                    Color::GREEN
+                }
+            }
+        };
+        for edge in outline_edges(node.size(), [settings.thickness; 4])
+            .into_iter()
+            .flatten()
+        {
+            extracted_uinodes.uinodes.push(extracted_quad(
+                stack_index,
+                transform,
+                edge,
+                color,
+                None,
+                None,
+            ));
+        }
+    }
+}
+
+/// Rows of a 3 by 5 pixel font for the digits 0 to 9, the lowest three bits of each row from left to right.
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b010, 0b010, 0b010],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// The rects of the pixels of `number` drawn in the digit font, with its top left corner at the origin.
+///
+/// Returns the rects and the size of the number.
+pub(crate) fn number_pixels(number: usize, scale: f32) -> (Vec<Rect>, Vec2) {
+    let digits: Vec<usize> = number
+        .to_string()
+        .bytes()
+        .map(|digit| (digit - b'0') as usize)
+        .collect();
+    let mut pixels = Vec::new();
+    for (i, digit) in digits.iter().enumerate() {
+        // One pixel of space between digits
+        let left = 4. * i as f32;
+        for (row, bits) in DIGITS[*digit].iter().enumerate() {
+            for column in 0..3 {
+                if bits & (0b100 >> column) != 0 {
+                    let min = Vec2::new(left + column as f32, row as f32) * scale;
+                    pixels.push(Rect::from_corners(min, min + scale));
+                }
+            }
+        }
+    }
+    let size = Vec2::new(4. * digits.len() as f32 - 1., 5.) * scale;
+    (pixels, size)
+}
+
+#[allow(clippy::type_complexity)]
+fn extract_focus_order_overlay(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    overlay: Extract<Res<FocusOrderOverlay>>,
+    settings: Extract<Res<DebugOutlineSettings>>,
+    ui_stack: Extract<Res<UiStack>>,
+    focusable_query: Extract<Query<(), With<FocusOutline>>>,
+    uinode_query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            &ComputedVisibility,
+            Option<&Focused>,
+        )>,
+    >,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    // Draw above every UI node and the debug outlines
+    let stack_index = ui_stack.uinodes.len();
+    let order: Vec<Entity> = if overlay.order.is_empty() {
+        ui_stack
+            .uinodes
+            .iter()
+            .copied()
+            .filter(|entity| focusable_query.contains(*entity))
+            .collect()
+    } else {
+        overlay.order.clone()
+    };
+    let scale = overlay.scale.max(1.);
+    let thickness = settings.thickness.max(1.);
+    for (index, entity) in order.into_iter().enumerate() {
+        let Ok((node, global_transform, visibility, focused)) = uinode_query.get(entity) else {
+            continue;
+        };
+        if !visibility.is_visible() {
+            continue;
+        }
+        let transform = global_transform.compute_matrix();
+        let node_rect = Rect::from_center_size(Vec2::ZERO, node.size());
+        let mut quads: Vec<(Rect, Color)> = wireframe(node_rect, thickness)
+            .into_iter()
+            .map(|side| (side, overlay.outline))
+            .collect();
+
+        let (pixels, number_size) = number_pixels(index + 1, scale);
+        let badge_size = number_size + 2. * scale;
+        let badge = Rect::from_center_size(node_rect.min, badge_size);
+        let badge_color = if focused.is_some() {
+            overlay.focused_badge
+        } else {
+            overlay.badge
+        };
+        quads.push((badge, badge_color));
+        let origin = badge.min + scale;
+        quads.extend(pixels.into_iter().map(|pixel| {
+            (
+                Rect {
+                    min: pixel.min + origin,
+                    max: pixel.max + origin,
+                },
+                overlay.digits,
+            )
+        }));
+
+        for (rect, color) in quads {
+            if rect.is_empty() || color.a() == 0. {
+                continue;
+            }
+            extracted_uinodes.uinodes.push(extracted_quad(
+                stack_index,
+                transform,
+                rect,
+                color,
+                None,
+                None,
+            ));
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use bevy::ecs::schedule::SystemSet;
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::render::RenderApp;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+use bevy::window::PrimaryWindow;
+
+use crate::extracted_quad;
+use crate::geometry::resolve_thickness;
+use crate::geometry::RoundedRect;
+use crate::radius::corner_shape;
+use crate::BorderRadius;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::CornerStyle;
+use crate::ParentWidthFallback;
+
+/// Where a decoration is drawn relative to the node's border and outline.
+///
+/// Decorations are always drawn above the node's background and below its children.
+#[derive(SystemSet, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecorationLayer {
+    /// Drawn before the border and outline
+    Below,
+    /// The border and outline
+    Border,
+    /// Drawn after the border and outline
+    Above,
+}
+
+/// A colored quad produced by a [`UiDecoration`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DecorationQuad {
+    /// The area covered by the quad in the node's local space, centered on the node
+    pub rect: Rect,
+    pub color: Color,
+}
+
+/// Everything the plugin knows about a node's geometry, passed to [`UiDecoration::decorate`].
+#[derive(Copy, Clone, Debug)]
+pub struct DecorationContext<'a> {
+    pub entity: Entity,
+    /// Size of the node in logical pixels
+    pub size: Vec2,
+    /// Width of the node's parent in logical pixels, zero for root nodes
+    pub parent_width: f32,
+    pub border: Option<&'a CalculatedBorder>,
+    pub outline: Option<&'a CalculatedOutline>,
+    pub radius: Option<&'a BorderRadius>,
+    pub corner_style: Option<&'a CornerStyle>,
+}
+
+impl<'a> DecorationContext<'a> {
+    /// The rect of the node's border box, in the node's local space.
+    pub fn node_rect(&self) -> Rect {
+        let half_size = 0.5 * self.size;
+        Rect {
+            min: -half_size,
+            max: half_size,
+        }
+    }
+
+    /// The node's border box with its [`BorderRadius`] or [`CornerStyle`], in the node's local space.
+    pub fn node_shape(&self) -> RoundedRect {
+        corner_shape(self.node_rect(), self.size, self.radius, self.corner_style)
+    }
+
+    /// The rect enclosed by the node's border, in the node's local space.
+    pub fn inner_rect(&self) -> Rect {
+        self.border
+            .map(|border| border.inner)
+            .unwrap_or_else(|| self.node_rect())
+    }
+
+    /// The rect enclosing the node and its outline, in the node's local space.
+    pub fn outer_rect(&self) -> Rect {
+        self.outline
+            .into_iter()
+            .flat_map(|outline| outline.edges.iter().flatten())
+            .fold(self.node_rect(), |bounds, edge| bounds.union(*edge))
+    }
+
+    /// Resolves a thickness value the same way as border and outline thicknesses.
+    pub fn resolve(&self, value: Val) -> f32 {
+        resolve_thickness(value, self.parent_width)
+    }
+}
+
+/// A component that draws extra quads for its UI node.
+///
+/// Register implementations with [`AddUiDecoration::add_ui_decoration`] and the plugin will resolve each node's
+/// geometry and extract the decoration's quads alongside its border.
+pub trait UiDecoration: Component {
+    /// Which layer the decoration is drawn in.
+    const LAYER: DecorationLayer = DecorationLayer::Above;
+
+    /// Appends the quads to draw for this node to `quads`.
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>);
+}
+
+/// Extension trait for registering [`UiDecoration`]s.
+pub trait AddUiDecoration {
+    fn add_ui_decoration<T: UiDecoration>(&mut self) -> &mut Self;
+}
+
+impl AddUiDecoration for App {
+    fn add_ui_decoration<T: UiDecoration>(&mut self) -> &mut Self {
+        if let Ok(render_app) = self.get_sub_app_mut(RenderApp) {
+            render_app.add_system(
+                extract_decorations::<T>
+                    .in_set(T::LAYER)
+                    .in_schedule(ExtractSchedule),
+            );
+        }
+        self
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn extract_decorations<T: UiDecoration>(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    mut quads: Local<Vec<DecorationQuad>>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &T,
+                Option<&CalculatedBorder>,
+                Option<&CalculatedOutline>,
+                Option<&BorderRadius>,
+                Option<&CornerStyle>,
+                Option<&Parent>,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            Without<CalculatedSize>,
+        >,
+    >,
+    parent_query: Extract<Query<&Node>>,
+    parent_width_fallback: Extract<Res<ParentWidthFallback>>,
+    window_query: Extract<Query<&Window, With<PrimaryWindow>>>,
+) {
+    let viewport_width = window_query
+        .get_single()
+        .map(|window| window.width())
+        .unwrap_or(0.);
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        if let Ok((
+            node,
+            global_transform,
+            decoration,
+            border,
+            outline,
+            radius,
+            corner_style,
+            parent,
+            visibility,
+            clip,
+        )) = uinode_query.get(*entity)
+        {
+            // Skip invisible nodes
+            if !visibility.is_visible() || node.size().x <= 0. || node.size().y <= 0. {
+                continue;
+            }
+
+            let parent_width = parent
+                .and_then(|parent| parent_query.get(parent.get()).ok())
+                .map(|parent_node| parent_node.size().x)
+                .unwrap_or_else(|| parent_width_fallback.width(node.size(), viewport_width));
+            let context = DecorationContext {
+                entity: *entity,
+                size: node.size(),
+                parent_width,
+                border,
+                outline,
+                radius,
+                corner_style,
+            };
+            quads.clear();
+            decoration.decorate(&context, &mut quads);
+
+            let transform = global_transform.compute_matrix();
+            for quad in quads.iter() {
+                if quad.color.a() == 0. || quad.rect.is_empty() {
+                    continue;
+                }
+                extracted_uinodes.uinodes.push(extracted_quad(
+                    stack_index,
+                    transform,
+                    quad.rect,
+                    quad.color,
+                    None,
+                    clip.map(|clip| clip.clip),
+                ));
+            }
+        }
+    }
+}
+
+/// A colored rect drawn at a fixed position relative to a UI node, see [`DecorationRects`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect, FromReflect)]
+pub struct DecorationRect {
+    /// Position of the rect's top left corner relative to the node's top left corner in logical pixels, with positive
+    /// y pointing down. The rect may extend outside the node.
+    pub offset: Vec2,
+    /// Size of the rect in logical pixels
+    pub size: Vec2,
+    pub color: Color,
+}
+
+impl DecorationRect {
+    pub fn new(offset: Vec2, size: Vec2, color: Color) -> Self {
+        Self {
+            offset,
+            size,
+            color,
+        }
+    }
+}
+
+/// Extra colored rects drawn for a UI node without taking part in the layout, for accent bars, notch marks or tick
+/// marks that would otherwise need extra entities.
+#[derive(Component, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+pub struct DecorationRects(pub Vec<DecorationRect>);
+
+impl DecorationRects {
+    pub fn with(mut self, rect: DecorationRect) -> Self {
+        self.0.push(rect);
+        self
+    }
+}
+
+impl UiDecoration for DecorationRects {
+    const LAYER: DecorationLayer = DecorationLayer::Border;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let origin = context.node_rect().min;
+        quads.extend(self.iter().map(|rect| DecorationQuad {
+            rect: Rect::from_corners(
+                origin + rect.offset,
+                origin + rect.offset + rect.size.max(Vec2::ZERO),
+            ),
+            color: rect.color,
+        }));
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use bevy::diagnostic::Diagnostic;
+use bevy::diagnostic::DiagnosticId;
+use bevy::diagnostic::Diagnostics;
+use bevy::prelude::*;
+use bevy::render::RenderApp;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::RenderUiSystem;
+
+use crate::radius::clip_extracted_decorations;
+use crate::radius::clip_extracted_nodes;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::DecorationLayer;
+
+/// Adds diagnostics for the number of bordered and outlined UI nodes and the number of quads the plugin extracts
+/// each frame, so dashboards can track how much work borders add to a scene.
+///
+/// The extracted quad count includes borders, outlines and every decoration drawn by the plugin, and lags one frame
+/// behind the node counts.
+#[derive(Default)]
+pub struct BorderDiagnosticsPlugin;
+
+impl BorderDiagnosticsPlugin {
+    pub const BORDERED_NODES: DiagnosticId =
+        DiagnosticId::from_u128(126662726014377707748647139269289207767);
+    pub const OUTLINED_NODES: DiagnosticId =
+        DiagnosticId::from_u128(193601164799515968791603228305470695925);
+    pub const EXTRACTED_QUADS: DiagnosticId =
+        DiagnosticId::from_u128(149895869044459302567833340163154697720);
+
+    fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
+        diagnostics.add(Diagnostic::new(
+            Self::BORDERED_NODES,
+            "bevy_ui_borders/bordered_nodes",
+            20,
+        ));
+        diagnostics.add(Diagnostic::new(
+            Self::OUTLINED_NODES,
+            "bevy_ui_borders/outlined_nodes",
+            20,
+        ));
+        diagnostics.add(Diagnostic::new(
+            Self::EXTRACTED_QUADS,
+            "bevy_ui_borders/extracted_quads",
+            20,
+        ));
+    }
+
+    fn diagnostic_system(
+        mut diagnostics: ResMut<Diagnostics>,
+        extracted_quads: Res<ExtractedQuadCount>,
+        border_query: Query<(), With<CalculatedBorder>>,
+        outline_query: Query<(), With<CalculatedOutline>>,
+    ) {
+        diagnostics.add_measurement(Self::BORDERED_NODES, || border_query.iter().count() as f64);
+        diagnostics.add_measurement(Self::OUTLINED_NODES, || outline_query.iter().count() as f64);
+        diagnostics.add_measurement(Self::EXTRACTED_QUADS, || {
+            extracted_quads.count.load(Ordering::Relaxed) as f64
+        });
+    }
+}
+
+impl Plugin for BorderDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let extracted_quads = ExtractedQuadCount::default();
+        app.insert_resource(extracted_quads.clone())
+            .add_startup_system(Self::setup_system)
+            .add_system(Self::diagnostic_system);
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app.insert_resource(extracted_quads).add_systems(
+            (
+                record_extracted_quads_start
+                    .after(RenderUiSystem::ExtractNode)
+                    .after(clip_extracted_nodes)
+                    .before(DecorationLayer::Below),
+                record_extracted_quads_end
+                    .after(DecorationLayer::Above)
+                    .after(clip_extracted_decorations),
+            )
+                .in_schedule(ExtractSchedule),
+        );
+    }
+}
+
+/// The number of quads extracted by the plugin in the last frame, shared between the main and render worlds.
+#[derive(Resource, Clone, Default)]
+struct ExtractedQuadCount {
+    /// The number of extracted UI nodes before the plugin's extraction systems ran
+    start: Arc<AtomicUsize>,
+    count: Arc<AtomicUsize>,
+}
+
+fn record_extracted_quads_start(
+    extracted_uinodes: Res<ExtractedUiNodes>,
+    extracted_quads: Res<ExtractedQuadCount>,
+) {
+    extracted_quads
+        .start
+        .store(extracted_uinodes.uinodes.len(), Ordering::Relaxed);
+}
+
+fn record_extracted_quads_end(
+    extracted_uinodes: Res<ExtractedUiNodes>,
+    extracted_quads: Res<ExtractedQuadCount>,
+) {
+    let start = extracted_quads.start.load(Ordering::Relaxed);
+    extracted_quads.count.store(
+        extracted_uinodes.uinodes.len().saturating_sub(start),
+        Ordering::Relaxed,
+    );
+}
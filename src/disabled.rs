@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::OutlineSegments;
+
+/// Marks a UI node as disabled.
+///
+/// Disabled nodes are drawn in the [`DisabledBorder`] style, and hover, press and focus highlights such as
+/// [`PickHighlight`](crate::PickHighlight), [`HoverElevation`](crate::HoverElevation) and
+/// [`FocusOutline`](crate::FocusOutline) treat them as if the pointer and focus were elsewhere.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Disabled;
+
+/// How the borders of [`Disabled`] UI nodes are drawn.
+///
+/// The style is applied when the border is drawn, so the node's own [`BorderColor`](crate::BorderColor) and
+/// layout are unchanged and come back as soon as the marker is removed. Disabled borders are drawn as plain rects,
+/// with square corners and without textures or shapes.
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct DisabledBorder {
+    /// How much of the border color's saturation is kept, from 0 for gray to 1 for unchanged
+    pub saturation: f32,
+    /// Multiplies the border color's alpha
+    pub alpha: f32,
+    /// Number of dashes along each edge, solid if 0
+    pub dashes: u32,
+}
+
+impl Default for DisabledBorder {
+    fn default() -> Self {
+        Self {
+            saturation: 0.,
+            alpha: 0.5,
+            dashes: 5,
+        }
+    }
+}
+
+impl DisabledBorder {
+    /// The color a disabled node's border of the given color is drawn in.
+    pub fn color(&self, color: Color) -> Color {
+        let [hue, saturation, lightness, alpha] = color.as_hsla_f32();
+        Color::hsla(
+            hue,
+            saturation * self.saturation.clamp(0., 1.),
+            lightness,
+            alpha * self.alpha.clamp(0., 1.),
+        )
+    }
+
+    /// The rects a disabled node's left, right, top and bottom border edges are drawn as.
+    pub fn edges(&self, edges: [Option<Rect>; 4]) -> Vec<Rect> {
+        if self.dashes == 0 {
+            edges.into_iter().flatten().collect()
+        } else {
+            OutlineSegments::dashed(self.dashes).split(edges)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use crate::DecorationContext;
+use crate::DecorationQuad;
+use crate::Disabled;
+use crate::UiDecoration;
+
+/// Whether a drag-and-drop is in progress, set by your drag handling code.
+///
+/// While `active` is set, each [`DropTargetHighlight`] node the pointer is over is highlighted. The pointer is found
+/// from the node's [`Interaction`], so the dragged node should have [`FocusPolicy::Pass`](bevy::ui::FocusPolicy)
+/// to avoid blocking the targets beneath it. To use another picking backend, set `target` to the hovered entity
+/// instead and it will replace the [`Interaction`] check.
+#[derive(Resource, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct DragState {
+    pub active: bool,
+    /// The drop target under the pointer, overriding the targets' [`Interaction`]s if set
+    pub target: Option<Entity>,
+}
+
+impl DragState {
+    /// Starts a drag.
+    pub fn start(&mut self) {
+        self.active = true;
+    }
+
+    /// Ends the drag and clears the target.
+    pub fn end(&mut self) {
+        self.active = false;
+        self.target = None;
+    }
+}
+
+/// Shows an animated dashed outline around a UI node while something is being dragged over it.
+///
+/// The outline is drawn outside the node's border box and the dashes march clockwise around it. [`Disabled`] nodes
+/// aren't highlighted.
+/// See [`DragState`] for how drags are tracked.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct DropTargetHighlight {
+    pub color: Color,
+    /// Thickness of the outline in logical pixels
+    pub thickness: f32,
+    /// Length of each dash in logical pixels
+    pub dash: f32,
+    /// Length of the gap between dashes in logical pixels
+    pub gap: f32,
+    /// Distance the dashes move each second in logical pixels
+    pub speed: f32,
+    #[reflect(ignore)]
+    shown: bool,
+    #[reflect(ignore)]
+    phase: f32,
+}
+
+impl Default for DropTargetHighlight {
+    fn default() -> Self {
+        Self {
+            color: Color::YELLOW,
+            thickness: 2.,
+            dash: 6.,
+            gap: 4.,
+            speed: 20.,
+            shown: false,
+            phase: 0.,
+        }
+    }
+}
+
+impl DropTargetHighlight {
+    pub fn new(color: Color, thickness: f32) -> Self {
+        Self {
+            color,
+            thickness,
+            ..Default::default()
+        }
+    }
+
+    /// Is the outline being drawn.
+    pub fn is_shown(&self) -> bool {
+        self.shown
+    }
+}
+
+impl UiDecoration for DropTargetHighlight {
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        if !self.shown || self.thickness <= 0. || self.color.a() == 0. {
+            return;
+        }
+        let node = context.node_rect();
+        let outer = node.inset(self.thickness);
+        let period = self.dash.max(1.) + self.gap.max(0.);
+        // The outline is a clockwise path around the node, starting from the top left corner
+        let lengths = [outer.width(), node.height(), outer.width(), node.height()];
+        let mut start = 0.;
+        for (edge, length) in lengths.into_iter().enumerate() {
+            // The first dash that might overlap this edge
+            let mut dash_start = ((start - self.phase) / period).floor() * period + self.phase;
+            while dash_start < start + length {
+                let a = (dash_start - start).max(0.);
+                let b = (dash_start + self.dash.max(1.) - start).min(length);
+                if a < b {
+                    quads.push(DecorationQuad {
+                        rect: path_span(node, outer, edge, a, b),
+                        color: self.color,
+                    });
+                }
+                dash_start += period;
+            }
+            start += length;
+        }
+    }
+}
+
+/// The rect covering the span from `a` to `b` along an edge of the outline between `node` and `outer`.
+///
+/// Edges are numbered clockwise from the top, and the top and bottom edges include the corners.
+fn path_span(node: Rect, outer: Rect, edge: usize, a: f32, b: f32) -> Rect {
+    match edge {
+        0 => Rect::new(outer.min.x + a, outer.min.y, outer.min.x + b, node.min.y),
+        1 => Rect::new(node.max.x, node.min.y + a, outer.max.x, node.min.y + b),
+        2 => Rect::new(outer.max.x - b, node.max.y, outer.max.x - a, outer.max.y),
+        _ => Rect::new(outer.min.x, node.max.y - b, node.min.x, node.max.y - a),
+    }
+}
+
+pub(crate) fn update_drop_target_highlights(
+    time: Res<Time>,
+    drag_state: Res<DragState>,
+    mut highlight_query: Query<(
+        Entity,
+        &mut DropTargetHighlight,
+        Option<&Interaction>,
+        Option<&Disabled>,
+    )>,
+) {
+    for (entity, mut highlight, interaction, disabled) in highlight_query.iter_mut() {
+        let hovered = match drag_state.target {
+            Some(target) => target == entity,
+            None => interaction == Some(&Interaction::Hovered),
+        };
+        let shown = drag_state.active && hovered && disabled.is_none();
+        if highlight.shown != shown {
+            highlight.shown = shown;
+        }
+        if shown {
+            let period = highlight.dash.max(1.) + highlight.gap.max(0.);
+            highlight.phase =
+                (highlight.phase + highlight.speed * time.delta_seconds()).rem_euclid(period);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+
+use crate::animation::lerp_color;
+use crate::BorderColor;
+use crate::Disabled;
+use crate::Easing;
+use crate::Outline;
+use crate::OutlineShadow;
+
+/// The node's own values of the properties a [`HoverElevation`] changes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct ElevationBase {
+    outline: Option<UiRect>,
+    border_color: Option<Color>,
+    shadow_blur: Option<f32>,
+}
+
+/// Lifts a UI node while it is hovered or pressed, like a card in a card grid: its outline grows, its border lightens
+/// and its [`OutlineShadow`] spreads.
+///
+/// The hover state is read from the node's [`Interaction`], and [`Disabled`] nodes stay lowered. The node eases towards full elevation over `duration`
+/// seconds while hovered and back down when it isn't, and its own values are restored once it is fully lowered.
+/// Only properties the node has components for are changed, so give it an [`Outline`], [`BorderColor`] and
+/// [`OutlineShadow`] for the full effect. Pixel outline thicknesses grow, other units are left unchanged.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct HoverElevation {
+    /// Logical pixels added to each edge of the outline at full elevation
+    pub outline_growth: f32,
+    /// How far the border color moves towards white at full elevation, from 0 to 1
+    pub border_lighten: f32,
+    /// Logical pixels added to the blur of the node's shadow at full elevation
+    pub shadow_growth: f32,
+    /// Seconds to rise to full elevation or to fall back
+    pub duration: f32,
+    pub easing: Easing,
+    /// Linear progress towards full elevation, from 0 to 1
+    #[reflect(ignore)]
+    elevation: f32,
+    #[reflect(ignore)]
+    base: Option<ElevationBase>,
+}
+
+impl Default for HoverElevation {
+    fn default() -> Self {
+        Self {
+            outline_growth: 2.,
+            border_lighten: 0.25,
+            shadow_growth: 6.,
+            duration: 0.15,
+            easing: Easing::QuadraticOut,
+            elevation: 0.,
+            base: None,
+        }
+    }
+}
+
+impl HoverElevation {
+    pub fn new(outline_growth: f32, border_lighten: f32, shadow_growth: f32) -> Self {
+        Self {
+            outline_growth,
+            border_lighten,
+            shadow_growth,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The eased elevation, from 0 when lowered to 1 when fully raised.
+    pub fn elevation(&self) -> f32 {
+        self.easing.ease(self.elevation)
+    }
+}
+
+fn grow_val(value: Val, growth: f32) -> Val {
+    match value {
+        Val::Px(px) => Val::Px((px + growth).max(0.)),
+        value => value,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_hover_elevations(
+    time: Res<Time>,
+    mut elevation_query: Query<(
+        &mut HoverElevation,
+        Option<&Interaction>,
+        Option<&Disabled>,
+        Option<&mut Outline>,
+        Option<&mut BorderColor>,
+        Option<&mut OutlineShadow>,
+    )>,
+) {
+    for (mut hover, interaction, disabled, outline, border_color, shadow) in
+        elevation_query.iter_mut()
+    {
+        let target = match (interaction, disabled) {
+            (Some(Interaction::Hovered | Interaction::Clicked), None) => 1.,
+            _ => 0.,
+        };
+        if hover.elevation == target && (target == 1. || hover.base.is_none()) {
+            continue;
+        }
+        let hover = hover.as_mut();
+        let step = if hover.duration <= 0. {
+            1.
+        } else {
+            time.delta_seconds() / hover.duration
+        };
+        hover.elevation = if hover.elevation < target {
+            (hover.elevation + step).min(target)
+        } else {
+            (hover.elevation - step).max(target)
+        };
+
+        let base = *hover.base.get_or_insert(ElevationBase {
+            outline: outline.as_ref().map(|outline| outline.thickness),
+            border_color: border_color.as_ref().map(|color| color.0),
+            shadow_blur: shadow.as_ref().map(|shadow| shadow.blur),
+        });
+        let t = hover.elevation();
+        if let (Some(mut outline), Some(thickness)) = (outline, base.outline) {
+            let growth = hover.outline_growth * t;
+            outline.thickness = UiRect::new(
+                grow_val(thickness.left, growth),
+                grow_val(thickness.right, growth),
+                grow_val(thickness.top, growth),
+                grow_val(thickness.bottom, growth),
+            );
+        }
+        if let (Some(mut border_color), Some(color)) = (border_color, base.border_color) {
+            let lighten = hover.border_lighten.clamp(0., 1.) * t;
+            border_color.0 = lerp_color(color, Color::WHITE.with_a(color.a()), lighten);
+        }
+        if let (Some(mut shadow), Some(blur)) = (shadow, base.shadow_blur) {
+            shadow.blur = blur + hover.shadow_growth * t;
+        }
+        if hover.elevation == 0. {
+            hover.base = None;
+        }
+    }
+}
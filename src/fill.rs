@@ -0,0 +1,402 @@
+use bevy::math::Vec2Swizzles;
+use bevy::prelude::*;
+use bevy::ui::ExtractedUiNodes;
+
+use crate::extracted_quad;
+
+/// How a texture is laid out along the length of a border edge.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum TileMode {
+    /// A single copy of the image is stretched over the whole edge.
+    Stretch,
+    /// The image is repeated along the edge, the final tile is cut short.
+    #[default]
+    Repeat,
+    /// The image is repeated along the edge and scaled so that a whole number of tiles fit.
+    Round,
+}
+
+/// Textured fill for a UI node's border edges.
+///
+/// The fill is tinted by the node's [`BorderColor`](crate::BorderColor), or drawn untinted if it has none.
+#[derive(Component, Clone, Debug, Reflect, FromReflect)]
+#[reflect(Component, Default)]
+pub enum BorderFill {
+    /// Fill each edge with `image`, scaled so its height matches the thickness of the edge.
+    Texture {
+        image: Handle<Image>,
+        tile: TileMode,
+    },
+    /// Fill each edge with the sprite at `index` in `atlas`, scaled so its height matches the thickness of the edge.
+    Atlas {
+        atlas: Handle<TextureAtlas>,
+        index: usize,
+        tile: TileMode,
+    },
+}
+
+impl Default for BorderFill {
+    fn default() -> Self {
+        Self::Texture {
+            image: Default::default(),
+            tile: Default::default(),
+        }
+    }
+}
+
+impl BorderFill {
+    pub fn texture(image: Handle<Image>, tile: TileMode) -> Self {
+        Self::Texture { image, tile }
+    }
+
+    pub fn atlas(atlas: Handle<TextureAtlas>, index: usize, tile: TileMode) -> Self {
+        Self::Atlas { atlas, index, tile }
+    }
+
+    /// Looks up the image region this fill samples from.
+    ///
+    /// Returns `None` if the image or atlas hasn't finished loading, or the atlas index is out of range.
+    pub(crate) fn resolve<'a>(
+        &'a self,
+        images: &Assets<Image>,
+        atlases: &'a Assets<TextureAtlas>,
+    ) -> Option<FillTexture<'a>> {
+        match self {
+            BorderFill::Texture { image, tile } => {
+                let image_size = images.get(image)?.size();
+                Some(FillTexture {
+                    image,
+                    region: Rect {
+                        min: Vec2::ZERO,
+                        max: image_size,
+                    },
+                    image_size,
+                    tile: *tile,
+                })
+            }
+            BorderFill::Atlas { atlas, index, tile } => {
+                let atlas = atlases.get(atlas)?;
+                if !images.contains(&atlas.texture) {
+                    return None;
+                }
+                Some(FillTexture {
+                    image: &atlas.texture,
+                    region: *atlas.textures.get(*index)?,
+                    image_size: atlas.size,
+                    tile: *tile,
+                })
+            }
+        }
+    }
+}
+
+/// Textured fill for a UI node's outline edges.
+///
+/// The fill is tinted by the node's [`OutlineColor`](crate::OutlineColor).
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+pub struct OutlineFill(pub BorderFill);
+
+impl From<BorderFill> for OutlineFill {
+    fn from(fill: BorderFill) -> Self {
+        Self(fill)
+    }
+}
+
+/// A fill used for every border and outline that has no [`BorderFill`] or [`OutlineFill`] of its own, in place of
+/// plain white.
+///
+/// Use it to give all borders and outlines a texture treatment at once, like a subtle noise texture tinted by each
+/// node's colors. Borders and outlines are drawn plain while the image loads, and rounded ones are always drawn plain.
+#[derive(Resource, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Default)]
+pub struct DefaultBorderFill(pub Option<BorderFill>);
+
+impl DefaultBorderFill {
+    pub fn texture(image: Handle<Image>, tile: TileMode) -> Self {
+        Self(Some(BorderFill::texture(image, tile)))
+    }
+}
+
+/// The region of a loaded image that a fill samples from.
+pub(crate) struct FillTexture<'a> {
+    pub image: &'a Handle<Image>,
+    /// The sub-rect of the image containing the fill texture, in pixels
+    pub region: Rect,
+    pub image_size: Vec2,
+    pub tile: TileMode,
+}
+
+/// Splits `edge` into a list of quads textured from the `region` of an image.
+///
+/// Returns each quad's rect in node space paired with the sub-rect of the image it samples, in pixels.
+/// Horizontal edges tile along the x axis, vertical edges along the y axis.
+pub(crate) fn tile_edge(
+    edge: Rect,
+    horizontal: bool,
+    region: Rect,
+    tile: TileMode,
+) -> Vec<(Rect, Rect)> {
+    if tile == TileMode::Stretch {
+        return vec![(edge, region)];
+    }
+
+    // work in (along, across) coordinates so both orientations share the same code
+    let swizzle = |v: Vec2| if horizontal { v } else { v.yx() };
+    let edge_size = swizzle(edge.size());
+    let image_size = swizzle(region.size());
+    if edge_size.y <= 0. || image_size.x <= 0. || image_size.y <= 0. {
+        return vec![];
+    }
+
+    let scale = edge_size.y / image_size.y;
+    let mut tile_length = image_size.x * scale;
+    let count = if tile == TileMode::Round {
+        let count = (edge_size.x / tile_length).round().max(1.);
+        tile_length = edge_size.x / count;
+        count as usize
+    } else {
+        (edge_size.x / tile_length).ceil() as usize
+    };
+
+    let origin = swizzle(edge.min);
+    let mut quads = Vec::with_capacity(count);
+    for i in 0..count {
+        let start = i as f32 * tile_length;
+        let length = tile_length.min(edge_size.x - start);
+        let min = origin + Vec2::new(start, 0.);
+        let max = min + Vec2::new(length, edge_size.y);
+        let source_max = Vec2::new(image_size.x * length / tile_length, image_size.y);
+        quads.push((
+            Rect::from_corners(swizzle(min), swizzle(max)),
+            Rect::from_corners(region.min, region.min + swizzle(source_max)),
+        ));
+    }
+    quads
+}
+
+/// A clockwise rotation of a fill texture in quarter turns.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum TextureRotation {
+    #[default]
+    None,
+    Clockwise90,
+    Clockwise180,
+    Clockwise270,
+}
+
+/// How a fill texture is oriented on one edge. The texture is flipped first and then rotated.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub struct EdgeTextureTransform {
+    pub rotation: TextureRotation,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+impl EdgeTextureTransform {
+    pub const IDENTITY: Self = Self {
+        rotation: TextureRotation::None,
+        flip_x: false,
+        flip_y: false,
+    };
+
+    pub const fn rotated(rotation: TextureRotation) -> Self {
+        Self {
+            rotation,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+}
+
+/// Orients the [`BorderFill`] and [`OutlineFill`] textures on each edge of a UI node.
+///
+/// Without this component textures are drawn upright on every edge. A texture rotated a quarter turn tiles along
+/// the edge in its own horizontal direction, so [`FillTransform::outward`] lets a single edge texture wrap around
+/// all four sides.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct FillTransform {
+    pub left: EdgeTextureTransform,
+    pub right: EdgeTextureTransform,
+    pub top: EdgeTextureTransform,
+    pub bottom: EdgeTextureTransform,
+}
+
+impl FillTransform {
+    /// Rotates a texture drawn for the top edge so that its top side faces away from the node on every edge.
+    pub fn outward() -> Self {
+        Self {
+            left: EdgeTextureTransform::rotated(TextureRotation::Clockwise270),
+            right: EdgeTextureTransform::rotated(TextureRotation::Clockwise90),
+            top: EdgeTextureTransform::IDENTITY,
+            bottom: EdgeTextureTransform::rotated(TextureRotation::Clockwise180),
+        }
+    }
+
+    /// The transforms in left, right, top, bottom order.
+    pub fn edges(&self) -> [EdgeTextureTransform; 4] {
+        [self.left, self.right, self.top, self.bottom]
+    }
+}
+
+/// Mirrors `rect` within `region` along the flipped axes.
+fn mirror_within(rect: Rect, region: Rect, flip_x: bool, flip_y: bool) -> Rect {
+    let mut mirrored = rect;
+    if flip_x {
+        mirrored.min.x = region.min.x + region.max.x - rect.max.x;
+        mirrored.max.x = region.min.x + region.max.x - rect.min.x;
+    }
+    if flip_y {
+        mirrored.min.y = region.min.y + region.max.y - rect.max.y;
+        mirrored.max.y = region.min.y + region.max.y - rect.min.y;
+    }
+    mirrored
+}
+
+/// Rotates `point` clockwise about the origin by the given number of quarter turns.
+fn rotate_quarters(point: Vec2, quarters: u32) -> Vec2 {
+    match quarters % 4 {
+        0 => point,
+        1 => Vec2::new(-point.y, point.x),
+        2 => -point,
+        _ => Vec2::new(point.y, -point.x),
+    }
+}
+
+/// An exact clockwise quarter turn, so Bevy doesn't mistake it for an arbitrary rotation.
+const QUARTER_TURN: Mat4 = Mat4::from_cols(Vec4::Y, Vec4::NEG_X, Vec4::Z, Vec4::W);
+
+/// Pushes one tile of an edge texture, in the texture's own frame centered on `center` in the node's local space
+/// and turned a quarter clockwise if `quarter_turn` is set.
+#[allow(clippy::too_many_arguments)]
+fn push_tile(
+    extracted_uinodes: &mut ExtractedUiNodes,
+    stack_index: usize,
+    transform: Mat4,
+    center: Vec2,
+    size: Vec2,
+    source: Rect,
+    (flip_x, flip_y): (bool, bool),
+    quarter_turn: bool,
+    color: Color,
+    texture: &FillTexture,
+    clip: Option<Rect>,
+) {
+    let (mut center, mut size, mut source, mut clip) = (center, size, source, clip);
+    if quarter_turn {
+        // Bevy clips quads along the window axes, which doesn't match the texture coordinates of a turned quad,
+        // so trim it to the clip rect here instead
+        if let Some(clip_rect) = clip.take() {
+            let world_center = transform.transform_point3(center.extend(0.)).truncate();
+            let visible = Rect::from_center_size(world_center, size.yx()).intersect(clip_rect);
+            if visible.is_empty() {
+                return;
+            }
+            // The visible part in the tile's own frame, relative to its center
+            let part = Rect::from_corners(
+                rotate_quarters(visible.min - world_center, 3),
+                rotate_quarters(visible.max - world_center, 3),
+            );
+            let fraction = Rect {
+                min: (part.min + 0.5 * size) / size,
+                max: (part.max + 0.5 * size) / size,
+            };
+            let fraction = mirror_within(fraction, Rect::new(0., 0., 1., 1.), flip_x, flip_y);
+            source = Rect {
+                min: source.min + fraction.min * source.size(),
+                max: source.min + fraction.max * source.size(),
+            };
+            center += rotate_quarters(part.center(), 1);
+            size = part.size();
+        }
+    }
+    let (quad, transform) = if quarter_turn {
+        (
+            Rect::from_center_size(Vec2::ZERO, size),
+            transform * Mat4::from_translation(center.extend(0.)) * QUARTER_TURN,
+        )
+    } else {
+        (Rect::from_center_size(center, size), transform)
+    };
+    let mut extracted = extracted_quad(
+        stack_index,
+        transform,
+        quad,
+        color,
+        Some((texture.image, source, texture.image_size)),
+        clip,
+    );
+    extracted.flip_x = flip_x;
+    extracted.flip_y = flip_y;
+    extracted_uinodes.uinodes.push(extracted);
+}
+
+/// Pushes an extracted quad for each edge, tiling the edges with `texture` if there is one.
+///
+/// Textures are oriented on each edge by the matching transform in `orientation`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn extract_edges(
+    extracted_uinodes: &mut ExtractedUiNodes,
+    stack_index: usize,
+    transform: Mat4,
+    edges: &[Option<Rect>; 4],
+    color: Color,
+    texture: Option<&FillTexture>,
+    orientation: [EdgeTextureTransform; 4],
+    clip: Option<Rect>,
+) {
+    for (i, edge) in edges.iter().enumerate() {
+        let Some(edge) = *edge else {
+            continue;
+        };
+        match texture {
+            Some(texture) => {
+                let EdgeTextureTransform {
+                    rotation,
+                    flip_x,
+                    flip_y,
+                } = orientation[i];
+                let quarters = rotation as u32;
+                let quarter_turn = quarters % 2 == 1;
+                // A half turn is the same as flipping both axes
+                let half_turn = 2 <= quarters;
+                let flips = (flip_x ^ half_turn, flip_y ^ half_turn);
+                // Tile the edge in the texture's own frame, then turn the tiles into place about the edge's center
+                let center = edge.center();
+                let horizontal = (2 <= i) ^ quarter_turn;
+                let frame = if quarter_turn {
+                    Rect::from_center_size(center, edge.size().yx())
+                } else {
+                    edge
+                };
+                for (quad, source) in tile_edge(frame, horizontal, texture.region, texture.tile) {
+                    let source = mirror_within(source, texture.region, flips.0, flips.1);
+                    push_tile(
+                        extracted_uinodes,
+                        stack_index,
+                        transform,
+                        center + rotate_quarters(quad.center() - center, quarters),
+                        quad.size(),
+                        source,
+                        flips,
+                        quarter_turn,
+                        color,
+                        texture,
+                        clip,
+                    );
+                }
+            }
+            None => {
+                extracted_uinodes.uinodes.push(extracted_quad(
+                    stack_index,
+                    transform,
+                    edge,
+                    color,
+                    None,
+                    clip,
+                ));
+            }
+        }
+    }
+}
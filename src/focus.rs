@@ -0,0 +1,159 @@
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::Disabled;
+use crate::OutlineColor;
+
+/// Marks the UI node that currently has focus.
+///
+/// Bevy has no built-in focus system, insert and remove this from your own navigation code.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Focused;
+
+/// The kind of input the user last interacted with, maintained by the plugin.
+#[derive(Resource, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub enum FocusInputSource {
+    /// A mouse button or touch
+    #[default]
+    Pointer,
+    /// A keyboard key or gamepad button
+    Keyboard,
+}
+
+/// When a [`FocusOutline`] is shown.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum FocusOutlineMode {
+    /// Whenever the node is [`Focused`]
+    Always,
+    /// Only when the node is [`Focused`] and the last input came from a keyboard or gamepad,
+    /// like the CSS `:focus-visible` pseudo-class
+    #[default]
+    FocusVisible,
+}
+
+/// Sets the node's [`OutlineColor`] to `color` while it has focus and to transparent otherwise.
+///
+/// An `OutlineColor` is inserted if the node doesn't have one, so the outline isn't drawn in its
+/// [`BorderColor`](crate::BorderColor) while unfocused. The outline's thickness is taken from the node's [`Outline`](crate::Outline). [`Disabled`] nodes never show it.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct FocusOutline {
+    pub color: Color,
+    pub mode: FocusOutlineMode,
+}
+
+impl Default for FocusOutline {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            mode: FocusOutlineMode::FocusVisible,
+        }
+    }
+}
+
+impl FocusOutline {
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_mode(mut self, mode: FocusOutlineMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Whether the outline is shown for a node with the given focus state.
+    pub fn is_visible(&self, focused: bool, input_source: FocusInputSource) -> bool {
+        focused
+            && (self.mode == FocusOutlineMode::Always || input_source == FocusInputSource::Keyboard)
+    }
+}
+
+/// Sent when a focus or selection outline appears around a UI node, for example to play a navigation sound or rumble
+/// a controller as the selection moves.
+///
+/// Sent by [`FocusOutline`], the [`SelectionCursor`](crate::SelectionCursor), [`SelectionGroup`](crate::SelectionGroup)s
+/// and [`SlidingOutline`](crate::SlidingOutline)s, which report the node they slide to.
+#[derive(Copy, Clone, Debug)]
+pub struct OutlineShown {
+    pub entity: Entity,
+}
+
+/// Sent when a focus or selection outline is taken off a UI node, see [`OutlineShown`].
+#[derive(Copy, Clone, Debug)]
+pub struct OutlineHidden {
+    pub entity: Entity,
+}
+
+/// Updates [`FocusInputSource`] from this frame's input.
+///
+/// The input resources are optional so the plugin still works in apps without the input plugin.
+pub(crate) fn track_focus_input_source(
+    mut input_source: ResMut<FocusInputSource>,
+    keys: Option<Res<Input<KeyCode>>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    touches: Option<Res<Touches>>,
+) {
+    let pointer = mouse_buttons.is_some_and(|buttons| buttons.get_just_pressed().next().is_some())
+        || touches.is_some_and(|touches| touches.any_just_pressed());
+    let keyboard = keys.is_some_and(|keys| keys.get_just_pressed().next().is_some())
+        || gamepad_buttons.is_some_and(|buttons| buttons.get_just_pressed().next().is_some());
+
+    let new_source = if pointer {
+        FocusInputSource::Pointer
+    } else if keyboard {
+        FocusInputSource::Keyboard
+    } else {
+        return;
+    };
+    if *input_source != new_source {
+        *input_source = new_source;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_focus_outlines(
+    mut commands: Commands,
+    input_source: Res<FocusInputSource>,
+    mut shown: Local<HashSet<Entity>>,
+    mut shown_events: EventWriter<OutlineShown>,
+    mut hidden_events: EventWriter<OutlineHidden>,
+    mut focus_query: Query<(
+        Entity,
+        &FocusOutline,
+        Option<&Focused>,
+        Option<&Disabled>,
+        Option<&mut OutlineColor>,
+    )>,
+) {
+    for (entity, focus_outline, focused, disabled, outline_color) in focus_query.iter_mut() {
+        let visible =
+            disabled.is_none() && focus_outline.is_visible(focused.is_some(), *input_source);
+        if visible && shown.insert(entity) {
+            shown_events.send(OutlineShown { entity });
+        } else if !visible && shown.remove(&entity) {
+            hidden_events.send(OutlineHidden { entity });
+        }
+        let color = if visible {
+            focus_outline.color
+        } else {
+            Color::NONE
+        };
+        match outline_color {
+            Some(mut outline_color) => {
+                if outline_color.0 != color {
+                    outline_color.0 = color;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(OutlineColor(color));
+            }
+        }
+    }
+}
@@ -0,0 +1,438 @@
+//! Border geometry math with no dependency on Bevy's renderer, ECS or windows, for layout tests and tools that run
+//! without a render app. It only uses Bevy's math types and the UI's `Val`.
+//!
+//! All geometry is in a UI node's local space, centered on the node with y pointing down.
+
+use bevy::math::Rect;
+use bevy::math::Vec2;
+use bevy::ui::Val;
+
+/// Resolves a border or outline thickness in logical pixels. Percentages are of `basis`, usually the width of the
+/// node's parent.
+///
+/// Negative thicknesses are clamped to zero. NaN and infinite values, and percentages of a NaN or infinite `basis`,
+/// resolve to zero.
+pub fn resolve_thickness(value: Val, basis: f32) -> f32 {
+    let thickness = match value {
+        Val::Auto | Val::Undefined => 0.,
+        Val::Px(px) => px,
+        Val::Percent(percent) => basis * percent / 100.,
+    };
+    finite_or_zero(thickness).max(0.)
+}
+
+/// Whether `value` is `Val::Px` or `Val::Percent` with a NaN or infinite length, which [`resolve_thickness`] ignores.
+pub fn is_non_finite(value: Val) -> bool {
+    match value {
+        Val::Px(length) | Val::Percent(length) => !length.is_finite(),
+        Val::Auto | Val::Undefined => false,
+    }
+}
+
+/// Whether `value` is a negative `Val::Px` or `Val::Percent`, which [`resolve_thickness`] clamps to zero.
+pub fn is_negative(value: Val) -> bool {
+    match value {
+        Val::Px(length) | Val::Percent(length) => length < 0.,
+        Val::Auto | Val::Undefined => false,
+    }
+}
+
+pub(crate) fn finite_or_zero(value: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        0.
+    }
+}
+
+/// The size and thicknesses with any NaN or infinite values replaced by zero, so they can't produce NaN rects.
+fn sanitize(size: Vec2, thickness: [f32; 4]) -> (Vec2, [f32; 4]) {
+    (
+        Vec2::new(finite_or_zero(size.x), finite_or_zero(size.y)),
+        thickness.map(finite_or_zero),
+    )
+}
+
+/// The left, right, top and bottom edges of the frame between the rects from `min` to `max` and from `inner_min` to
+/// `inner_max`. The left and right edges cover the corners.
+pub const fn edge_rects(min: Vec2, max: Vec2, inner_min: Vec2, inner_max: Vec2) -> [Rect; 4] {
+    [
+        // Left
+        Rect {
+            min,
+            max: Vec2::new(inner_min.x, max.y),
+        },
+        // Right
+        Rect {
+            min: Vec2::new(inner_max.x, min.y),
+            max,
+        },
+        // Top
+        Rect {
+            min: Vec2::new(inner_min.x, min.y),
+            max: Vec2::new(inner_max.x, inner_min.y),
+        },
+        // Bottom
+        Rect {
+            min: Vec2::new(inner_min.x, inner_max.y),
+            max: Vec2::new(inner_max.x, max.y),
+        },
+    ]
+}
+
+/// The quads covering the frame between `outer` and the rounded rect `inner`, as drawn by the instanced border
+/// pipeline, which discards the pixels of each quad outside the frame.
+///
+/// The first four are the edges from [`edge_rects`]. The other four cover the part of each of `inner`'s corners that
+/// lies outside its curve, in top left, top right, bottom right, bottom left order. Each is as large as the corner's
+/// radius, but no larger than its quarter of `inner`, so none of the quads overlap.
+pub fn frame_quads(outer: Rect, inner: &RoundedRect) -> [Rect; 8] {
+    let Rect { min, max } = inner.rect;
+    let half_size = (0.5 * inner.rect.size()).max(Vec2::ZERO);
+    let [top_left, top_right, bottom_right, bottom_left] = inner
+        .radii
+        .map(|radius| Vec2::splat(radius.max(0.)).min(half_size));
+    let [left, right, top, bottom] = edge_rects(outer.min, outer.max, min, max);
+    [
+        left,
+        right,
+        top,
+        bottom,
+        Rect::from_corners(min, min + top_left),
+        Rect::from_corners(
+            Vec2::new(max.x - top_right.x, min.y),
+            Vec2::new(max.x, min.y + top_right.y),
+        ),
+        Rect::from_corners(max - bottom_right, max),
+        Rect::from_corners(
+            Vec2::new(min.x, max.y - bottom_left.y),
+            Vec2::new(min.x + bottom_left.x, max.y),
+        ),
+    ]
+}
+
+/// The edges of a border with the given left, right, top and bottom thickness around a node of the given size, and
+/// the area it encloses.
+///
+/// For a non-negative size and thicknesses the result holds these invariants, which `tests/edge_properties.rs` checks
+/// against generated inputs:
+/// * every edge and the enclosed area lie within the node's bounds,
+/// * no two edges overlap, and no edge overlaps the enclosed area,
+/// * the edges and the enclosed area together cover the node exactly,
+/// * an edge is `None` if it has no area, so every edge of a node with no width or height is `None`.
+///
+/// Edges are trimmed so they don't overlap on nodes too small for their border. NaN and infinite sizes and
+/// thicknesses are treated as zero.
+pub fn border_edges(size: Vec2, thickness: [f32; 4]) -> ([Option<Rect>; 4], Rect) {
+    let (size, [left, right, top, bottom]) = sanitize(size, thickness);
+    let max = 0.5 * size;
+    let min = -max;
+    let inner_min = (min + Vec2::new(left, top)).min(max);
+    let inner_max = (max - Vec2::new(right, bottom)).max(inner_min);
+    let edges = edge_rects(min, max, inner_min, inner_max)
+        .map(|edge| (edge.min.x < edge.max.x && edge.min.y < edge.max.y).then_some(edge));
+    (
+        edges,
+        Rect {
+            min: inner_min,
+            max: inner_max,
+        },
+    )
+}
+
+/// The edges of an outline around a node of the given size, in the node's local space.
+///
+/// `thickness` is the left, right, top and bottom thickness in logical pixels. An edge is `None` if it has no area.
+/// NaN and infinite sizes and thicknesses are treated as zero.
+pub fn outline_edges(size: Vec2, thickness: [f32; 4]) -> [Option<Rect>; 4] {
+    let (size, [left, right, top, bottom]) = sanitize(size, thickness);
+    // calculate outline rects, ensuring that they don't overlap
+    let half_size = 0.5 * size;
+    let min = -Vec2::new(half_size.x + left, half_size.y + top);
+    let max = Vec2::new(half_size.x + right, half_size.y + bottom);
+    let inner_min = min + Vec2::new(left, top);
+    let inner_max = (max - Vec2::new(right, bottom)).max(inner_min);
+    edge_rects(min, max, inner_min, inner_max)
+        .map(|edge| (edge.min.x < edge.max.x && edge.min.y < edge.max.y).then_some(edge))
+}
+
+/// The horizontal extent of the ellipse fitted to `rect` at height `y`, `None` outside of it.
+fn ellipse_span(rect: Rect, y: f32) -> Option<(f32, f32)> {
+    let half_size = 0.5 * rect.size();
+    if half_size.x <= 0. || half_size.y <= 0. {
+        return None;
+    }
+    let center = rect.center();
+    let t = (y - center.y) / half_size.y;
+    if 1. <= t.abs() {
+        return None;
+    }
+    let half_width = half_size.x * (1. - t * t).sqrt();
+    Some((center.x - half_width, center.x + half_width))
+}
+
+/// Rects covering the area inside the ellipse fitted to `outer` and outside the ellipse fitted to `inner`, one logical
+/// pixel tall.
+pub fn ellipse_ring_strips(outer: Rect, inner: Rect) -> Vec<Rect> {
+    let mut rows = Vec::new();
+    push_band(&mut rows, outer.min.y, outer.max.y);
+    let mut strips = Vec::new();
+    for (y0, y1) in rows {
+        let y = 0.5 * (y0 + y1);
+        let Some((outer_min, outer_max)) = ellipse_span(outer, y) else {
+            continue;
+        };
+        match ellipse_span(inner, y) {
+            Some((inner_min, inner_max)) => {
+                if outer_min < inner_min {
+                    strips.push(Rect::new(outer_min, y0, inner_min.min(outer_max), y1));
+                }
+                if inner_max < outer_max {
+                    strips.push(Rect::new(inner_max.max(outer_min), y0, outer_max, y1));
+                }
+            }
+            None => strips.push(Rect::new(outer_min, y0, outer_max, y1)),
+        }
+    }
+    strips
+}
+
+/// A rect with rounded or chamfered corners.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RoundedRect {
+    pub rect: Rect,
+    /// Corner radii in top left, top right, bottom right, bottom left order. For a chamfered rect these are the
+    /// lengths cut from each edge.
+    pub radii: [f32; 4],
+    /// Corners are cut with a straight 45° bevel instead of a curve
+    pub chamfered: bool,
+}
+
+impl RoundedRect {
+    pub fn new(rect: Rect, radii: [f32; 4]) -> Self {
+        Self {
+            rect,
+            radii,
+            chamfered: false,
+        }
+    }
+
+    /// A rect with its corners cut by 45° bevels, `sizes` long on each edge.
+    pub fn chamfered(rect: Rect, sizes: [f32; 4]) -> Self {
+        Self {
+            rect,
+            radii: sizes,
+            chamfered: true,
+        }
+    }
+
+    /// How much a corner shrinks or grows when an edge of the given thickness is removed or added. A bevel moves
+    /// along its diagonal, so it changes by less than a curve.
+    fn corner_change(&self, thickness: f32) -> f32 {
+        if self.chamfered {
+            (2. - std::f32::consts::SQRT_2) * thickness
+        } else {
+            thickness
+        }
+    }
+
+    /// Shrinks the rect by the given left, right, top and bottom thicknesses, reducing each corner's radius by the
+    /// larger of its two adjacent thicknesses. Bevels keep their distance from the outer bevel.
+    pub fn inset(&self, [left, right, top, bottom]: [f32; 4]) -> Self {
+        let rect = Rect {
+            min: self.rect.min + Vec2::new(left, top),
+            max: self.rect.max - Vec2::new(right, bottom),
+        };
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        Self {
+            rect,
+            radii: [
+                top_left - self.corner_change(left.max(top)),
+                top_right - self.corner_change(right.max(top)),
+                bottom_right - self.corner_change(right.max(bottom)),
+                bottom_left - self.corner_change(left.max(bottom)),
+            ]
+            .map(|radius| radius.max(0.)),
+            chamfered: self.chamfered,
+        }
+    }
+
+    /// Grows the rect by the given left, right, top and bottom thicknesses, increasing each corner's radius by the
+    /// larger of its two adjacent thicknesses. Corners with no radius stay square.
+    pub fn outset(&self, [left, right, top, bottom]: [f32; 4]) -> Self {
+        let rect = Rect {
+            min: self.rect.min - Vec2::new(left, top),
+            max: self.rect.max + Vec2::new(right, bottom),
+        };
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        let grow = |radius: f32, thickness: f32| {
+            if 0. < radius {
+                radius + self.corner_change(thickness)
+            } else {
+                0.
+            }
+        };
+        Self {
+            rect,
+            radii: [
+                grow(top_left, left.max(top)),
+                grow(top_right, right.max(top)),
+                grow(bottom_right, right.max(bottom)),
+                grow(bottom_left, left.max(bottom)),
+            ],
+            chamfered: self.chamfered,
+        }
+    }
+
+    pub fn is_rounded(&self) -> bool {
+        self.radii.iter().any(|radius| 0. < *radius)
+    }
+
+    /// Height of the top and bottom bands containing the curved corners.
+    fn bands(&self) -> (f32, f32) {
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        let height = self.rect.height().max(0.);
+        let top = top_left.max(top_right).min(height);
+        let bottom = bottom_left.max(bottom_right).min(height - top);
+        (top, bottom)
+    }
+
+    /// The horizontal span covered by the rounded rect at height `y`, `None` if `y` is outside it.
+    pub fn span_at(&self, y: f32) -> Option<(f32, f32)> {
+        let Rect { min, max } = self.rect;
+        if y < min.y || max.y < y || max.x <= min.x {
+            return None;
+        }
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        let inset = |radius: f32| {
+            let dy = if y < min.y + radius {
+                min.y + radius - y
+            } else if max.y - radius < y {
+                y - (max.y - radius)
+            } else {
+                return 0.;
+            };
+            if self.chamfered {
+                dy
+            } else {
+                radius - (radius * radius - dy * dy).max(0.).sqrt()
+            }
+        };
+        let left = if y < self.rect.center().y {
+            inset(top_left)
+        } else {
+            inset(bottom_left)
+        };
+        let right = if y < self.rect.center().y {
+            inset(top_right)
+        } else {
+            inset(bottom_right)
+        };
+        let span = (min.x + left, max.x - right);
+        (span.0 < span.1).then_some(span)
+    }
+
+    /// The rows making up the shape, from top to bottom. Rows in the curved bands are one logical pixel tall and the
+    /// straight middle of the shape is a single row.
+    fn rows(&self) -> Vec<(f32, f32)> {
+        let Rect { min, max } = self.rect;
+        if max.y <= min.y {
+            return Vec::new();
+        }
+        let (top, bottom) = self.bands();
+        let mut rows = Vec::new();
+        push_band(&mut rows, min.y, min.y + top);
+        if min.y + top < max.y - bottom {
+            rows.push((min.y + top, max.y - bottom));
+        }
+        push_band(&mut rows, max.y - bottom, max.y);
+        rows
+    }
+
+    /// Rects covering the rounded rect, one for each row.
+    pub fn strips(&self) -> Vec<Rect> {
+        self.rows()
+            .into_iter()
+            .filter_map(|(y0, y1)| {
+                let (x0, x1) = self.span_at(0.5 * (y0 + y1))?;
+                Some(Rect::new(x0, y0, x1, y1))
+            })
+            .collect()
+    }
+
+    /// Rects covering the area inside this rounded rect and outside of `inner`, which should be contained by it.
+    pub fn ring_strips(&self, inner: &RoundedRect) -> Vec<Rect> {
+        let mut breaks: Vec<f32> = self
+            .rows()
+            .into_iter()
+            .chain(inner.rows())
+            .flat_map(|(y0, y1)| [y0, y1])
+            .collect();
+        breaks.sort_by(f32::total_cmp);
+        breaks.dedup();
+        let mut strips = Vec::new();
+        for pair in breaks.windows(2) {
+            let (y0, y1) = (pair[0], pair[1]);
+            if y1 <= y0 {
+                continue;
+            }
+            let y = 0.5 * (y0 + y1);
+            let Some((outer_min, outer_max)) = self.span_at(y) else {
+                continue;
+            };
+            match inner.span_at(y) {
+                Some((inner_min, inner_max)) => {
+                    if outer_min < inner_min {
+                        strips.push(Rect::new(outer_min, y0, inner_min.min(outer_max), y1));
+                    }
+                    if inner_max < outer_max {
+                        strips.push(Rect::new(inner_max.max(outer_min), y0, outer_max, y1));
+                    }
+                }
+                None => strips.push(Rect::new(outer_min, y0, outer_max, y1)),
+            }
+        }
+        strips
+    }
+
+    /// Does `rect` touch any of the curved corners.
+    pub(crate) fn touches_corners(&self, rect: Rect) -> bool {
+        let Rect { min, max } = self.rect;
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        [
+            Rect::new(min.x, min.y, min.x + top_left, min.y + top_left),
+            Rect::new(max.x - top_right, min.y, max.x, min.y + top_right),
+            Rect::new(max.x - bottom_right, max.y - bottom_right, max.x, max.y),
+            Rect::new(min.x, max.y - bottom_left, min.x + bottom_left, max.y),
+        ]
+        .into_iter()
+        .any(|corner| !corner.is_empty() && !corner.intersect(rect).is_empty())
+    }
+}
+
+/// The most rows [`push_band`] splits a band into. Taller bands get rows taller than a logical pixel, so huge shapes
+/// can't produce millions of strips.
+pub const MAX_BAND_ROWS: usize = 4096;
+
+/// Pushes rows one logical pixel tall covering `start` to `end`, or at most [`MAX_BAND_ROWS`] equal rows for taller
+/// bands.
+pub(crate) fn push_band(rows: &mut Vec<(f32, f32)>, start: f32, end: f32) {
+    if !start.is_finite() || !end.is_finite() || end <= start {
+        return;
+    }
+    let height = (end - start).max(1.);
+    let row_height = (height / MAX_BAND_ROWS as f32).max(1.);
+    let count = ((height / row_height).ceil() as usize).min(MAX_BAND_ROWS);
+    // Rows are placed by index, as `y + 1.` stops changing `y` at large coordinates
+    for i in 0..count {
+        let y0 = start + i as f32 * row_height;
+        let y1 = if i + 1 == count {
+            end
+        } else {
+            (start + (i + 1) as f32 * row_height).min(end)
+        };
+        if y0 < y1 {
+            rows.push((y0, y1));
+        }
+    }
+}
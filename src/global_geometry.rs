@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+
+/// A UI node's border and outline geometry in window space, in logical pixels with the origin at the top left.
+///
+/// Insert this on nodes whose geometry other systems need, such as custom picking or screenshot annotation.
+/// It is updated each frame after layout and transform propagation. Rects are axis aligned, so on rotated or
+/// scaled nodes they are the bounds of the transformed geometry.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct GlobalBorderGeometry {
+    /// The node's border box
+    pub node: Rect,
+    /// The area enclosed by the border, the same as `node` if the node has no [`CalculatedBorder`]
+    pub inner: Rect,
+    /// The bounds of the node and its outline, the same as `node` if the node has no outline
+    pub outer: Rect,
+    /// The left, right, top and bottom edges of the border, `None` if an edge has no area
+    pub border_edges: [Option<Rect>; 4],
+    /// The left, right, top and bottom edges of the outline, `None` if an edge has no area
+    pub outline_edges: [Option<Rect>; 4],
+}
+
+/// Maps a rect from a node's local space to window space.
+pub(crate) fn to_window(transform: &GlobalTransform, rect: Rect) -> Rect {
+    Rect::from_corners(
+        transform.transform_point(rect.min.extend(0.)).truncate(),
+        transform.transform_point(rect.max.extend(0.)).truncate(),
+    )
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_global_border_geometry(
+    mut geometry_query: Query<(
+        &mut GlobalBorderGeometry,
+        &Node,
+        &GlobalTransform,
+        Option<&CalculatedBorder>,
+        Option<&CalculatedOutline>,
+    )>,
+) {
+    for (mut geometry, node, transform, border, outline) in geometry_query.iter_mut() {
+        let node_rect = to_window(
+            transform,
+            Rect::from_center_half_size(Vec2::ZERO, 0.5 * node.size()),
+        );
+        let border_edges = border
+            .map(|border| {
+                border
+                    .edges
+                    .map(|edge| edge.map(|edge| to_window(transform, edge)))
+            })
+            .unwrap_or_default();
+        let outline_edges = outline
+            .map(|outline| {
+                outline
+                    .edges
+                    .map(|edge| edge.map(|edge| to_window(transform, edge)))
+            })
+            .unwrap_or_default();
+        let new_geometry = GlobalBorderGeometry {
+            node: node_rect,
+            inner: border
+                .map(|border| to_window(transform, border.inner))
+                .unwrap_or(node_rect),
+            outer: outline_edges
+                .iter()
+                .flatten()
+                .fold(node_rect, |outer, edge| outer.union(*edge)),
+            border_edges,
+            outline_edges,
+        };
+        if *geometry != new_geometry {
+            *geometry = new_geometry;
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+use crate::animation::lerp_color;
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// The direction a [`BackgroundGradient`] runs in.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum GradientDirection {
+    /// From `start` at the top to `end` at the bottom
+    #[default]
+    Vertical,
+    /// From `start` on the left to `end` on the right
+    Horizontal,
+}
+
+/// Fills a UI node's border box with a two color linear gradient.
+///
+/// The gradient is drawn over the node's [`BackgroundColor`] and beneath its border, so give the node a transparent
+/// background or use translucent colors to tint it. It follows the node's [`BorderRadius`](crate::BorderRadius).
+///
+/// The gradient is built from flat colored bands, each at least one logical pixel wide.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct BackgroundGradient {
+    pub start: Color,
+    pub end: Color,
+    pub direction: GradientDirection,
+    /// The most bands the gradient is divided into
+    pub max_bands: u32,
+}
+
+impl Default for BackgroundGradient {
+    fn default() -> Self {
+        Self {
+            start: Color::WHITE,
+            end: Color::BLACK,
+            direction: GradientDirection::Vertical,
+            max_bands: 64,
+        }
+    }
+}
+
+impl BackgroundGradient {
+    pub fn new(start: Color, end: Color, direction: GradientDirection) -> Self {
+        Self {
+            start,
+            end,
+            direction,
+            ..Default::default()
+        }
+    }
+
+    /// A gradient from `start` at the top to `end` at the bottom.
+    pub fn vertical(start: Color, end: Color) -> Self {
+        Self::new(start, end, GradientDirection::Vertical)
+    }
+
+    /// A gradient from `start` on the left to `end` on the right.
+    pub fn horizontal(start: Color, end: Color) -> Self {
+        Self::new(start, end, GradientDirection::Horizontal)
+    }
+
+    /// The color of the gradient at `t`, from 0 at the start to 1 at the end.
+    pub fn color_at(&self, t: f32) -> Color {
+        lerp_color(self.start, self.end, t.clamp(0., 1.))
+    }
+}
+
+impl UiDecoration for BackgroundGradient {
+    const LAYER: DecorationLayer = DecorationLayer::Below;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let shape = context.node_shape();
+        let rect = shape.rect;
+        let length = match self.direction {
+            GradientDirection::Vertical => rect.height(),
+            GradientDirection::Horizontal => rect.width(),
+        };
+        if length <= 0. {
+            return;
+        }
+        let bands = self.max_bands.max(1).min(length.ceil() as u32);
+        let strips = if shape.is_rounded() {
+            shape.strips()
+        } else {
+            vec![rect]
+        };
+        for band in 0..bands {
+            let a = length * band as f32 / bands as f32;
+            let b = length * (band + 1) as f32 / bands as f32;
+            let band_rect = match self.direction {
+                GradientDirection::Vertical => {
+                    Rect::new(rect.min.x, rect.min.y + a, rect.max.x, rect.min.y + b)
+                }
+                GradientDirection::Horizontal => {
+                    Rect::new(rect.min.x + a, rect.min.y, rect.min.x + b, rect.max.y)
+                }
+            };
+            let color = self.color_at((band as f32 + 0.5) / bands as f32);
+            for strip in strips.iter() {
+                let quad = band_rect.intersect(*strip);
+                if !quad.is_empty() {
+                    quads.push(DecorationQuad { rect: quad, color });
+                }
+            }
+        }
+    }
+}
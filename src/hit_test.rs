@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+/// Enlarges the area around a UI node's border edges that counts as a hit, for pointer interactions like resizing.
+///
+/// Each edge's hit band is extended by this many logical pixels both outside the node and into it, so thin borders
+/// can be grabbed on touchscreens. Used by [`WindowFrame`](crate::WindowFrame) resizing,
+/// [`Splitter`](crate::Splitter)s and [`border_edges_at`].
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderHitSlop(pub f32);
+
+/// Which border edges of `rect` are under `point`, in left, right, top, bottom order.
+///
+/// `thickness` is the left, right, top and bottom thickness of the border, and `slop` enlarges each edge's band
+/// by that distance on both sides. `rect` and `point` should be in the same space, like the window space of
+/// [`GlobalBorderGeometry`](crate::GlobalBorderGeometry). Near the corners two edges can be hit at once.
+pub fn border_edges_at(rect: Rect, thickness: [f32; 4], point: Vec2, slop: f32) -> [bool; 4] {
+    let slop = slop.max(0.);
+    if !rect.inset(slop).contains(point) {
+        return [false; 4];
+    }
+    // Distance of the point inside each edge, negative outside the rect
+    let distances = [
+        point.x - rect.min.x,
+        rect.max.x - point.x,
+        point.y - rect.min.y,
+        rect.max.y - point.y,
+    ];
+    [0, 1, 2, 3].map(|i| distances[i] <= thickness[i].max(0.) + slop)
+}
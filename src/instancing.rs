@@ -0,0 +1,519 @@
+use std::ops::Range;
+
+use bevy::asset::load_internal_asset;
+use bevy::ecs::system::lifetimeless::Read;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParamItem;
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::render::render_phase::AddRenderCommand;
+use bevy::render::render_phase::DrawFunctions;
+use bevy::render::render_phase::PhaseItem;
+use bevy::render::render_phase::RenderCommand;
+use bevy::render::render_phase::RenderCommandResult;
+use bevy::render::render_phase::RenderPhase;
+use bevy::render::render_phase::SetItemPipeline;
+use bevy::render::render_phase::TrackedRenderPass;
+use bevy::render::render_resource::*;
+use bevy::render::renderer::RenderDevice;
+use bevy::render::renderer::RenderQueue;
+use bevy::render::texture::BevyDefault;
+use bevy::render::view::ExtractedView;
+use bevy::render::view::ViewTarget;
+use bevy::render::view::ViewUniform;
+use bevy::render::view::ViewUniformOffset;
+use bevy::render::view::ViewUniforms;
+use bevy::render::Extract;
+use bevy::render::RenderApp;
+use bevy::render::RenderSet;
+use bevy::ui::CalculatedClip;
+use bevy::ui::TransparentUi;
+use bevy::ui::UiStack;
+use bevy::utils::tracing::info_span;
+use bevy::utils::FloatOrd;
+use bytemuck::Pod;
+use bytemuck::Zeroable;
+
+use crate::geometry::RoundedRect;
+use crate::outline::offset_shape;
+use crate::radius::rounded_outline_shape;
+use crate::BorderColor;
+use crate::BorderRadius;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::OutlineColor;
+
+const BORDER_INSTANCE_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 6_140_375_520_834_167_291);
+
+/// Vertices drawn for each instance, two triangles for each of the eight quads from
+/// [`frame_quads`](crate::geometry::frame_quads).
+const VERTICES_PER_INSTANCE: u32 = 48;
+
+/// Draws a UI node's border with the instanced border pipeline instead of extracting a UI node for each edge.
+///
+/// Every instanced border and outline in the UI is drawn with a single draw call, for UIs with tens of thousands of
+/// bordered cells like inventory grids. The border is drawn as a frame in its `BorderColor`, rounded by the node's
+/// `BorderRadius` and clipped to its `CalculatedClip`. `BorderSpans`, `CornerStyle`, `BorderFill`, `BorderShape` and
+/// the other border styles are ignored.
+///
+/// Instanced borders are drawn in stack order among themselves, but outside of Bevy's UI batches. Like those batches
+/// they are sorted against the rest of the UI by their node's z translation, so they are drawn above the other UI
+/// nodes at the same z rather than in their node's place in the stack. Use them for cells that nothing else at the
+/// same z overlaps.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct InstancedBorder;
+
+/// Draws a UI node's outline with the instanced border pipeline, like [`InstancedBorder`].
+///
+/// Each outline is a single instance of its rect, thickness, color and corner radii, expanded into quads in the
+/// vertex shader, for selection-heavy scenes like RTS control groups. The outline is drawn in its `OutlineColor`, or
+/// its `BorderColor` without one, rounded by the node's `BorderRadius`. `OutlineShape`, `OutlineSegments`,
+/// `OutlineFill`, `ContrastOutline` and `CornerStyle` are ignored, and like instanced borders instanced outlines are
+/// drawn above the other UI nodes at the same z.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct InstancedOutline;
+
+/// The data for one instance, a frame between two rounded rects in a node's local space.
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+struct BorderInstance {
+    /// The node's x and y axes in window space
+    axes: [f32; 4],
+    /// The node's translation in window space
+    translation: [f32; 4],
+    /// The outer rect, as min x, min y, max x, max y
+    outer: [f32; 4],
+    /// The inner rect, as min x, min y, max x, max y
+    inner: [f32; 4],
+    /// The outer corner radii, in top left, top right, bottom right, bottom left order
+    outer_radii: [f32; 4],
+    /// The inner corner radii, in top left, top right, bottom right, bottom left order
+    inner_radii: [f32; 4],
+    /// Linear RGBA
+    color: [f32; 4],
+    /// The window space clip rect, as min x, min y, max x, max y
+    clip: [f32; 4],
+}
+
+impl BorderInstance {
+    fn new(
+        transform: Mat4,
+        outer: &RoundedRect,
+        inner: &RoundedRect,
+        color: Color,
+        clip: Option<&CalculatedClip>,
+    ) -> Self {
+        Self {
+            axes: [
+                transform.x_axis.x,
+                transform.x_axis.y,
+                transform.y_axis.x,
+                transform.y_axis.y,
+            ],
+            translation: transform.w_axis.to_array(),
+            outer: rect_to_array(outer.rect),
+            inner: rect_to_array(inner.rect),
+            outer_radii: outer.radii,
+            inner_radii: inner.radii,
+            color: color.as_linear_rgba_f32(),
+            clip: clip.map_or(NO_CLIP, |clip| rect_to_array(clip.clip)),
+        }
+    }
+}
+
+const NO_CLIP: [f32; 4] = [f32::MIN, f32::MIN, f32::MAX, f32::MAX];
+
+fn rect_to_array(rect: Rect) -> [f32; 4] {
+    [rect.min.x, rect.min.y, rect.max.x, rect.max.y]
+}
+
+#[derive(Resource, Default)]
+struct ExtractedBorderInstances {
+    instances: Vec<BorderInstance>,
+}
+
+#[derive(Resource)]
+struct BorderInstanceMeta {
+    instances: BufferVec<BorderInstance>,
+    view_bind_group: Option<BindGroup>,
+}
+
+impl Default for BorderInstanceMeta {
+    fn default() -> Self {
+        Self {
+            instances: BufferVec::new(BufferUsages::VERTEX),
+            view_bind_group: None,
+        }
+    }
+}
+
+/// A render world entity for a run of instances whose nodes have the same z translation.
+#[derive(Component)]
+struct BorderInstanceBatch {
+    range: Range<u32>,
+    z: f32,
+}
+
+#[allow(clippy::type_complexity)]
+fn extract_border_instances(
+    mut extracted: ResMut<ExtractedBorderInstances>,
+    ui_stack: Extract<Res<UiStack>>,
+    border_query: Extract<
+        Query<
+            (
+                &GlobalTransform,
+                &CalculatedBorder,
+                &BorderColor,
+                Option<&BorderRadius>,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            With<InstancedBorder>,
+        >,
+    >,
+    outline_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &CalculatedOutline,
+                Option<&OutlineColor>,
+                Option<&BorderColor>,
+                Option<&BorderRadius>,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            With<InstancedOutline>,
+        >,
+    >,
+) {
+    let _span = info_span!("bevy_ui_borders::extract_border_instances").entered();
+    extracted.instances.clear();
+    for entity in ui_stack.uinodes.iter() {
+        if let Ok((global_transform, calculated_border, border_color, radius, visibility, clip)) =
+            border_query.get(*entity)
+        {
+            let inner = calculated_border.inner;
+            let outer = calculated_border
+                .edges
+                .into_iter()
+                .flatten()
+                .reduce(|outer, edge| outer.union(edge))
+                .filter(|_| visibility.is_visible() && border_color.0.a() != 0.);
+            if let Some(outer) = outer.map(|outer| outer.union(inner)) {
+                let outer = RoundedRect::new(
+                    outer,
+                    radius
+                        .map(|radius| radius.resolve(outer.size()))
+                        .unwrap_or_default(),
+                );
+                let inner = outer.inset([
+                    inner.min.x - outer.rect.min.x,
+                    outer.rect.max.x - inner.max.x,
+                    inner.min.y - outer.rect.min.y,
+                    outer.rect.max.y - inner.max.y,
+                ]);
+                extracted.instances.push(BorderInstance::new(
+                    global_transform.compute_matrix(),
+                    &outer,
+                    &inner,
+                    border_color.0,
+                    clip,
+                ));
+            }
+        }
+
+        if let Ok((
+            node,
+            global_transform,
+            calculated_outline,
+            outline_color,
+            border_color,
+            radius,
+            visibility,
+            clip,
+        )) = outline_query.get(*entity)
+        {
+            // Without an `OutlineColor` the outline matches the border
+            let Some(color) = outline_color
+                .map(|color| color.0)
+                .or(border_color.map(|color| color.0))
+            else {
+                continue;
+            };
+            if !visibility.is_visible()
+                || color.a() == 0.
+                || calculated_outline.edges.iter().all(Option::is_none)
+            {
+                continue;
+            }
+            let inner = offset_shape(
+                RoundedRect::new(
+                    Rect::from_center_size(Vec2::ZERO, node.size()),
+                    radius
+                        .map(|radius| radius.resolve(node.size()))
+                        .unwrap_or_default(),
+                ),
+                calculated_outline.offset,
+            );
+            let outer = rounded_outline_shape(&inner, &calculated_outline.edges);
+            extracted.instances.push(BorderInstance::new(
+                global_transform.compute_matrix(),
+                &outer,
+                &inner,
+                color,
+                clip,
+            ));
+        }
+    }
+}
+
+fn prepare_border_instances(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut meta: ResMut<BorderInstanceMeta>,
+    mut extracted: ResMut<ExtractedBorderInstances>,
+) {
+    meta.instances.clear();
+    let mut batch: Option<BorderInstanceBatch> = None;
+    for instance in extracted.instances.drain(..) {
+        let index = meta.instances.push(instance) as u32;
+        let z = instance.translation[2];
+        if let Some(batch) = batch.as_mut().filter(|batch| batch.z == z) {
+            batch.range.end = index + 1;
+            continue;
+        }
+        if let Some(batch) = batch.replace(BorderInstanceBatch {
+            range: index..index + 1,
+            z,
+        }) {
+            commands.spawn(batch);
+        }
+    }
+    let Some(batch) = batch else {
+        return;
+    };
+    commands.spawn(batch);
+    meta.instances.write_buffer(&render_device, &render_queue);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn queue_border_instances(
+    draw_functions: Res<DrawFunctions<TransparentUi>>,
+    render_device: Res<RenderDevice>,
+    mut meta: ResMut<BorderInstanceMeta>,
+    view_uniforms: Res<ViewUniforms>,
+    pipeline: Res<BorderInstancePipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<BorderInstancePipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    batch_query: Query<(Entity, &BorderInstanceBatch)>,
+    mut views: Query<(&ExtractedView, &mut RenderPhase<TransparentUi>)>,
+) {
+    if batch_query.is_empty() {
+        return;
+    }
+    let Some(view_binding) = view_uniforms.uniforms.binding() else {
+        return;
+    };
+    meta.view_bind_group = Some(render_device.create_bind_group(&BindGroupDescriptor {
+        entries: &[BindGroupEntry {
+            binding: 0,
+            resource: view_binding,
+        }],
+        label: Some("border_instance_view_bind_group"),
+        layout: &pipeline.view_layout,
+    }));
+    let draw_function = draw_functions.read().id::<DrawBorderInstances>();
+    for (view, mut transparent_phase) in views.iter_mut() {
+        let pipeline = pipelines.specialize(
+            &pipeline_cache,
+            &pipeline,
+            BorderInstancePipelineKey { hdr: view.hdr },
+        );
+        for (entity, batch) in batch_query.iter() {
+            transparent_phase.add(TransparentUi {
+                draw_function,
+                pipeline,
+                entity,
+                // Bevy sorts its UI batches by z, sort just after those at the same z
+                sort_key: FloatOrd(batch.z.next_up()),
+            });
+        }
+    }
+}
+
+#[derive(Resource)]
+struct BorderInstancePipeline {
+    view_layout: BindGroupLayout,
+}
+
+impl FromWorld for BorderInstancePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let view_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            entries: &[BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::VERTEX,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: Some(ViewUniform::min_size()),
+                },
+                count: None,
+            }],
+            label: Some("border_instance_view_layout"),
+        });
+        BorderInstancePipeline { view_layout }
+    }
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+struct BorderInstancePipelineKey {
+    hdr: bool,
+}
+
+impl SpecializedRenderPipeline for BorderInstancePipeline {
+    type Key = BorderInstancePipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let instance_layout = VertexBufferLayout::from_vertex_formats(
+            VertexStepMode::Instance,
+            vec![
+                // axes
+                VertexFormat::Float32x4,
+                // translation
+                VertexFormat::Float32x4,
+                // outer
+                VertexFormat::Float32x4,
+                // inner
+                VertexFormat::Float32x4,
+                // outer_radii
+                VertexFormat::Float32x4,
+                // inner_radii
+                VertexFormat::Float32x4,
+                // color
+                VertexFormat::Float32x4,
+                // clip
+                VertexFormat::Float32x4,
+            ],
+        );
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: BORDER_INSTANCE_SHADER_HANDLE.typed::<Shader>(),
+                entry_point: "vertex".into(),
+                shader_defs: Vec::new(),
+                buffers: vec![instance_layout],
+            },
+            fragment: Some(FragmentState {
+                shader: BORDER_INSTANCE_SHADER_HANDLE.typed::<Shader>(),
+                shader_defs: Vec::new(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: if key.hdr {
+                        ViewTarget::TEXTURE_FORMAT_HDR
+                    } else {
+                        TextureFormat::bevy_default()
+                    },
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout: vec![self.view_layout.clone()],
+            push_constant_ranges: Vec::new(),
+            primitive: PrimitiveState {
+                front_face: FrontFace::Ccw,
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: PolygonMode::Fill,
+                conservative: false,
+                topology: PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+            },
+            depth_stencil: None,
+            multisample: MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("border_instance_pipeline".into()),
+        }
+    }
+}
+
+type DrawBorderInstances = (
+    SetItemPipeline,
+    SetBorderInstanceViewBindGroup<0>,
+    DrawBorderInstanceBatch,
+);
+
+struct SetBorderInstanceViewBindGroup<const I: usize>;
+
+impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetBorderInstanceViewBindGroup<I> {
+    type Param = SRes<BorderInstanceMeta>;
+    type ViewWorldQuery = Read<ViewUniformOffset>;
+    type ItemWorldQuery = ();
+
+    fn render<'w>(
+        _item: &P,
+        view_uniform: &'w ViewUniformOffset,
+        _entity: (),
+        meta: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(view_bind_group) = meta.into_inner().view_bind_group.as_ref() else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_bind_group(I, view_bind_group, &[view_uniform.offset]);
+        RenderCommandResult::Success
+    }
+}
+
+struct DrawBorderInstanceBatch;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawBorderInstanceBatch {
+    type Param = SRes<BorderInstanceMeta>;
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = Read<BorderInstanceBatch>;
+
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        batch: &'w BorderInstanceBatch,
+        meta: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(buffer) = meta.into_inner().instances.buffer() else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_vertex_buffer(0, buffer.slice(..));
+        pass.draw(0..VERTICES_PER_INSTANCE, batch.range.clone());
+        RenderCommandResult::Success
+    }
+}
+
+/// Sets up the instanced border pipeline in the render app.
+pub(crate) fn build_border_instancing(app: &mut App) {
+    if app.get_sub_app(RenderApp).is_err() {
+        return;
+    }
+    load_internal_asset!(
+        app,
+        BORDER_INSTANCE_SHADER_HANDLE,
+        "instancing.wgsl",
+        Shader::from_wgsl
+    );
+
+    app.sub_app_mut(RenderApp)
+        .init_resource::<ExtractedBorderInstances>()
+        .init_resource::<BorderInstanceMeta>()
+        .init_resource::<BorderInstancePipeline>()
+        .init_resource::<SpecializedRenderPipelines<BorderInstancePipeline>>()
+        .add_render_command::<TransparentUi, DrawBorderInstances>()
+        .add_system(extract_border_instances.in_schedule(ExtractSchedule))
+        .add_system(prepare_border_instances.in_set(RenderSet::Prepare))
+        .add_system(queue_border_instances.in_set(RenderSet::Queue));
+}
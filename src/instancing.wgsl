@@ -0,0 +1,119 @@
+#import bevy_render::view
+
+@group(0) @binding(0)
+var<uniform> view: View;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) world_position: vec2<f32>,
+    @location(2) local_position: vec2<f32>,
+    @location(3) @interpolate(flat) clip: vec4<f32>,
+    @location(4) @interpolate(flat) outer: vec4<f32>,
+    @location(5) @interpolate(flat) inner: vec4<f32>,
+    @location(6) @interpolate(flat) outer_radii: vec4<f32>,
+    @location(7) @interpolate(flat) inner_radii: vec4<f32>,
+};
+
+// Each instance is drawn as eight quads of six vertices, like `geometry::frame_quads`. First the left, right, top
+// and bottom edges, with the left and right edges covering the corners, then a quad in each of the inner rect's
+// corners, as large as the corner's radius but no larger than its quarter of the inner rect. The fragment shader
+// discards the pixels of each quad outside the frame.
+@vertex
+fn vertex(
+    @builtin(vertex_index) index: u32,
+    @location(0) axes: vec4<f32>,
+    @location(1) translation: vec4<f32>,
+    @location(2) outer: vec4<f32>,
+    @location(3) inner: vec4<f32>,
+    @location(4) outer_radii: vec4<f32>,
+    @location(5) inner_radii: vec4<f32>,
+    @location(6) color: vec4<f32>,
+    @location(7) clip: vec4<f32>,
+) -> VertexOutput {
+    let quad = index / 6u;
+    let half_size = max(0.5 * (inner.zw - inner.xy), vec2<f32>(0.0));
+    var lo = outer.xy;
+    var hi = outer.zw;
+    if (quad == 0u) {
+        hi.x = inner.x;
+    } else if (quad == 1u) {
+        lo.x = inner.z;
+    } else if (quad == 2u) {
+        lo.x = inner.x;
+        hi = inner.zy;
+    } else if (quad == 3u) {
+        lo = inner.xw;
+        hi.x = inner.z;
+    } else if (quad == 4u) {
+        lo = inner.xy;
+        hi = inner.xy + min(vec2<f32>(max(inner_radii.x, 0.0)), half_size);
+    } else if (quad == 5u) {
+        let size = min(vec2<f32>(max(inner_radii.y, 0.0)), half_size);
+        lo = vec2<f32>(inner.z - size.x, inner.y);
+        hi = vec2<f32>(inner.z, inner.y + size.y);
+    } else if (quad == 6u) {
+        lo = inner.zw - min(vec2<f32>(max(inner_radii.z, 0.0)), half_size);
+        hi = inner.zw;
+    } else {
+        let size = min(vec2<f32>(max(inner_radii.w, 0.0)), half_size);
+        lo = vec2<f32>(inner.x, inner.w - size.y);
+        hi = vec2<f32>(inner.x + size.x, inner.w);
+    }
+
+    // Two triangles, with corners 0, 1, 2 and 0, 2, 3 of the rect
+    var corners = array<u32, 6>(0u, 1u, 2u, 0u, 2u, 3u);
+    let corner = corners[index % 6u];
+    var local = lo;
+    if (corner == 1u || corner == 2u) {
+        local.x = hi.x;
+    }
+    if (corner == 2u || corner == 3u) {
+        local.y = hi.y;
+    }
+
+    let world_position = translation.xy + axes.xy * local.x + axes.zw * local.y;
+    var out: VertexOutput;
+    out.position = view.view_proj * vec4<f32>(world_position, translation.z, 1.0);
+    out.color = color;
+    out.world_position = world_position;
+    out.local_position = local;
+    out.clip = clip;
+    out.outer = outer;
+    out.inner = inner;
+    out.outer_radii = outer_radii;
+    out.inner_radii = inner_radii;
+    return out;
+}
+
+// Whether `point` is inside `rect`, given as min x, min y, max x, max y, with its corners rounded by `radii`, in top
+// left, top right, bottom right, bottom left order.
+fn inside_rounded(point: vec2<f32>, rect: vec4<f32>, radii: vec4<f32>) -> bool {
+    if (any(point < rect.xy) || any(rect.zw <= point)) {
+        return false;
+    }
+    let center = 0.5 * (rect.xy + rect.zw);
+    var radius = radii.x;
+    if (center.x <= point.x && point.y < center.y) {
+        radius = radii.y;
+    } else if (center.x <= point.x) {
+        radius = radii.z;
+    } else if (center.y <= point.y) {
+        radius = radii.w;
+    }
+    // The center of the corner's curve, or the point itself away from the corners
+    let curve_center = clamp(point, min(rect.xy + radius, center), max(rect.zw - radius, center));
+    return distance(point, curve_center) <= radius;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (any(in.world_position < in.clip.xy) || any(in.clip.zw <= in.world_position)) {
+        discard;
+    }
+    if (!inside_rounded(in.local_position, in.outer, in.outer_radii)
+        || inside_rounded(in.local_position, in.inner, in.inner_radii)) {
+        discard;
+    }
+    return in.color;
+}
@@ -1,14 +1,150 @@
+mod accent;
+mod animation;
+mod badge;
+mod border;
+mod bundles;
+mod caret;
+mod commands;
+mod corners;
+#[cfg(feature = "style_asset")]
+mod css;
+#[cfg(feature = "debug")]
+mod debug;
+mod decoration;
+mod diagnostics;
+mod disabled;
+mod drop_target;
+mod elevation;
+mod fill;
+mod focus;
+pub mod geometry;
+mod global_geometry;
+mod gradient;
+mod hit_test;
+mod instancing;
+mod lens;
+mod mesh;
+mod nav_hints;
+mod nine_patch;
+mod notch;
+mod outline;
+mod palette;
+mod pick_highlight;
+mod progress;
+mod radius;
+#[cfg(feature = "report")]
+mod report;
+mod screen_frame;
+mod scroll_indicator;
+mod segments;
+mod selection;
+mod shadow;
+mod splitter;
+mod stroke;
+#[cfg(feature = "style_asset")]
+mod style_asset;
+mod tabs;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
+mod theme;
+mod ticks;
+mod tooltip;
+mod validation;
+mod window_frame;
+
 use bevy::prelude::*;
-use bevy::render::Extract;
+use bevy::transform::TransformSystem;
 use bevy::ui::ExtractedUiNode;
-use bevy::ui::ExtractedUiNodes;
-use bevy::ui::FocusPolicy;
 use bevy::ui::RenderUiSystem;
-use bevy::ui::UiStack;
+use bevy::ui::UiSystem;
+
+pub use accent::AccentBar;
+pub use animation::*;
+pub use badge::*;
+pub use border::Border;
+pub use border::BorderRelativeTo;
+pub use border::BorderShape;
+pub use border::BorderSpans;
+pub use border::BorderStyle;
+pub use border::BorderThicknessClamp;
+pub use border::CalculatedBorder;
+pub use border::EdgeSpan;
+pub use border::ParentWidthFallback;
+pub use border::RequestBorderRecalculation;
+pub use border::Sides;
+pub use bundles::*;
+pub use caret::*;
+pub use commands::*;
+pub use corners::BorderCorners;
+#[cfg(feature = "style_asset")]
+pub use css::*;
+#[cfg(feature = "debug")]
+pub use debug::*;
+pub use decoration::*;
+pub use diagnostics::BorderDiagnosticsPlugin;
+pub use disabled::*;
+pub use drop_target::*;
+pub use elevation::HoverElevation;
+pub use fill::*;
+pub use focus::*;
+pub use geometry::ellipse_ring_strips;
+pub use geometry::RoundedRect;
+pub use global_geometry::GlobalBorderGeometry;
+pub use gradient::*;
+pub use hit_test::*;
+pub use instancing::InstancedBorder;
+pub use instancing::InstancedOutline;
+pub use lens::*;
+pub use mesh::*;
+pub use nav_hints::NavigationHints;
+pub use nine_patch::NinePatch;
+pub use nine_patch::SliceInsets;
+pub use notch::BorderNotch;
+pub use outline::CalculatedOutline;
+pub use outline::OutlineOffset;
+pub use outline::OutlineRelativeTo;
+pub use outline::OutlineSegments;
+pub use outline::OutlineShape;
+pub use outline::OutlineUnits;
+pub use palette::*;
+pub use pick_highlight::*;
+pub use progress::Progress;
+pub use progress::ProgressFill;
+pub use progress::ProgressFillNode;
+pub use radius::BorderRadius;
+pub use radius::CornerStyle;
+pub use radius::RoundedClip;
+#[cfg(feature = "report")]
+pub use report::*;
+pub use screen_frame::Letterbox;
+pub use screen_frame::SafeAreaBorder;
+pub use screen_frame::SafeAreaInsets;
+pub use screen_frame::ScreenFrame;
+pub use scroll_indicator::ScrollIndicatorBorder;
+pub use segments::SegmentedOverlay;
+pub use selection::Selected;
+pub use selection::SelectionCursor;
+pub use selection::SelectionGroup;
+pub use selection::SelectionGroupStyle;
+pub use selection::SlidingOutline;
+pub use shadow::InsetShadow;
+pub use shadow::OutlineShadow;
+pub use splitter::SplitAxis;
+pub use splitter::Splitter;
+pub use stroke::StrokePath;
+pub use stroke::StrokeQuad;
+#[cfg(feature = "style_asset")]
+pub use style_asset::*;
+pub use tabs::TabIndicator;
+pub use theme::*;
+pub use ticks::BorderTicks;
+pub use tooltip::*;
+pub use validation::*;
+pub use window_frame::*;
 
 /// The color of a UI node's border.
 #[derive(Component, Copy, Clone, Default, Debug, Deref, DerefMut, Reflect)]
-#[reflect(Component)]
+#[reflect(Component, Default)]
 pub struct BorderColor(pub Color);
 
 impl From<Color> for BorderColor {
@@ -17,266 +153,528 @@ impl From<Color> for BorderColor {
     }
 }
 
+impl BorderColor {
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self(Color::rgb(r, g, b))
+    }
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self(Color::rgba(r, g, b, a))
+    }
+
+    pub fn rgb_u8(r: u8, g: u8, b: u8) -> Self {
+        Self(Color::rgb_u8(r, g, b))
+    }
+
+    pub fn rgba_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(Color::rgba_u8(r, g, b, a))
+    }
+}
+
 /// Outline around the UI node's border that doesn't occupy any space in the UI layout.
+///
+/// The outline is drawn with the node's [`OutlineColor`], or with its [`BorderColor`] if it has no `OutlineColor`.
 #[derive(Component, Copy, Clone, Default, Debug, Reflect)]
-#[reflect(Component)]
+#[reflect(Component, Default)]
 pub struct Outline {
-    pub color: Color,
     pub thickness: UiRect,
 }
 
 impl Outline {
-    pub fn new(color: Color, thickness: UiRect) -> Self {
-        Self { color, thickness }
+    /// An outline with the given color and thickness for each edge.
+    ///
+    /// Returns an [`OutlineBundle`] so the color and thickness can be inserted together.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(color: Color, thickness: UiRect) -> OutlineBundle {
+        OutlineBundle::new(color, thickness)
     }
 
-    pub fn all(color: Color, thickness: Val) -> Self {
-        Self {
-            color,
-            thickness: UiRect::all(thickness),
-        }
+    /// An outline with the given color and the same thickness for every edge.
+    pub fn all(color: Color, thickness: Val) -> OutlineBundle {
+        OutlineBundle::all(color, thickness)
+    }
+
+    /// An outline with the given thickness for each edge, without a color.
+    pub fn thickness(thickness: UiRect) -> Self {
+        Self { thickness }
     }
 }
 
-/// The basic UI node but with a Border and Outline
+/// The color of a UI node's outline.
 ///
-/// Useful as a container for a variety of child nodes.
-#[derive(Bundle, Clone, Debug)]
-pub struct BorderedNodeBundle {
-    /// Describes the logical size of the node
-    pub node: Node,
-    /// Describes the style including flexbox settings
-    pub style: Style,
-    /// The background color, which serves as a "fill" for this node
-    pub background_color: BackgroundColor,
-    /// Whether this node should block interaction with lower nodes
-    pub focus_policy: FocusPolicy,
-    /// The transform of the node
-    ///
-    /// This field is automatically managed by the UI layout system.
-    /// To alter the position of the `nodebundle`, use the properties of the [`Style`] component.
-    pub transform: Transform,
-    /// The global transform of the node
-    ///
-    /// This field is automatically managed by the UI layout system.
-    /// To alter the position of the `NodeBundle`, use the properties of the [`Style`] component.
-    pub global_transform: GlobalTransform,
-    /// Describes the visibility properties of the node
-    pub visibility: Visibility,
-    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
-    pub computed_visibility: ComputedVisibility,
-    /// Indicates the depth at which the node should appear in the UI
-    pub z_index: ZIndex,
-    /// The color of the node's border.
-    pub border_color: BorderColor,
-    /// The thickness and color of the outline
-    pub outline: Outline,
+/// Optional, without one the outline is drawn in the node's [`BorderColor`] so that outlines match their borders
+/// unless overridden.
+#[derive(Component, Copy, Clone, Default, Debug, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+pub struct OutlineColor(pub Color);
+
+impl From<Color> for OutlineColor {
+    fn from(color: Color) -> Self {
+        Self(color)
+    }
 }
 
-impl Default for BorderedNodeBundle {
-    fn default() -> Self {
-        BorderedNodeBundle {
-            // Transparent background
-            background_color: Color::NONE.into(),
-            node: Default::default(),
-            style: Default::default(),
-            focus_policy: Default::default(),
-            transform: Default::default(),
-            global_transform: Default::default(),
-            visibility: Default::default(),
-            computed_visibility: Default::default(),
-            z_index: Default::default(),
-            border_color: Color::WHITE.into(),
-            outline: Default::default(),
-        }
+impl OutlineColor {
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self(Color::rgb(r, g, b))
+    }
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self(Color::rgba(r, g, b, a))
+    }
+
+    pub fn rgb_u8(r: u8, g: u8, b: u8) -> Self {
+        Self(Color::rgb_u8(r, g, b))
+    }
+
+    pub fn rgba_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(Color::rgba_u8(r, g, b, a))
     }
 }
 
-/// Add a border bundle to a ui node to draw its border
-#[derive(Bundle, Copy, Clone, Default)]
-pub struct BorderBundle {
-    /// The color of the node's border.
-    pub border_color: BorderColor,
-    /// The color and thickness of the node's outline
-    pub outline: Outline,
+/// Rounding applied to the resolved thickness of every border and outline edge.
+///
+/// Bevy's layout aligns nodes to physical pixels, so rounding the thickness in physical pixels gives crisp
+/// edges at the cost of slightly inexact thicknesses.
+#[derive(Resource, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub enum BorderRounding {
+    /// Exact fractional geometry
+    #[default]
+    None,
+    /// Round to the nearest physical pixel
+    Round,
+    /// Round down to a whole physical pixel
+    Floor,
+    /// Round up to a whole physical pixel
+    Ceil,
 }
 
-/// Percentage thickness of all border edges is calculated based on the width of the parent node.
-fn resolve_thickness(value: Val, parent_width: f32) -> f32 {
-    match value {
-        Val::Auto | Val::Undefined => 0.,
-        Val::Px(px) => px.max(0.),
-        Val::Percent(percent) => (parent_width * percent / 100.).max(0.),
+impl BorderRounding {
+    /// Rounds a thickness in logical pixels, given the number of physical pixels per logical pixel.
+    pub fn apply(self, thickness: f32, scale_factor: f32) -> f32 {
+        let physical = thickness * scale_factor;
+        let rounded = match self {
+            BorderRounding::None => return thickness,
+            BorderRounding::Round => physical.round(),
+            BorderRounding::Floor => physical.floor(),
+            BorderRounding::Ceil => physical.ceil(),
+        };
+        rounded / scale_factor
     }
 }
 
-const fn edge_rects(min: Vec2, max: Vec2, inner_min: Vec2, inner_max: Vec2) -> [Rect; 4] {
-    [
-        // Left
-        Rect {
-            min,
-            max: Vec2::new(inner_min.x, max.y),
-        },
-        // Right
-        Rect {
-            min: Vec2::new(inner_max.x, min.y),
-            max,
-        },
-        // Top
-        Rect {
-            min: Vec2::new(inner_min.x, min.y),
-            max: Vec2::new(inner_max.x, inner_min.y),
-        },
-        // Bottom
-        Rect {
-            min: Vec2::new(inner_min.x, inner_max.y),
-            max: Vec2::new(inner_max.x, max.y),
-        },
-    ]
+/// A multiplier applied to the resolved thickness of every outline, before [`BorderRounding`].
+///
+/// Use it to thicken every outline and focus ring at once, for example from an accessibility setting or on handheld
+/// and TV targets. It also scales [`SlidingOutline`]s. Borders are unaffected since they take up space in the layout.
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Default)]
+pub struct OutlineScale(pub f32);
+
+impl Default for OutlineScale {
+    fn default() -> Self {
+        Self(1.)
+    }
 }
 
-#[allow(clippy::type_complexity)]
-fn extract_uinode_borders(
-    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
-    ui_stack: Extract<Res<UiStack>>,
-    uinode_query: Extract<
-        Query<
+/// The thinnest that a non-zero border or outline edge is drawn, in physical pixels.
+///
+/// Applied after [`BorderRounding`], so that thin edges such as `Val::Px(0.5)` or small percentages never vanish on
+/// high scale factor displays or round away to nothing. Edges that resolve to zero stay hidden. The default of 0
+/// leaves thicknesses unchanged, set it to 1 to keep every edge at least one physical pixel thick.
+#[derive(Resource, Copy, Clone, Default, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Default)]
+pub struct MinVisibleThickness(pub f32);
+
+impl MinVisibleThickness {
+    /// Widens `rounded`, a thickness in logical pixels after [`BorderRounding`], to the minimum unless the thickness
+    /// before rounding was zero, given the number of physical pixels per logical pixel.
+    pub fn apply(self, thickness: f32, rounded: f32, scale_factor: f32) -> f32 {
+        if thickness <= 0. {
+            return rounded;
+        }
+        rounded.max(self.0 / scale_factor)
+    }
+}
+
+/// The rect covered by a UI node's border box in window space, ignoring any rotation or scaling.
+pub(crate) fn world_rect(node: &Node, transform: &GlobalTransform) -> Rect {
+    Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+/// Creates an extracted quad covering `quad`, given in the node's local space.
+///
+/// If `texture` is `None` the quad is drawn with the default white image, otherwise it samples
+/// the source rect (in pixels) from an image of the given size.
+pub(crate) fn extracted_quad(
+    stack_index: usize,
+    transform: Mat4,
+    quad: Rect,
+    color: Color,
+    texture: Option<(&Handle<Image>, Rect, Vec2)>,
+    clip: Option<Rect>,
+) -> ExtractedUiNode {
+    // Scale the source rect to the size of the quad so that clipping is applied in node space
+    let (image, rect, atlas_size) = match texture {
+        Some((image, source, image_size)) => {
+            let scale = quad.size() / source.size();
             (
-                &Node,
-                &GlobalTransform,
-                &Style,
-                Option<&BorderColor>,
-                Option<&Outline>,
-                Option<&Parent>,
-                &ComputedVisibility,
-                Option<&CalculatedClip>,
-            ),
-            Without<CalculatedSize>,
-        >,
-    >,
-    parent_node_query: Extract<Query<&Node, With<Parent>>>,
-) {
-    let image = bevy::render::texture::DEFAULT_IMAGE_HANDLE.typed();
-
-    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
-        if let Ok((
-            node,
-            global_transform,
-            style,
-            maybe_border_color,
-            maybe_outline,
-            parent,
-            visibility,
-            clip,
-        )) = uinode_query.get(*entity)
-        {
-            if !visibility.is_visible() || node.size().x <= 0. || node.size().y <= 0. {
-                continue;
-            }
-
-            // calculate border rects, ensuring that they don't overlap
-            let transform = global_transform.compute_matrix();
-
-            let mut maybe_parent_width = None;
-            let get_parent_width = || {
-                parent
-                    .and_then(|parent| parent_node_query.get(parent.get()).ok())
-                    .map(|parent_node| parent_node.size().x)
-                    .unwrap_or(0.)
-            };
-
-            if let Some(border_color) =
-                maybe_border_color.filter(|border_color| border_color.a() != 0.)
-            {
-                if border_color.a() != 0. {
-                    let parent_width = get_parent_width();
-                    maybe_parent_width = parent_width.into();
-
-                    // calculate border rects, ensuring no overlap
-                    let left = resolve_thickness(style.border.left, parent_width);
-                    let right = resolve_thickness(style.border.right, parent_width);
-                    let top = resolve_thickness(style.border.top, parent_width);
-                    let bottom = resolve_thickness(style.border.bottom, parent_width);
-                    let max = 0.5 * node.size();
-                    let min = -max;
-                    let inner_min = min + Vec2::new(left, top);
-                    let inner_max = (max - Vec2::new(right, bottom)).max(inner_min);
-                    let border_rects = edge_rects(min, max, inner_min, inner_max);
-
-                    for edge in border_rects {
-                        if edge.min.x < edge.max.x && edge.min.y < edge.max.y {
-                            extracted_uinodes.uinodes.push(ExtractedUiNode {
-                                stack_index,
-                                transform: transform
-                                    * Mat4::from_translation(edge.center().extend(0.)),
-                                color: **border_color,
-                                rect: Rect {
-                                    max: edge.size(),
-                                    ..Default::default()
-                                },
-                                image: image.clone_weak(),
-                                atlas_size: None,
-                                clip: clip.map(|clip| clip.clip),
-                                flip_x: false,
-                                flip_y: false,
-                            });
-                        }
-                    }
-                }
-            }
-
-            if let Some(outline) = maybe_outline.filter(|outline| outline.color.a() != 0.) {
-                let parent_width = maybe_parent_width.unwrap_or_else(get_parent_width);
-                let left = resolve_thickness(outline.thickness.left, parent_width);
-                let right = resolve_thickness(outline.thickness.right, parent_width);
-                let top = resolve_thickness(outline.thickness.top, parent_width);
-                let bottom = resolve_thickness(outline.thickness.bottom, parent_width);
-
-                // calculate outline rects, ensuring that they don't overlap
-                let half_size = 0.5 * node.size();
-                let min = -Vec2::new(half_size.x + left, half_size.y + top);
-                let max = Vec2::new(half_size.x + right, half_size.y + bottom);
-                let inner_min = min + Vec2::new(left, top);
-                let inner_max = (max - Vec2::new(right, bottom)).max(inner_min);
-                let outline_rects = edge_rects(min, max, inner_min, inner_max);
-
-                for edge in outline_rects {
-                    extracted_uinodes.uinodes.push(ExtractedUiNode {
-                        stack_index,
-                        transform: transform * Mat4::from_translation(edge.center().extend(0.)),
-                        color: outline.color,
-                        rect: Rect {
-                            max: edge.size(),
-                            ..Default::default()
-                        },
-                        image: image.clone_weak(),
-                        atlas_size: None,
-                        clip: clip.map(|clip| clip.clip),
-                        flip_x: false,
-                        flip_y: false,
-                    });
-                }
-            }
+                image.clone_weak(),
+                Rect {
+                    min: source.min * scale,
+                    max: source.max * scale,
+                },
+                Some(image_size * scale),
+            )
         }
+        None => (
+            bevy::render::texture::DEFAULT_IMAGE_HANDLE.typed(),
+            Rect {
+                max: quad.size(),
+                ..Default::default()
+            },
+            None,
+        ),
+    };
+    ExtractedUiNode {
+        stack_index,
+        transform: transform * Mat4::from_translation(quad.center().extend(0.)),
+        color,
+        rect,
+        image,
+        atlas_size,
+        clip,
+        flip_x: false,
+        flip_y: false,
     }
 }
 
+/// Adds border and outline calculation and rendering for UI nodes.
+///
+/// Without a render sub-app, such as in a server or a test, only the geometry is calculated.
+/// [`BordersPlugin::headless`] does the same explicitly.
 pub struct BordersPlugin;
 
-impl Plugin for BordersPlugin {
+impl BordersPlugin {
+    /// A plugin that only calculates border and outline geometry, for layout tests and servers.
+    pub fn headless() -> HeadlessBordersPlugin {
+        HeadlessBordersPlugin
+    }
+}
+
+/// Adds only the systems that calculate border and outline geometry, without any rendering.
+///
+/// [`CalculatedBorder`], [`CalculatedOutline`] and the other geometry components are kept up to date exactly as with
+/// the [`BordersPlugin`], and animations, themes and selection still run. Nothing is extracted for rendering, even
+/// if the app has a render sub-app.
+pub struct HeadlessBordersPlugin;
+
+impl Plugin for HeadlessBordersPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<BorderColor>()
-            .register_type::<Outline>();
+            .register_type::<Border>()
+            .register_type::<Outline>()
+            .register_type::<OutlineColor>()
+            .register_type::<OutlineRelativeTo>()
+            .register_type::<OutlineUnits>()
+            .register_type::<OutlineOffset>()
+            .register_type::<OutlineSegments>()
+            .register_type::<OutlineShape>()
+            .register_type::<BorderStyle>()
+            .register_type::<BorderShape>()
+            .register_type::<BorderSpans>()
+            .register_type::<EdgeSpan>()
+            .register_type::<BorderRelativeTo>()
+            .register_type::<BorderThicknessClamp>()
+            .register_type::<TileMode>()
+            .register_type::<BorderFill>()
+            .register_type::<OutlineFill>()
+            .register_type::<DefaultBorderFill>()
+            .register_type::<TextureRotation>()
+            .register_type::<EdgeTextureTransform>()
+            .register_type::<FillTransform>()
+            .register_type::<BorderCorners>()
+            .register_type::<SliceInsets>()
+            .register_type::<NinePatch>()
+            .register_type::<OutlineShadow>()
+            .register_type::<InsetShadow>()
+            .register_type::<Easing>()
+            .register_type::<ColorSpace>()
+            .register_type::<KeyframeRepeat>()
+            .register_type::<BorderAnimation>()
+            .register_type::<BorderKeyframes>()
+            .register_type::<BorderFlash>()
+            .register_type::<BorderShake>()
+            .register_type::<Focused>()
+            .register_type::<FocusInputSource>()
+            .register_type::<FocusOutlineMode>()
+            .register_type::<FocusOutline>()
+            .register_type::<TailSide>()
+            .register_type::<TooltipFrame>()
+            .register_type::<BorderNotch>()
+            .register_type::<BadgeCorner>()
+            .register_type::<CornerBadge>()
+            .register_type::<Progress>()
+            .register_type::<ProgressFill>()
+            .register_type::<BorderTicks>()
+            .register_type::<SegmentedOverlay>()
+            .register_type::<TextCaret>()
+            .register_type::<SelectionHighlight>()
+            .register_type::<ValidationState>()
+            .register_type::<ValidationStyle>()
+            .register_type::<ValidationIcon>()
+            .register_type::<NavigationHints>()
+            .register_type::<DecorationRects>()
+            .register_type::<AccentBar>()
+            .register_type::<ScrollIndicatorBorder>()
+            .register_type::<Splitter>()
+            .register_type::<SplitAxis>()
+            .register_type::<Selected>()
+            .register_type::<SelectionGroup>()
+            .register_type::<SelectionGroupStyle>()
+            .register_type::<DragState>()
+            .register_type::<DropTargetHighlight>()
+            .register_type::<PickState>()
+            .register_type::<HoverElevation>()
+            .register_type::<BorderPalette>()
+            .register_type::<BorderColorRef>()
+            .register_type::<OutlineColorRef>()
+            .register_type::<ThemeMode>()
+            .register_type::<BorderThemes>()
+            .register_type::<ContrastBorder>()
+            .register_type::<ContrastOutline>()
+            .register_type::<BorderRadius>()
+            .register_type::<CornerStyle>()
+            .register_type::<GradientDirection>()
+            .register_type::<BackgroundGradient>()
+            .register_type::<BorderHitSlop>()
+            .register_type::<StrokePath>()
+            .register_type::<WindowFrame>()
+            .register_type::<WindowTitleBar>()
+            .register_type::<WindowCloseButton>()
+            .register_type::<BorderRounding>()
+            .register_type::<MinVisibleThickness>()
+            .register_type::<ScreenFrame>()
+            .register_type::<Letterbox>()
+            .register_type::<SafeAreaInsets>()
+            .register_type::<SafeAreaBorder>()
+            .register_type::<Disabled>()
+            .register_type::<DisabledBorder>()
+            .register_type::<OutlineScale>()
+            .register_type::<ParentWidthFallback>()
+            .register_type::<InstancedBorder>()
+            .register_type::<InstancedOutline>()
+            .register_type::<RequestBorderRecalculation>()
+            .register_type::<GlobalBorderGeometry>()
+            .register_type::<CalculatedBorder>()
+            .register_type::<CalculatedOutline>()
+            .init_resource::<FocusInputSource>()
+            .init_resource::<BorderRounding>()
+            .init_resource::<MinVisibleThickness>()
+            .init_resource::<DisabledBorder>()
+            .init_resource::<SafeAreaInsets>()
+            .init_resource::<OutlineScale>()
+            .init_resource::<ParentWidthFallback>()
+            .init_resource::<DefaultBorderFill>()
+            .init_resource::<SelectionGroupStyle>()
+            .init_resource::<ValidationStyle>()
+            .init_resource::<DragState>()
+            .init_resource::<BorderPalette>()
+            .init_resource::<ThemeMode>()
+            .add_event::<BorderAnimationFinished>()
+            .add_event::<BorderFlashFinished>()
+            .add_event::<OutlineShown>()
+            .add_event::<OutlineHidden>()
+            .add_systems((
+                animation::animate_borders,
+                animation::animate_border_keyframes.after(animation::animate_borders),
+                animation::flash_borders
+                    .after(animation::animate_border_keyframes)
+                    .after(focus::update_focus_outlines),
+                animation::shake_borders,
+                border::insert_calculated_borders,
+                outline::insert_calculated_outlines,
+                focus::track_focus_input_source,
+                focus::update_focus_outlines.after(focus::track_focus_input_source),
+                window_frame::drag_window_frames,
+                window_frame::close_window_frames,
+                splitter::drag_splitters,
+                selection::update_selection_groups,
+                drop_target::update_drop_target_highlights,
+                pick_highlight::update_pick_highlights
+                    .after(selection::update_selection_groups)
+                    .before(animation::flash_borders),
+                selection::move_selection_cursor
+                    .run_if(resource_exists::<SelectionCursor>())
+                    .before(animation::animate_borders),
+            ))
+            .add_systems(
+                (
+                    theme::apply_theme_mode,
+                    palette::resolve_border_color_refs,
+                    validation::apply_validation_states,
+                    theme::ensure_border_contrast,
+                )
+                    .chain()
+                    .before(animation::animate_borders),
+            )
+            .add_system(caret::blink_carets)
+            .add_system(progress::update_progress_fills)
+            .add_system(
+                elevation::update_hover_elevations
+                    .after(pick_highlight::update_pick_highlights)
+                    .before(animation::animate_borders),
+            )
+            .add_system(
+                border::sync_borders
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(UiSystem::Flex),
+            )
+            .add_systems(
+                (
+                    border::update_viewport_relative_borders,
+                    outline::update_viewport_relative_outlines,
+                    border::remove_calculated_borders,
+                    outline::remove_calculated_outlines,
+                )
+                    .in_base_set(CoreSet::PostUpdate),
+            )
+            .add_systems(
+                (border::calculate_borders, outline::calculate_outlines)
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(UiSystem::Flex)
+                    .after(border::update_viewport_relative_borders)
+                    .after(outline::update_viewport_relative_outlines),
+            )
+            .add_system(
+                border::remove_recalculation_requests
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(border::calculate_borders)
+                    .after(outline::calculate_outlines),
+            )
+            .add_system(
+                global_geometry::update_global_border_geometry
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(TransformSystem::TransformPropagate)
+                    .after(border::calculate_borders)
+                    .after(outline::calculate_outlines),
+            )
+            .add_system(
+                radius::update_rounded_clips
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(TransformSystem::TransformPropagate)
+                    .after(border::calculate_borders),
+            )
+            .add_system(
+                tabs::update_tab_indicators
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(TransformSystem::TransformPropagate)
+                    .before(border::calculate_borders),
+            )
+            .add_systems(
+                (
+                    selection::slide_outlines,
+                    tooltip::update_tooltip_tails,
+                    scroll_indicator::update_scroll_indicators,
+                )
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(TransformSystem::TransformPropagate),
+            );
 
-        let render_app = match app.get_sub_app_mut(bevy::render::RenderApp) {
-            Ok(render_app) => render_app,
-            Err(_) => return,
-        };
+        #[cfg(feature = "style_asset")]
+        app.add_asset::<BorderStyleAsset>()
+            .init_asset_loader::<BorderStyleAssetLoader>()
+            .add_asset::<BorderStylesheet>()
+            .init_asset_loader::<BorderStylesheetLoader>()
+            .add_systems((
+                style_asset::apply_border_style_assets,
+                css::apply_stylesheet.after(style_asset::apply_border_style_assets),
+            ));
+    }
+}
+
+impl Plugin for BordersPlugin {
+    fn build(&self, app: &mut App) {
+        HeadlessBordersPlugin.build(app);
+        add_extraction(app);
+        instancing::build_border_instancing(app);
+    }
+}
+
+/// Adds the systems extracting borders, outlines and decorations to the app's render sub-app, if it has one.
+pub(crate) fn add_extraction(app: &mut App) {
+    app.add_ui_decoration::<OutlineShadow>()
+        .add_ui_decoration::<InsetShadow>()
+        .add_ui_decoration::<TooltipFrame>()
+        .add_ui_decoration::<CornerBadge>()
+        .add_ui_decoration::<BorderTicks>()
+        .add_ui_decoration::<SegmentedOverlay>()
+        .add_ui_decoration::<TextCaret>()
+        .add_ui_decoration::<SelectionHighlight>()
+        .add_ui_decoration::<ValidationIcon>()
+        .add_ui_decoration::<DropTargetHighlight>()
+        .add_ui_decoration::<BackgroundGradient>()
+        .add_ui_decoration::<NavigationHints>()
+        .add_ui_decoration::<DecorationRects>()
+        .add_ui_decoration::<AccentBar>()
+        .add_ui_decoration::<ScrollIndicatorBorder>()
+        .add_ui_decoration::<Splitter>();
+
+    let render_app = match app.get_sub_app_mut(bevy::render::RenderApp) {
+        Ok(render_app) => render_app,
+        Err(_) => return,
+    };
+
+    render_app.edit_schedule(ExtractSchedule, |schedule| {
+        schedule.configure_sets(
+            (
+                DecorationLayer::Below,
+                DecorationLayer::Border,
+                DecorationLayer::Above,
+            )
+                .chain()
+                .after(RenderUiSystem::ExtractNode),
+        );
+    });
 
-        render_app.add_system(
-            extract_uinode_borders
-                .after(RenderUiSystem::ExtractNode)
+    render_app
+        .init_resource::<radius::RoundedClipStart>()
+        .add_systems(
+            (
+                // Text is clipped with the rest of the node's content, so it has to be extracted first
+                radius::clip_extracted_nodes
+                    .after(RenderUiSystem::ExtractNode)
+                    .after(bevy::ui::extract_text_uinodes)
+                    .before(DecorationLayer::Below),
+                radius::clip_extracted_decorations.after(DecorationLayer::Above),
+            )
+                .in_schedule(ExtractSchedule),
+        )
+        .add_system(
+            nine_patch::extract_nine_patches
+                .in_set(DecorationLayer::Below)
+                .in_schedule(ExtractSchedule),
+        )
+        .add_systems(
+            (
+                border::extract_uinode_borders,
+                outline::extract_uinode_outlines,
+                notch::extract_border_notches,
+                stroke::extract_stroke_paths,
+            )
+                .chain()
+                .in_set(DecorationLayer::Border)
+                .in_schedule(ExtractSchedule),
+        )
+        .add_systems(
+            (
+                corners::extract_uinode_corners,
+                selection::extract_sliding_outlines,
+                screen_frame::extract_screen_frame,
+            )
+                .chain()
+                .in_set(DecorationLayer::Above)
                 .in_schedule(ExtractSchedule),
         );
-    }
 }
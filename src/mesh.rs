@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use bevy::render::mesh::Indices;
+use bevy::render::render_resource::PrimitiveTopology;
+
+use crate::outline::offset_shape;
+use crate::radius::local_shape;
+use crate::radius::rounded_outline_shape;
+use crate::BorderRadius;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+
+/// Builds a triangle list mesh with a quad for each rect, given in a UI node's local space.
+///
+/// Positions are flipped so that y points up, matching the node when the mesh is drawn with a 2D camera.
+/// Texture coordinates span the bounds of all of the rects, with `v` increasing downwards like the UI.
+/// Every vertex has a normal facing +Z.
+pub fn rects_to_mesh(rects: impl IntoIterator<Item = Rect>) -> Mesh {
+    let rects: Vec<Rect> = rects.into_iter().filter(|rect| !rect.is_empty()).collect();
+    let bounds = rects
+        .iter()
+        .copied()
+        .reduce(|bounds, rect| bounds.union(rect))
+        .unwrap_or_default();
+    let bounds_size = bounds.size().max(Vec2::splat(f32::EPSILON));
+
+    let mut positions = Vec::with_capacity(4 * rects.len());
+    let mut uvs = Vec::with_capacity(4 * rects.len());
+    let mut indices = Vec::with_capacity(6 * rects.len());
+    for rect in rects {
+        let start = positions.len() as u32;
+        for corner in [
+            rect.min,
+            Vec2::new(rect.max.x, rect.min.y),
+            rect.max,
+            Vec2::new(rect.min.x, rect.max.y),
+        ] {
+            positions.push([corner.x, -corner.y, 0.]);
+            uvs.push(((corner - bounds.min) / bounds_size).to_array());
+        }
+        // Counter-clockwise once y is flipped
+        indices.extend([start, start + 2, start + 1, start, start + 3, start + 2]);
+    }
+    let normals = vec![[0., 0., 1.]; positions.len()];
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+/// Builds a mesh of a UI node's border, rounded by its [`BorderRadius`] if it has one. See [`rects_to_mesh`].
+pub fn border_mesh(size: Vec2, border: &CalculatedBorder, radius: Option<&BorderRadius>) -> Mesh {
+    match local_shape(size, radius, None) {
+        Some(outer) => rects_to_mesh(outer.ring_strips(&outer.inset(border.resolved))),
+        None => rects_to_mesh(border.edges.into_iter().flatten()),
+    }
+}
+
+/// Builds a mesh of a UI node's outline, rounded by its [`BorderRadius`] if it has one. See [`rects_to_mesh`].
+pub fn outline_mesh(
+    size: Vec2,
+    outline: &CalculatedOutline,
+    radius: Option<&BorderRadius>,
+) -> Mesh {
+    match local_shape(size, radius, None) {
+        Some(inner) => {
+            let inner = offset_shape(inner, outline.offset);
+            rects_to_mesh(rounded_outline_shape(&inner, &outline.edges).ring_strips(&inner))
+        }
+        None => rects_to_mesh(outline.edges.into_iter().flatten()),
+    }
+}
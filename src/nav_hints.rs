@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+use crate::tooltip::push_tail;
+use crate::DecorationContext;
+use crate::DecorationQuad;
+use crate::Sides;
+use crate::TailSide;
+use crate::UiDecoration;
+
+/// Small arrows outside a UI node's outline, pointing in the directions that navigation can move from it.
+///
+/// Each arrow is centered on its side of the node's outline, or of its border box if it has no outline, and points
+/// away from the node. Arrows are built from strips one logical pixel thick.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct NavigationHints {
+    /// The sides with an arrow
+    pub directions: Sides,
+    /// Distance from the base of each arrow to its tip in logical pixels. The base is twice as wide.
+    pub size: f32,
+    /// Space between the outline and the base of each arrow in logical pixels
+    pub gap: f32,
+    pub color: Color,
+}
+
+impl Default for NavigationHints {
+    fn default() -> Self {
+        Self {
+            directions: Sides::NONE,
+            size: 4.,
+            gap: 2.,
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl NavigationHints {
+    pub fn new(directions: Sides, color: Color) -> Self {
+        Self {
+            directions,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// The tip of each arrow, in the node's local space.
+    pub fn tips(&self, context: &DecorationContext) -> Vec<Vec2> {
+        let size = self.size.max(0.);
+        arrows(self.directions, context.outer_rect(), self.gap.max(0.))
+            .map(|(side, offset, start)| match side {
+                TailSide::Top => Vec2::new(offset, -start - size),
+                TailSide::Bottom => Vec2::new(offset, start + size),
+                TailSide::Left => Vec2::new(-start - size, offset),
+                TailSide::Right => Vec2::new(start + size, offset),
+            })
+            .collect()
+    }
+}
+
+/// The side of each arrow, the offset of its middle along the side and the distance from the middle of the node to
+/// its base.
+fn arrows(directions: Sides, outer: Rect, gap: f32) -> impl Iterator<Item = (TailSide, f32, f32)> {
+    let center = outer.center();
+    [
+        (Sides::TOP, TailSide::Top, center.x, -outer.min.y),
+        (Sides::BOTTOM, TailSide::Bottom, center.x, outer.max.y),
+        (Sides::LEFT, TailSide::Left, center.y, -outer.min.x),
+        (Sides::RIGHT, TailSide::Right, center.y, outer.max.x),
+    ]
+    .into_iter()
+    .filter(move |(sides, ..)| directions.contains(*sides))
+    .map(move |(_, side, offset, edge)| (side, offset, edge + gap))
+}
+
+impl UiDecoration for NavigationHints {
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        if self.size <= 0. || !self.size.is_finite() {
+            return;
+        }
+        for (side, offset, start) in arrows(self.directions, context.outer_rect(), self.gap.max(0.))
+        {
+            push_tail(
+                side, offset, start, self.size, self.size, 0., self.color, quads,
+            );
+        }
+    }
+}
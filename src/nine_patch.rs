@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+
+use crate::extracted_quad;
+use crate::fill::tile_edge;
+use crate::TileMode;
+
+/// Distances from each side of an image, in pixels, that divide it into nine slices.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Reflect, FromReflect)]
+pub struct SliceInsets {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl SliceInsets {
+    pub const fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    pub const fn all(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
+
+/// Draws a UI node as a nine-patch frame cut from a single image.
+///
+/// The image's corners are drawn unstretched at each corner of the node, its edges are stretched or tiled along
+/// the node's sides and its center fills the middle. The frame is drawn below the node's border and outline.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct NinePatch {
+    pub image: Handle<Image>,
+    /// Where the image is sliced
+    pub insets: SliceInsets,
+    /// Logical pixels per image pixel of the drawn corners and edges
+    pub scale: f32,
+    /// How the edge slices are laid out along the node's sides
+    pub tile: TileMode,
+    /// Whether the center slice is drawn
+    pub draw_center: bool,
+    /// Tint applied to the whole frame
+    pub color: Color,
+}
+
+impl Default for NinePatch {
+    fn default() -> Self {
+        Self {
+            image: Default::default(),
+            insets: Default::default(),
+            scale: 1.,
+            tile: TileMode::Stretch,
+            draw_center: true,
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl NinePatch {
+    pub fn new(image: Handle<Image>, insets: SliceInsets) -> Self {
+        Self {
+            image,
+            insets,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_tile(mut self, tile: TileMode) -> Self {
+        self.tile = tile;
+        self
+    }
+
+    pub fn without_center(mut self) -> Self {
+        self.draw_center = false;
+        self
+    }
+}
+
+/// Splits the span from `min` to `max` at the given insets, shrinking the insets proportionally if they don't fit.
+fn slice_span(min: f32, max: f32, start: f32, end: f32) -> [f32; 4] {
+    let length = max - min;
+    let shrink = if length < start + end {
+        length / (start + end)
+    } else {
+        1.
+    };
+    [min, min + start * shrink, max - end * shrink, max]
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn extract_nine_patches(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    images: Extract<Res<Assets<Image>>>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &NinePatch,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            Without<CalculatedSize>,
+        >,
+    >,
+) {
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        let Ok((node, global_transform, nine_patch, visibility, clip)) = uinode_query.get(*entity)
+        else {
+            continue;
+        };
+        // Skip invisible nodes and loading images
+        if !visibility.is_visible()
+            || nine_patch.color.a() == 0.
+            || node.size().x <= 0.
+            || node.size().y <= 0.
+        {
+            continue;
+        }
+        let Some(image_size) = images.get(&nine_patch.image).map(|image| image.size()) else {
+            continue;
+        };
+
+        let insets = nine_patch.insets;
+        let scale = nine_patch.scale;
+        let half_size = 0.5 * node.size();
+        let xs = slice_span(
+            -half_size.x,
+            half_size.x,
+            insets.left * scale,
+            insets.right * scale,
+        );
+        let ys = slice_span(
+            -half_size.y,
+            half_size.y,
+            insets.top * scale,
+            insets.bottom * scale,
+        );
+        let source_xs = [0., insets.left, image_size.x - insets.right, image_size.x];
+        let source_ys = [0., insets.top, image_size.y - insets.bottom, image_size.y];
+
+        let transform = global_transform.compute_matrix();
+        let clip = clip.map(|clip| clip.clip);
+        for row in 0..3 {
+            for column in 0..3 {
+                if row == 1 && column == 1 && !nine_patch.draw_center {
+                    continue;
+                }
+                let quad = Rect::new(xs[column], ys[row], xs[column + 1], ys[row + 1]);
+                let source = Rect::new(
+                    source_xs[column],
+                    source_ys[row],
+                    source_xs[column + 1],
+                    source_ys[row + 1],
+                );
+                if quad.is_empty() || source.is_empty() {
+                    continue;
+                }
+                // Only the edge slices are tiled, corners and the center are stretched
+                let tiles = match (column, row) {
+                    (1, 0) | (1, 2) => tile_edge(quad, true, source, nine_patch.tile),
+                    (0, 1) | (2, 1) => tile_edge(quad, false, source, nine_patch.tile),
+                    _ => vec![(quad, source)],
+                };
+                for (quad, source) in tiles {
+                    extracted_uinodes.uinodes.push(extracted_quad(
+                        stack_index,
+                        transform,
+                        quad,
+                        nine_patch.color,
+                        Some((&nine_patch.image, source, image_size)),
+                        clip,
+                    ));
+                }
+            }
+        }
+    }
+}
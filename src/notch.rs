@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+
+use crate::extracted_quad;
+use crate::tooltip::push_tail;
+use crate::tooltip::to_local;
+use crate::BorderColor;
+use crate::CalculatedBorder;
+use crate::DecorationQuad;
+use crate::TailSide;
+
+/// Adds a tail to one edge of a UI node's border, turning it into a speech bubble or callout.
+///
+/// The tail is outlined in the node's [`BorderColor`] with the border thickness of its edge, and filled with the node's
+/// [`BackgroundColor`] so the border beneath the tail is covered and the tail opens into the node.
+/// The tail is drawn outside the node, so leave a margin of at least `length` on its side.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderNotch {
+    pub side: TailSide,
+    /// Position of the middle of the tail along its edge, from 0 at the left or top end to 1 at the right or bottom end.
+    /// The tail is kept within the edge.
+    pub position: f32,
+    /// Width of the tail where it meets the border in logical pixels
+    pub width: f32,
+    /// Distance from the border to the tip of the tail in logical pixels
+    pub length: f32,
+}
+
+impl Default for BorderNotch {
+    fn default() -> Self {
+        Self {
+            side: TailSide::Bottom,
+            position: 0.25,
+            width: 16.,
+            length: 12.,
+        }
+    }
+}
+
+impl BorderNotch {
+    pub fn new(side: TailSide, position: f32, width: f32, length: f32) -> Self {
+        Self {
+            side,
+            position,
+            width,
+            length,
+        }
+    }
+
+    /// Offset of the middle of the tail from the middle of its edge, for a node of the given size.
+    fn offset(&self, size: Vec2) -> f32 {
+        let edge_length = match self.side {
+            TailSide::Top | TailSide::Bottom => size.x,
+            TailSide::Left | TailSide::Right => size.y,
+        };
+        let max_offset = (0.5 * (edge_length - self.width.max(0.))).max(0.);
+        let position = if self.position.is_finite() {
+            self.position.clamp(0., 1.)
+        } else {
+            0.5
+        };
+        ((position - 0.5) * edge_length).clamp(-max_offset, max_offset)
+    }
+
+    /// The position of the tip of the tail for a node of the given size, in the node's local space.
+    pub fn tip(&self, size: Vec2) -> Vec2 {
+        let normal = match self.side {
+            TailSide::Top | TailSide::Bottom => 0.5 * size.y,
+            TailSide::Left | TailSide::Right => 0.5 * size.x,
+        };
+        to_local(
+            self.side,
+            Vec2::new(self.offset(size), normal + self.length.max(0.)),
+        )
+    }
+
+    /// Appends the quads for the tail of a node of the given size and border thickness.
+    fn push_quads(
+        &self,
+        size: Vec2,
+        thickness: f32,
+        border_color: Color,
+        fill: Color,
+        quads: &mut Vec<DecorationQuad>,
+    ) {
+        let half_width = 0.5 * self.width.max(0.);
+        let length = self.length.max(0.);
+        if half_width <= 0. || length <= 0. {
+            return;
+        }
+        let offset = self.offset(size);
+        let normal = match self.side {
+            TailSide::Top | TailSide::Bottom => 0.5 * size.y,
+            TailSide::Left | TailSide::Right => 0.5 * size.x,
+        };
+        push_tail(
+            self.side,
+            offset,
+            normal,
+            length,
+            half_width,
+            0.,
+            border_color,
+            quads,
+        );
+        push_tail(
+            self.side,
+            offset,
+            normal - thickness,
+            length + thickness,
+            half_width + thickness,
+            thickness,
+            fill,
+            quads,
+        );
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn extract_border_notches(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    mut quads: Local<Vec<DecorationQuad>>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &BorderNotch,
+                &BorderColor,
+                Option<&BackgroundColor>,
+                Option<&CalculatedBorder>,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            Without<CalculatedSize>,
+        >,
+    >,
+) {
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        if let Ok((
+            node,
+            global_transform,
+            notch,
+            border_color,
+            background,
+            border,
+            visibility,
+            clip,
+        )) = uinode_query.get(*entity)
+        {
+            // Skip invisible nodes
+            if !visibility.is_visible() || node.size().x <= 0. || node.size().y <= 0. {
+                continue;
+            }
+
+            let edge = match notch.side {
+                TailSide::Left => 0,
+                TailSide::Right => 1,
+                TailSide::Top => 2,
+                TailSide::Bottom => 3,
+            };
+            let thickness = border.map(|border| border.resolved[edge]).unwrap_or(0.);
+            let fill = background
+                .map(|background| background.0)
+                .unwrap_or(Color::NONE);
+            quads.clear();
+            notch.push_quads(node.size(), thickness, border_color.0, fill, &mut quads);
+
+            let transform = global_transform.compute_matrix();
+            for quad in quads.iter() {
+                if quad.color.a() == 0. || quad.rect.is_empty() {
+                    continue;
+                }
+                extracted_uinodes.uinodes.push(extracted_quad(
+                    stack_index,
+                    transform,
+                    quad.rect,
+                    quad.color,
+                    None,
+                    clip.map(|clip| clip.clip),
+                ));
+            }
+        }
+    }
+}
@@ -1,117 +1,533 @@
 use bevy::prelude::*;
 use bevy::render::Extract;
-use bevy::ui::ExtractedUiNode;
 use bevy::ui::ExtractedUiNodes;
-use bevy::ui::FocusPolicy;
 use bevy::ui::UiStack;
+use bevy::utils::tracing::info_span;
+use bevy::utils::HashSet;
+use bevy::window::PrimaryWindow;
 
-use crate::resolve_thickness;
+use crate::animation::shaken_transform;
+use crate::extracted_quad;
+use crate::fill::extract_edges;
+use crate::geometry::ellipse_ring_strips;
+use crate::geometry::finite_or_zero;
+use crate::geometry::is_negative;
+use crate::geometry::is_non_finite;
+use crate::geometry::outline_edges;
+use crate::geometry::resolve_thickness;
+use crate::geometry::RoundedRect;
+use crate::light_or_dark;
+use crate::radius::extract_rounded_ring;
+use crate::radius::local_shape;
+use crate::radius::rounded_outline_shape;
+use crate::BorderColor;
+use crate::BorderRadius;
+use crate::BorderRounding;
+use crate::BorderShake;
+use crate::BorderSpans;
+use crate::ContrastOutline;
+use crate::CornerStyle;
+use crate::DefaultBorderFill;
+use crate::EdgeSpan;
+use crate::FillTransform;
+use crate::InstancedOutline;
+use crate::MinVisibleThickness;
+use crate::Outline;
+use crate::OutlineColor;
+use crate::OutlineFill;
+use crate::OutlineScale;
+use crate::ParentWidthFallback;
+use crate::RequestBorderRecalculation;
 
+/// The outline geometry of a UI node, in the node's local space.
+///
+/// This component is automatically managed by the [`BordersPlugin`](crate::BordersPlugin).
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct CalculatedOutline {
+    /// The left, right, top and bottom edges of the outline, `None` if an edge has no area.
+    pub edges: [Option<Rect>; 4],
+    /// The resolved [`OutlineOffset`] in logical pixels
+    pub offset: f32,
+}
+
+impl CalculatedOutline {
+    /// The rect the outline surrounds in the node's local space, its border box grown by the outline's offset.
+    pub fn inner_rect(&self, node_size: Vec2) -> Rect {
+        Rect::from_center_size(Vec2::ZERO, (node_size + 2. * self.offset).max(Vec2::ZERO))
+    }
+}
+
+/// Space between a UI node's border box and its outline, like CSS `outline-offset`.
+///
+/// Resolved like the outline's thickness, using the node's [`OutlineRelativeTo`] and [`OutlineUnits`]. Negative
+/// offsets move the outline inside the border box, by at most half of the node's smaller dimension.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct OutlineOffset(pub Val);
+
+impl Default for OutlineOffset {
+    fn default() -> Self {
+        Self(Val::Px(0.))
+    }
+}
+
+impl OutlineOffset {
+    /// Resolves the offset to logical pixels, like [`OutlineUnits::resolve`] but keeping its sign.
+    pub fn resolve(self, units: OutlineUnits, basis: f32, scale_factor: f32) -> f32 {
+        let (sign, magnitude) = match self.0 {
+            Val::Px(px) if px < 0. => (-1., Val::Px(-px)),
+            Val::Percent(percent) if percent < 0. => (-1., Val::Percent(-percent)),
+            value => (1., value),
+        };
+        sign * units.resolve(magnitude, basis, scale_factor)
+    }
+}
+
+/// The rounded shape an outline surrounds, `inner` moved out by the outline's offset.
+pub(crate) fn offset_shape(inner: RoundedRect, offset: f32) -> RoundedRect {
+    if offset < 0. {
+        inner.inset([-offset; 4])
+    } else {
+        inner.outset([offset; 4])
+    }
+}
+
+/// The shape of a UI node's outline.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub enum OutlineShape {
+    /// Follows the node's border box, shaped by its [`BorderRadius`] or [`CornerStyle`]
+    #[default]
+    Rectangle,
+    /// A ring around the ellipse fitted to the node's bounds, for circular avatar frames and radial buttons.
+    /// The ring is as thick as the outline on each side. Outlines filled with a texture stay rectangular.
+    Ellipse,
+}
+
+/// Draws each edge of a UI node's outline as a list of segments, like a minimalist sci-fi HUD frame with gaps at the
+/// middle of each edge, or a dashed outline.
+///
+/// Spans run along the outside of the outline, from the left or top end of each edge. Outlines with segments are
+/// drawn with square corners and without a texture.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct OutlineSegments {
+    pub left: Vec<EdgeSpan>,
+    pub right: Vec<EdgeSpan>,
+    pub top: Vec<EdgeSpan>,
+    pub bottom: Vec<EdgeSpan>,
+}
+
+impl OutlineSegments {
+    /// The same segments on every edge.
+    pub fn all(segments: Vec<EdgeSpan>) -> Self {
+        Self {
+            left: segments.clone(),
+            right: segments.clone(),
+            top: segments.clone(),
+            bottom: segments,
+        }
+    }
+
+    /// Leaves a gap covering `fraction` of each edge around its middle.
+    pub fn gapped(fraction: f32) -> Self {
+        let half_gap = 0.5 * fraction.clamp(0., 1.);
+        Self::all(vec![
+            EdgeSpan::new(0., 0.5 - half_gap),
+            EdgeSpan::new(0.5 + half_gap, 1.),
+        ])
+    }
+
+    /// Splits each edge into `dashes` dashes separated by gaps of the same length, with a dash at each end.
+    pub fn dashed(dashes: u32) -> Self {
+        let count = 2 * dashes.max(1) - 1;
+        let step = 1. / count as f32;
+        Self::all(
+            (0..dashes.max(1))
+                .map(|dash| EdgeSpan::new(2. * dash as f32 * step, (2 * dash + 1) as f32 * step))
+                .collect(),
+        )
+    }
+
+    /// The rects of every segment of the left, right, top and bottom outline edges.
+    pub fn split(&self, edges: [Option<Rect>; 4]) -> Vec<Rect> {
+        let Some(outer) = edges
+            .into_iter()
+            .flatten()
+            .reduce(|outer, edge| outer.union(edge))
+        else {
+            return Vec::new();
+        };
+        [&self.left, &self.right, &self.top, &self.bottom]
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, spans)| {
+                spans
+                    .iter()
+                    .filter_map(move |span| BorderSpans::all(*span).trim(outer, edges)[i])
+            })
+            .collect()
+    }
+}
+
+/// What percentage [`Outline`] thicknesses are resolved against.
+///
+/// Without this component percentages are resolved against the width of the node's parent.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub enum OutlineRelativeTo {
+    /// The width of the node's parent
+    #[default]
+    ParentWidth,
+    /// The width of the node
+    SelfWidth,
+    /// The smaller of the node's width and height
+    SelfMinDimension,
+    /// The width of the primary window
+    Viewport,
+}
+
+impl OutlineRelativeTo {
+    /// The length in logical pixels that a thickness of 100% resolves to.
+    pub fn basis(self, node_size: Vec2, parent_width: f32, viewport_width: f32) -> f32 {
+        match self {
+            OutlineRelativeTo::ParentWidth => parent_width,
+            OutlineRelativeTo::SelfWidth => node_size.x,
+            OutlineRelativeTo::SelfMinDimension => node_size.min_element(),
+            OutlineRelativeTo::Viewport => viewport_width,
+        }
+    }
+}
+
+/// What unit `Val::Px` [`Outline`] thicknesses are given in.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub enum OutlineUnits {
+    /// Logical pixels, scaled by the window's scale factor and [`UiScale`]
+    #[default]
+    Logical,
+    /// Physical pixels, unaffected by the window's scale factor and [`UiScale`]. A thickness of `Val::Px(1.)` draws a
+    /// hairline exactly one device pixel wide, for crisp editor-style UIs on hiDPI screens.
+    Physical,
+}
+
+impl OutlineUnits {
+    /// Resolves a thickness to logical pixels, given the number of physical pixels per logical pixel.
+    pub fn resolve(self, value: Val, basis: f32, scale_factor: f32) -> f32 {
+        match (self, value) {
+            (OutlineUnits::Physical, Val::Px(physical)) => {
+                finite_or_zero(physical / scale_factor).max(0.)
+            }
+            _ => resolve_thickness(value, basis),
+        }
+    }
+}
+
+/// Marks viewport relative outlines as changed when the primary window is resized.
+pub(crate) fn update_viewport_relative_outlines(
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut relative_to_query: Query<&mut OutlineRelativeTo>,
+) {
+    if !window_query
+        .get_single()
+        .is_ok_and(|window| window.is_changed())
+    {
+        return;
+    }
+    for mut relative_to in relative_to_query.iter_mut() {
+        if *relative_to == OutlineRelativeTo::Viewport {
+            relative_to.set_changed();
+        }
+    }
+}
+
+pub(crate) fn insert_calculated_outlines(
+    mut commands: Commands,
+    outline_query: Query<Entity, (With<Outline>, Without<CalculatedOutline>)>,
+) {
+    for entity in outline_query.iter() {
+        commands.entity(entity).insert(CalculatedOutline::default());
+    }
+}
+
+/// Removes the outline geometry from nodes that no longer have an outline.
+pub(crate) fn remove_calculated_outlines(
+    mut commands: Commands,
+    mut removed_outlines: RemovedComponents<Outline>,
+    outline_query: Query<(), (With<CalculatedOutline>, Without<Outline>)>,
+) {
+    for entity in removed_outlines.iter() {
+        if outline_query.contains(entity) {
+            commands.entity(entity).remove::<CalculatedOutline>();
+        }
+    }
+}
 
 /// Generates the outline geometry
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(crate) fn calculate_outlines(
-    parent_query: Query<&Node, With<Children>>,
-    mut outline_query: Query<
-        (&Node, &Outline, Option<&Parent>),
-        (Or<(Changed<Node>, Changed<Outline>, Changed<Parent>)>,),
-    >,
+    rounding: Res<BorderRounding>,
+    outline_scale: Res<OutlineScale>,
+    min_thickness: Res<MinVisibleThickness>,
+    parent_query: Query<Ref<Node>, With<Children>>,
+    parent_width_fallback: Res<ParentWidthFallback>,
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut warned_non_finite: Local<HashSet<Entity>>,
+    mut warned_negative: Local<HashSet<Entity>>,
+    mut warned_root_percent: Local<HashSet<Entity>>,
+    mut removed_offsets: RemovedComponents<OutlineOffset>,
+    mut outline_query: Query<(
+        Entity,
+        Ref<Node>,
+        Ref<Outline>,
+        &mut CalculatedOutline,
+        Option<Ref<Parent>>,
+        Option<Ref<OutlineRelativeTo>>,
+        Option<Ref<OutlineUnits>>,
+        Option<Ref<OutlineOffset>>,
+        Option<&RequestBorderRecalculation>,
+    )>,
 ) {
-    for (node, outline, mut calculated_outline, parent) in outline_query.iter_mut() {
+    let _span = info_span!("bevy_ui_borders::calculate_outlines").entered();
+    let (viewport_width, window_scale_factor) = window_query
+        .get_single()
+        .map(|window| (window.width(), window.scale_factor()))
+        .unwrap_or((0., 1.));
+    // Root nodes may resolve percentages against the window's width
+    let fallback_changed = parent_width_fallback.is_changed()
+        || (*parent_width_fallback == ParentWidthFallback::Viewport
+            && window_query
+                .get_single()
+                .is_ok_and(|window| window.is_changed()));
+    let removed_offsets: HashSet<Entity> = removed_offsets.iter().collect();
+    for (
+        entity,
+        node,
+        outline,
+        mut calculated_outline,
+        parent,
+        relative_to,
+        units,
+        offset,
+        request,
+    ) in outline_query.iter_mut()
+    {
+        let changed = request.is_some()
+            || node.is_changed()
+            || outline.is_changed()
+            || calculated_outline.is_added()
+            || parent.as_ref().is_some_and(Ref::is_changed)
+            // Percentage thickness depends on the size of the parent
+            || parent.as_ref().is_some_and(|parent| {
+                parent_query
+                    .get(parent.get())
+                    .is_ok_and(|parent_node| parent_node.is_changed())
+            })
+            || relative_to.as_ref().is_some_and(Ref::is_changed)
+            || units.as_ref().is_some_and(Ref::is_changed)
+            || offset.as_ref().is_some_and(Ref::is_changed)
+            || removed_offsets.contains(&entity)
+            || (parent.is_none() && fallback_changed);
+        if !changed
+            && !rounding.is_changed()
+            && !outline_scale.is_changed()
+            && !min_thickness.is_changed()
+        {
+            continue;
+        }
+
+        let values = [
+            outline.thickness.left,
+            outline.thickness.right,
+            outline.thickness.top,
+            outline.thickness.bottom,
+        ];
+        let relative_to = relative_to.as_deref().copied().unwrap_or_default();
         let parent_width = parent
             .and_then(|parent| parent_query.get(parent.get()).ok())
             .map(|parent_node| parent_node.size().x)
-            .unwrap_or(0.);
-        let left = resolve_thickness(outline.left, parent_width);
-        let right = resolve_thickness(outline.right, parent_width);
-        let top = resolve_thickness(outline.top, parent_width);
-        let bottom = resolve_thickness(outline.bottom, parent_width);
-
-        // calculate outline rects, ensuring that they don't overlap
-        let half_size = 0.5 * node.size();
-        let min = -Vec2::new(half_size.x + left, half_size.y + top);
-        let max = Vec2::new(half_size.x + right, half_size.y + bottom);
-        let inner_min = min + Vec2::new(left, top);
-        let inner_max = (max - Vec2::new(right, bottom)).max(inner_min);
-
-        let outline_rects = [
-            // Left outline
-            Rect {
-                min,
-                max: Vec2::new(inner_min.x, max.y),
-            },
-            // Right outline
-            Rect {
-                min: Vec2::new(inner_max.x, min.y),
-                max,
-            },
-            // Top outline
-            Rect {
-                min: Vec2::new(inner_min.x, min.y),
-                max: Vec2::new(inner_max.x, inner_min.y),
-            },
-            // Bottom outline
-            Rect {
-                min: Vec2::new(inner_min.x, inner_max.y),
-                max: Vec2::new(inner_max.x, max.y),
-            },
-        ];
-
-        for (i, edge) in outline_rects.into_iter().enumerate() {
-            calculated_outline.edges[i] = if edge.min.x < edge.max.x && edge.min.y < edge.max.y {
-                Some(edge)
-            } else {
-                None
-            };
-        }
+            .unwrap_or_else(|| {
+                if relative_to == OutlineRelativeTo::ParentWidth
+                    && parent_width_fallback.should_warn(values)
+                    && warned_root_percent.insert(entity)
+                {
+                    warn!("Percentage outline thickness on {entity:?} resolves to zero as it has no parent, set the `ParentWidthFallback` resource to choose another width");
+                }
+                parent_width_fallback.width(node.size(), viewport_width)
+            });
+        let basis = relative_to.basis(node.size(), parent_width, viewport_width);
+        let units = units.as_deref().copied().unwrap_or_default();
+        let thickness = values.map(|value| {
+            if is_non_finite(value) && warned_non_finite.insert(entity) {
+                warn!("Ignoring non-finite outline thickness {value:?} on {entity:?}");
+            }
+            if is_negative(value) && warned_negative.insert(entity) {
+                warn!("Clamping negative outline thickness {value:?} on {entity:?} to zero");
+            }
+            let thickness =
+                units.resolve(value, basis, window_scale_factor as f32) * outline_scale.0.max(0.);
+            let rounded = rounding.apply(thickness, window_scale_factor as f32);
+            min_thickness.apply(thickness, rounded, window_scale_factor as f32)
+        });
+        let offset = offset.map_or(0., |offset| {
+            let offset = offset.resolve(units, basis, window_scale_factor as f32);
+            let rounded = rounding.apply(offset.abs(), window_scale_factor as f32);
+            offset.signum() * rounded
+        });
+        calculated_outline.offset = offset.max(-0.5 * node.size().min_element().max(0.));
+        calculated_outline.edges =
+            outline_edges(calculated_outline.inner_rect(node.size()).size(), thickness);
     }
 }
 
 #[allow(clippy::type_complexity)]
 pub(crate) fn extract_uinode_outlines(
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    default_fill: Extract<Res<DefaultBorderFill>>,
+    images: Extract<Res<Assets<Image>>>,
+    atlases: Extract<Res<Assets<TextureAtlas>>>,
     ui_stack: Extract<Res<UiStack>>,
     uinode_query: Extract<
         Query<
             (
+                &Node,
                 &GlobalTransform,
-                &Outline,
+                Option<&OutlineColor>,
+                Option<&BorderColor>,
+                &CalculatedOutline,
+                Option<&OutlineFill>,
+                Option<&BorderShake>,
+                Option<&BorderRadius>,
+                Option<&CornerStyle>,
+                Option<&FillTransform>,
+                Option<&OutlineShape>,
+                Option<&OutlineSegments>,
+                (Option<&ContrastOutline>, Option<&BackgroundColor>),
                 &ComputedVisibility,
                 Option<&CalculatedClip>,
             ),
-            Without<CalculatedSize>,
+            (Without<CalculatedSize>, Without<InstancedOutline>),
         >,
     >,
 ) {
-    let image = bevy::render::texture::DEFAULT_IMAGE_HANDLE.typed();
-
+    let _span = info_span!("bevy_ui_borders::extract_uinode_outlines").entered();
     for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
-        if let Ok((global_transform, outline, visibility, clip)) =
-            uinode_query.get(*entity)
+        if let Ok((
+            node,
+            global_transform,
+            outline_color,
+            border_color,
+            calculated_outline,
+            outline_fill,
+            shake,
+            radius,
+            corner_style,
+            fill_transform,
+            shape,
+            segments,
+            (contrast, background),
+            visibility,
+            clip,
+        )) = uinode_query.get(*entity)
         {
-            // Skip invisible nodes
-            if !visibility.is_visible() || outline_color.a() == 0.0 {
+            // Without an `OutlineColor` the outline matches the border
+            let Some(outline_color) = outline_color
+                .map(|color| color.0)
+                .or(border_color.map(|color| color.0))
+            else {
+                continue;
+            };
+            let outline_color = match (contrast, background) {
+                (Some(_), Some(background)) => {
+                    light_or_dark(background.0).with_a(outline_color.a())
+                }
+                _ => outline_color,
+            };
+
+            // Skip invisible outlines
+            if !visibility.is_visible()
+                || outline_color.a() == 0.
+                || node.size().x <= 0.
+                || node.size().y <= 0.
+            {
                 continue;
             }
 
-            let transform = global_transform.compute_matrix();
+            let texture = match outline_fill {
+                Some(fill) => match fill.resolve(&images, &atlases) {
+                    Some(texture) => Some(texture),
+                    // Skip loading images
+                    None => continue,
+                },
+                None => default_fill
+                    .0
+                    .as_ref()
+                    .and_then(|fill| fill.resolve(&images, &atlases)),
+            };
 
-            for &outline_rect in calculated_outline.edges.iter().flatten() {
-                extracted_uinodes.uinodes.push(ExtractedUiNode {
+            let transform = shaken_transform(global_transform, shake);
+            if let (Some(OutlineShape::Ellipse), None) = (shape, outline_fill) {
+                let Some(outer) = calculated_outline
+                    .edges
+                    .into_iter()
+                    .flatten()
+                    .reduce(|outer, edge| outer.union(edge))
+                else {
+                    continue;
+                };
+                let inner = calculated_outline.inner_rect(node.size());
+                for strip in ellipse_ring_strips(outer.union(inner), inner) {
+                    extracted_uinodes.uinodes.push(extracted_quad(
+                        stack_index,
+                        transform,
+                        strip,
+                        outline_color,
+                        None,
+                        clip.map(|clip| clip.clip),
+                    ));
+                }
+                continue;
+            }
+            if let Some(segments) = segments {
+                for segment in segments.split(calculated_outline.edges) {
+                    extracted_uinodes.uinodes.push(extracted_quad(
+                        stack_index,
+                        transform,
+                        segment,
+                        outline_color,
+                        None,
+                        clip.map(|clip| clip.clip),
+                    ));
+                }
+                continue;
+            }
+            if let (Some(inner), None) =
+                (local_shape(node.size(), radius, corner_style), outline_fill)
+            {
+                let inner = offset_shape(inner, calculated_outline.offset);
+                extract_rounded_ring(
+                    &mut extracted_uinodes,
                     stack_index,
-                    transform: transform * Mat4::from_translation(outline_rect.center().extend(0.)),
-                    color: **outline_color,
-                    rect: Rect {
-                        max: outline_rect.size(),
-                        ..Default::default()
-                    },
-                    image: image.clone_weak(),
-                    atlas_size: None,
-                    clip: clip.map(|clip| clip.clip),
-                    flip_x: false,
-                    flip_y: false,
-                });
+                    transform,
+                    &rounded_outline_shape(&inner, &calculated_outline.edges),
+                    &inner,
+                    outline_color,
+                    clip.map(|clip| clip.clip),
+                );
+                continue;
             }
+
+            extract_edges(
+                &mut extracted_uinodes,
+                stack_index,
+                transform,
+                &calculated_outline.edges,
+                outline_color,
+                texture.as_ref(),
+                fill_transform.copied().unwrap_or_default().edges(),
+                clip.map(|clip| clip.clip),
+            );
         }
     }
 }
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::BorderColor;
+use crate::OutlineColor;
+
+/// Named colors that UI nodes can refer to with [`BorderColorRef`] and [`OutlineColorRef`].
+///
+/// Changing a color re-colors the border or outline of every node that refers to it, so swapping the palette switches
+/// between themes at once.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct BorderPalette {
+    pub colors: HashMap<String, Color>,
+}
+
+impl BorderPalette {
+    /// Adds a named color to the palette, replacing any color with the same name.
+    pub fn with(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.insert(name, color);
+        self
+    }
+
+    /// Sets a named color, returning the color it replaced.
+    pub fn insert(&mut self, name: impl Into<String>, color: Color) -> Option<Color> {
+        self.colors.insert(name.into(), color)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.colors.get(name).copied()
+    }
+}
+
+/// Sets a UI node's [`BorderColor`] to a named color from the [`BorderPalette`].
+///
+/// The border color is updated whenever the palette or the reference changes. Nodes that refer to a name missing
+/// from the palette keep their current color.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderColorRef(pub Cow<'static, str>);
+
+impl BorderColorRef {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// Sets a UI node's [`OutlineColor`] to a named color from the [`BorderPalette`], like [`BorderColorRef`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct OutlineColorRef(pub Cow<'static, str>);
+
+impl OutlineColorRef {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// Looks up a named color, warning if the palette doesn't have it.
+fn lookup(palette: &BorderPalette, name: &str) -> Option<Color> {
+    let color = palette.get(name);
+    if color.is_none() {
+        warn!("No color named `{name}` in the border palette");
+    }
+    color
+}
+
+pub(crate) fn resolve_border_color_refs(
+    mut commands: Commands,
+    palette: Res<BorderPalette>,
+    mut border_query: Query<(Entity, Ref<BorderColorRef>, Option<&mut BorderColor>)>,
+    mut outline_query: Query<(Entity, Ref<OutlineColorRef>, Option<&mut OutlineColor>)>,
+) {
+    for (entity, color_ref, outline_color) in outline_query.iter_mut() {
+        if !palette.is_changed() && !color_ref.is_changed() {
+            continue;
+        }
+        let Some(color) = lookup(&palette, &color_ref.0) else {
+            continue;
+        };
+        match outline_color {
+            Some(mut outline_color) => {
+                if outline_color.0 != color {
+                    outline_color.0 = color;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(OutlineColor(color));
+            }
+        }
+    }
+    for (entity, color_ref, border_color) in border_query.iter_mut() {
+        if !palette.is_changed() && !color_ref.is_changed() {
+            continue;
+        }
+        let Some(color) = lookup(&palette, &color_ref.0) else {
+            continue;
+        };
+        match border_color {
+            Some(mut border_color) => {
+                if border_color.0 != color {
+                    border_color.0 = color;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(BorderColor(color));
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+
+use crate::animation::read_property;
+use crate::animation::write_property;
+use crate::animation::AnimatedComponents;
+use crate::BorderProperty;
+use crate::Disabled;
+use crate::Selected;
+
+/// Overrides for a node's border and outline while it is hovered, pressed or selected.
+///
+/// Properties left as `None` keep the node's own value.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct HighlightStyle {
+    pub border_color: Option<Color>,
+    pub outline_color: Option<Color>,
+    pub outline_thickness: Option<UiRect>,
+}
+
+impl HighlightStyle {
+    /// A style that only changes the border color.
+    pub fn border(color: Color) -> Self {
+        Self {
+            border_color: Some(color),
+            ..Default::default()
+        }
+    }
+
+    /// A style that only changes the outline.
+    pub fn outline(color: Color, thickness: UiRect) -> Self {
+        Self {
+            outline_color: Some(color),
+            outline_thickness: Some(thickness),
+            ..Default::default()
+        }
+    }
+
+    fn properties(&self) -> impl Iterator<Item = BorderProperty> {
+        [
+            self.border_color.map(BorderProperty::BorderColor),
+            self.outline_color.map(BorderProperty::OutlineColor),
+            self.outline_thickness.map(BorderProperty::OutlineThickness),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// The pick state a [`PickHighlight`] is showing.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum PickState {
+    #[default]
+    None,
+    Hovered,
+    Selected,
+    Pressed,
+}
+
+/// Restyles a UI node's border and outline while it is hovered, pressed or [`Selected`].
+///
+/// The pick state is read from the node's [`Interaction`] and [`Selected`] marker, so any picking backend that
+/// updates those drives the highlight. Pressed takes priority over selected, and selected over hovered.
+/// The node's own values are restored when it returns to [`PickState::None`], which [`Disabled`] nodes always are.
+///
+/// Only properties the node has components for are changed, so give the node an [`Outline`](crate::Outline)
+/// and [`OutlineColor`](crate::OutlineColor) to highlight its outline.
+#[derive(Component, Clone, Debug, Default)]
+pub struct PickHighlight {
+    pub hovered: HighlightStyle,
+    pub pressed: HighlightStyle,
+    pub selected: HighlightStyle,
+    state: PickState,
+    /// The node's own values of the overridden properties
+    original: Vec<BorderProperty>,
+}
+
+impl PickHighlight {
+    pub fn new(hovered: HighlightStyle, pressed: HighlightStyle, selected: HighlightStyle) -> Self {
+        Self {
+            hovered,
+            pressed,
+            selected,
+            ..Default::default()
+        }
+    }
+
+    /// A highlight that uses the same style for every pick state.
+    pub fn all(style: HighlightStyle) -> Self {
+        Self {
+            hovered: style,
+            pressed: style,
+            selected: style,
+            ..Default::default()
+        }
+    }
+
+    pub fn state(&self) -> PickState {
+        self.state
+    }
+
+    fn style(&self, state: PickState) -> Option<&HighlightStyle> {
+        match state {
+            PickState::None => None,
+            PickState::Hovered => Some(&self.hovered),
+            PickState::Selected => Some(&self.selected),
+            PickState::Pressed => Some(&self.pressed),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_pick_highlights(
+    mut highlight_query: Query<(
+        &mut PickHighlight,
+        Option<&Interaction>,
+        Option<&Selected>,
+        Option<&Disabled>,
+        AnimatedComponents,
+    )>,
+) {
+    for (mut highlight, interaction, selected, disabled, mut components) in
+        highlight_query.iter_mut()
+    {
+        let state = match (interaction, selected, disabled) {
+            (_, _, Some(_)) => PickState::None,
+            (Some(Interaction::Clicked), _, _) => PickState::Pressed,
+            (_, Some(_), _) => PickState::Selected,
+            (Some(Interaction::Hovered), _, _) => PickState::Hovered,
+            _ => PickState::None,
+        };
+        if state == highlight.state {
+            continue;
+        }
+        let highlight = highlight.as_mut();
+
+        // Restore the node's own values before applying the new style, so properties the new style doesn't
+        // override aren't left highlighted
+        for original in highlight.original.drain(..) {
+            write_property(&mut components, original);
+        }
+        if let Some(style) = highlight.style(state).copied() {
+            for property in style.properties() {
+                if let Some(original) = read_property(&components, &property) {
+                    highlight.original.push(original);
+                    write_property(&mut components, property);
+                }
+            }
+        }
+        highlight.state = state;
+    }
+}
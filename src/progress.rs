@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::CalculatedBorder;
+use crate::SegmentedOverlay;
+
+/// How full a progress bar is, from 0 for empty to 1 for full.
+///
+/// Add this to a bordered UI node, usually with a [`ProgressFrameBundle`](crate::ProgressFrameBundle), and the plugin
+/// gives the node a fill child as wide as this fraction of the area enclosed by its border. The fill is sized from
+/// the node's [`CalculatedBorder`], so it follows changes to the node's size or border a frame later.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct Progress(pub f32);
+
+impl Progress {
+    /// The fraction clamped between 0 and 1, with NaN treated as empty.
+    pub fn fraction(self) -> f32 {
+        if self.0.is_nan() {
+            0.
+        } else {
+            self.0.clamp(0., 1.)
+        }
+    }
+}
+
+/// The color of a [`Progress`] bar's fill.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct ProgressFill(pub Color);
+
+impl Default for ProgressFill {
+    fn default() -> Self {
+        Self(Color::LIME_GREEN)
+    }
+}
+
+/// The fill child spawned for a [`Progress`] bar.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct ProgressFillEntity(Entity);
+
+/// Marks the fill child of a [`Progress`] bar.
+#[derive(Component, Copy, Clone, Debug, Default)]
+pub struct ProgressFillNode;
+
+/// The style of a fill covering `fraction` of the width of `inner`, the rect enclosed by a node's border.
+fn fill_style(inner: Rect, fraction: f32) -> Style {
+    // Absolutely positioned children are placed from the inside of their parent's border, but their percentage sizes
+    // are resolved against the parent's border box, so the fill is sized in pixels
+    Style {
+        position_type: PositionType::Absolute,
+        position: UiRect {
+            left: Val::Px(0.),
+            top: Val::Px(0.),
+            ..Default::default()
+        },
+        size: Size::new(Val::Px(inner.width() * fraction), Val::Px(inner.height())),
+        ..Default::default()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_progress_fills(
+    mut commands: Commands,
+    track_query: Query<
+        (
+            Entity,
+            &CalculatedBorder,
+            &Progress,
+            Option<&ProgressFill>,
+            Option<&ProgressFillEntity>,
+            Option<&SegmentedOverlay>,
+        ),
+        Without<ProgressFillNode>,
+    >,
+    mut fill_query: Query<
+        (
+            Entity,
+            &mut Style,
+            &mut BackgroundColor,
+            Option<&mut SegmentedOverlay>,
+        ),
+        With<ProgressFillNode>,
+    >,
+) {
+    for (entity, border, progress, fill, fill_entity, overlay) in track_query.iter() {
+        let color = fill.copied().unwrap_or_default().0;
+        let style = fill_style(border.inner, progress.fraction());
+        // The fill's dividers are spaced along the whole track, not just the fill
+        let overlay = overlay.map(|overlay| SegmentedOverlay {
+            span: Some(overlay.span.unwrap_or(border.inner.width())),
+            ..*overlay
+        });
+        match fill_entity.and_then(|fill_entity| fill_query.get_mut(fill_entity.0).ok()) {
+            Some((fill, mut fill_style, mut background_color, fill_overlay)) => {
+                match (fill_overlay, overlay) {
+                    (Some(mut fill_overlay), Some(overlay)) => {
+                        if *fill_overlay != overlay {
+                            *fill_overlay = overlay;
+                        }
+                    }
+                    (None, Some(overlay)) => {
+                        commands.entity(fill).insert(overlay);
+                    }
+                    (Some(_), None) => {
+                        commands.entity(fill).remove::<SegmentedOverlay>();
+                    }
+                    (None, None) => {}
+                }
+                if fill_style.size != style.size {
+                    fill_style.size = style.size;
+                }
+                if background_color.0 != color {
+                    background_color.0 = color;
+                }
+            }
+            None => {
+                let fill = commands
+                    .spawn((
+                        NodeBundle {
+                            style,
+                            background_color: color.into(),
+                            focus_policy: FocusPolicy::Pass,
+                            ..Default::default()
+                        },
+                        ProgressFillNode,
+                    ))
+                    .id();
+                commands
+                    .entity(entity)
+                    .insert(ProgressFillEntity(fill))
+                    .add_child(fill);
+            }
+        }
+    }
+}
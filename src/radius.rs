@@ -0,0 +1,465 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNode;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+
+use crate::extracted_quad;
+use crate::geometry::RoundedRect;
+use crate::world_rect;
+use crate::CalculatedBorder;
+
+/// Rounds the corners of a UI node's background, border and outline.
+///
+/// Percentage radii are resolved against the smaller dimension of the node, and each radius is limited to half of
+/// that. The node's own background, image and text are clipped to the rounded border box. If the node also has
+/// [`Overflow::Hidden`] its descendants, including their borders and outlines, are clipped to its rounded padding box.
+///
+/// Curves are built from strips one logical pixel tall. Borders and outlines filled with a texture keep square corners.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderRadius {
+    pub top_left: Val,
+    pub top_right: Val,
+    pub bottom_right: Val,
+    pub bottom_left: Val,
+}
+
+impl Default for BorderRadius {
+    fn default() -> Self {
+        Self::all(Val::Px(0.))
+    }
+}
+
+impl BorderRadius {
+    pub fn new(top_left: Val, top_right: Val, bottom_right: Val, bottom_left: Val) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// The same radius for every corner.
+    pub fn all(radius: Val) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// The radii in logical pixels for a node of the given size, in top left, top right, bottom right, bottom left
+    /// order.
+    pub fn resolve(&self, size: Vec2) -> [f32; 4] {
+        let min_dimension = size.min_element().max(0.);
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+        .map(|radius| {
+            let radius = match radius {
+                Val::Px(px) => px,
+                Val::Percent(percent) => percent / 100. * min_dimension,
+                _ => 0.,
+            };
+            if radius.is_finite() {
+                radius.clamp(0., 0.5 * min_dimension)
+            } else {
+                0.
+            }
+        })
+    }
+}
+
+/// How the corners of a UI node's background, border and outline are shaped.
+///
+/// Without this component corners are rounded by the node's [`BorderRadius`].
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub enum CornerStyle {
+    /// Corners are rounded by the node's [`BorderRadius`]
+    #[default]
+    Round,
+    /// Every corner is cut off with a straight 45° bevel, like a sci-fi HUD panel, replacing the node's
+    /// [`BorderRadius`]. The value is the length cut from each edge, with percentages and limits resolved like a
+    /// [`BorderRadius`].
+    // `Val` doesn't implement `FromReflect`, which enum fields need to be reflected
+    Chamfer(#[reflect(ignore)] Val),
+}
+
+/// The shape of a node of the given size occupying `rect`, from its [`BorderRadius`] and [`CornerStyle`].
+pub(crate) fn corner_shape(
+    rect: Rect,
+    size: Vec2,
+    radius: Option<&BorderRadius>,
+    style: Option<&CornerStyle>,
+) -> RoundedRect {
+    match style {
+        Some(CornerStyle::Chamfer(length)) => {
+            RoundedRect::chamfered(rect, BorderRadius::all(*length).resolve(size))
+        }
+        _ => RoundedRect::new(
+            rect,
+            radius
+                .map(|radius| radius.resolve(size))
+                .unwrap_or_default(),
+        ),
+    }
+}
+
+/// The rounded shape of a node's border box in its local space, `None` if the node has no rounded corners.
+pub(crate) fn local_shape(
+    size: Vec2,
+    radius: Option<&BorderRadius>,
+    style: Option<&CornerStyle>,
+) -> Option<RoundedRect> {
+    let shape = corner_shape(
+        Rect::from_center_size(Vec2::ZERO, size),
+        size,
+        radius,
+        style,
+    );
+    shape.is_rounded().then_some(shape)
+}
+
+/// The rounded outer edge of an outline around a node with the rounded shape `inner`, from the outline's edges.
+pub(crate) fn rounded_outline_shape(inner: &RoundedRect, edges: &[Option<Rect>; 4]) -> RoundedRect {
+    let bounds = edges
+        .iter()
+        .flatten()
+        .fold(inner.rect, |bounds, edge| bounds.union(*edge));
+    inner.outset([
+        inner.rect.min.x - bounds.min.x,
+        bounds.max.x - inner.rect.max.x,
+        inner.rect.min.y - bounds.min.y,
+        bounds.max.y - inner.rect.max.y,
+    ])
+}
+
+/// Pushes an extracted quad for each strip of the ring between `outer` and `inner`, given in the node's local space.
+pub(crate) fn extract_rounded_ring(
+    extracted_uinodes: &mut ExtractedUiNodes,
+    stack_index: usize,
+    transform: Mat4,
+    outer: &RoundedRect,
+    inner: &RoundedRect,
+    color: Color,
+    clip: Option<Rect>,
+) {
+    for strip in outer.ring_strips(inner) {
+        extracted_uinodes.uinodes.push(extracted_quad(
+            stack_index,
+            transform,
+            strip,
+            color,
+            None,
+            clip,
+        ));
+    }
+}
+
+/// The rounded shapes of a UI node's ancestors that clip it, in window space.
+///
+/// This component is automatically managed by the [`BordersPlugin`](crate::BordersPlugin) for nodes with an ancestor
+/// that has a [`BorderRadius`] and [`Overflow::Hidden`].
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct RoundedClip {
+    pub shapes: Vec<RoundedRect>,
+}
+
+/// The rounded padding box of a node in window space, that its descendants are clipped to.
+fn child_clip_shape(
+    node: &Node,
+    transform: &GlobalTransform,
+    radius: Option<&BorderRadius>,
+    style: Option<&CornerStyle>,
+    border: Option<&CalculatedBorder>,
+) -> RoundedRect {
+    let shape = corner_shape(world_rect(node, transform), node.size(), radius, style);
+    shape.inset(border.map(|border| border.resolved).unwrap_or_default())
+}
+
+type RoundedClipQueryItem<'a> = (
+    &'a Node,
+    &'a GlobalTransform,
+    &'a Style,
+    Option<&'a BorderRadius>,
+    Option<&'a CornerStyle>,
+    Option<&'a CalculatedBorder>,
+    Option<&'a mut RoundedClip>,
+);
+
+/// Updates the [`RoundedClip`] of each node below a rounded node that clips its contents.
+///
+/// Does nothing unless a node is rounded or clipped and the UI's layout, transforms or rounding changed this frame.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn update_rounded_clips(
+    mut commands: Commands,
+    rounded_query: Query<(), (With<Node>, Or<(With<BorderRadius>, With<CornerStyle>)>)>,
+    clipped_query: Query<(), With<RoundedClip>>,
+    changed_query: Query<
+        (),
+        (
+            With<Node>,
+            Or<(
+                Changed<Node>,
+                Changed<Style>,
+                Changed<GlobalTransform>,
+                Changed<BorderRadius>,
+                Changed<CornerStyle>,
+                Changed<CalculatedBorder>,
+                Changed<Children>,
+                Changed<Parent>,
+            )>,
+        ),
+    >,
+    mut removed_radii: RemovedComponents<BorderRadius>,
+    mut removed_corner_styles: RemovedComponents<CornerStyle>,
+    mut removed_parents: RemovedComponents<Parent>,
+    root_query: Query<Entity, (With<Node>, Without<Parent>)>,
+    children_query: Query<&Children, With<Node>>,
+    mut node_query: Query<RoundedClipQueryItem>,
+) {
+    // Every removal reader is drained so old events don't trigger an update later
+    let removed = removed_radii.iter().count()
+        + removed_corner_styles.iter().count()
+        + removed_parents.iter().count();
+    if rounded_query.is_empty() && clipped_query.is_empty() {
+        return;
+    }
+    if changed_query.is_empty() && removed == 0 {
+        return;
+    }
+    let mut shapes = Vec::new();
+    for root in root_query.iter() {
+        update_rounded_clips_recursive(
+            &mut commands,
+            root,
+            &mut shapes,
+            &children_query,
+            &mut node_query,
+        );
+    }
+}
+
+fn update_rounded_clips_recursive(
+    commands: &mut Commands,
+    entity: Entity,
+    shapes: &mut Vec<RoundedRect>,
+    children_query: &Query<&Children, With<Node>>,
+    node_query: &mut Query<RoundedClipQueryItem>,
+) {
+    let Ok((node, transform, style, radius, corner_style, border, clip)) =
+        node_query.get_mut(entity)
+    else {
+        return;
+    };
+    match (shapes.is_empty(), clip) {
+        (true, Some(_)) => {
+            commands.entity(entity).remove::<RoundedClip>();
+        }
+        (false, Some(mut clip)) => {
+            if clip.shapes != *shapes {
+                clip.shapes = shapes.clone();
+            }
+        }
+        (false, None) => {
+            commands.entity(entity).insert(RoundedClip {
+                shapes: shapes.clone(),
+            });
+        }
+        (true, None) => {}
+    }
+
+    let inherited = shapes.len();
+    if style.overflow == Overflow::Hidden {
+        let shape = child_clip_shape(node, transform, radius, corner_style, border);
+        if shape.is_rounded() {
+            shapes.push(shape);
+        }
+    }
+    if let Ok(children) = children_query.get(entity) {
+        for child in children.iter() {
+            update_rounded_clips_recursive(commands, *child, shapes, children_query, node_query);
+        }
+    }
+    shapes.truncate(inherited);
+}
+
+/// The window space bounds of an extracted quad.
+fn quad_bounds(uinode: &ExtractedUiNode) -> Rect {
+    let half_size = 0.5 * uinode.rect.size();
+    let corners = [
+        -half_size,
+        Vec2::new(half_size.x, -half_size.y),
+        half_size,
+        Vec2::new(-half_size.x, half_size.y),
+    ]
+    .map(|corner| {
+        uinode
+            .transform
+            .transform_point3(corner.extend(0.))
+            .truncate()
+    });
+    corners[1..].iter().fold(
+        Rect::from_corners(corners[0], corners[0]),
+        |bounds, corner| bounds.union_point(*corner),
+    )
+}
+
+/// Clips an extracted quad to a rounded shape, by replacing it with a copy for each row of the shape it overlaps,
+/// each clipped to its row. Rotated quads are only dropped if they are entirely outside the shape.
+fn clip_to_shape(uinode: ExtractedUiNode, shape: &RoundedRect, out: &mut Vec<ExtractedUiNode>) {
+    let clip = uinode.clip.unwrap_or(Rect {
+        min: Vec2::splat(f32::NEG_INFINITY),
+        max: Vec2::splat(f32::INFINITY),
+    });
+    let visible = quad_bounds(&uinode).intersect(clip);
+    if visible.intersect(shape.rect).is_empty() {
+        return;
+    }
+    // Bevy can only clip quads aligned with the window, so rotated quads are kept whole
+    if uinode.transform.x_axis.y != 0. {
+        out.push(uinode);
+        return;
+    }
+    if !shape.touches_corners(visible) {
+        out.push(ExtractedUiNode {
+            clip: Some(clip.intersect(shape.rect)),
+            ..uinode
+        });
+        return;
+    }
+    for strip in shape.strips() {
+        let strip_clip = strip.intersect(clip);
+        if strip_clip.intersect(visible).is_empty() {
+            continue;
+        }
+        out.push(ExtractedUiNode {
+            clip: Some(strip_clip),
+            ..copy_quad(&uinode)
+        });
+    }
+}
+
+/// Index of the first extracted quad added by the plugin's own extraction systems.
+#[derive(Resource, Default)]
+pub(crate) struct RoundedClipStart(usize);
+
+/// A copy of an extracted quad, with a weak handle to its image.
+fn copy_quad(uinode: &ExtractedUiNode) -> ExtractedUiNode {
+    ExtractedUiNode {
+        stack_index: uinode.stack_index,
+        transform: uinode.transform,
+        color: uinode.color,
+        rect: uinode.rect,
+        image: uinode.image.clone_weak(),
+        atlas_size: uinode.atlas_size,
+        clip: uinode.clip,
+        flip_x: uinode.flip_x,
+        flip_y: uinode.flip_y,
+    }
+}
+
+/// Clips the extracted quads from `start` onwards with the rounded shapes returned by `shapes` for each node.
+///
+/// Quads are clipped in place. The list is only rebuilt if a quad is removed, or split into a strip for each row of a
+/// shape's corners, keeping the strips where the quad was so the draw order within a node doesn't change.
+fn clip_extracted_quads(
+    extracted_uinodes: &mut ExtractedUiNodes,
+    start: usize,
+    ui_stack: &UiStack,
+    mut shapes: impl FnMut(Entity, &mut Vec<RoundedRect>),
+) {
+    let mut node_shapes = Vec::new();
+    let mut pending = Vec::new();
+    let mut clipped = Vec::new();
+    // Quads to replace, by index, with nothing or with more than one strip
+    let mut replacements: Vec<(usize, Vec<ExtractedUiNode>)> = Vec::new();
+    for (index, quad) in extracted_uinodes.uinodes.iter_mut().enumerate().skip(start) {
+        node_shapes.clear();
+        if let Some(entity) = ui_stack.uinodes.get(quad.stack_index) {
+            shapes(*entity, &mut node_shapes);
+        }
+        if node_shapes.is_empty() {
+            continue;
+        }
+        pending.push(copy_quad(quad));
+        for shape in node_shapes.iter() {
+            for quad in pending.drain(..) {
+                clip_to_shape(quad, shape, &mut clipped);
+            }
+            std::mem::swap(&mut pending, &mut clipped);
+        }
+        if pending.len() == 1 {
+            *quad = pending.pop().unwrap();
+        } else {
+            replacements.push((index, std::mem::take(&mut pending)));
+        }
+    }
+    if replacements.is_empty() {
+        return;
+    }
+
+    let quads = std::mem::take(&mut extracted_uinodes.uinodes);
+    let mut replacements = replacements.into_iter().peekable();
+    for (index, quad) in quads.into_iter().enumerate() {
+        match replacements.next_if(|(replaced, _)| *replaced == index) {
+            Some((_, strips)) => extracted_uinodes.uinodes.extend(strips),
+            None => extracted_uinodes.uinodes.push(quad),
+        }
+    }
+}
+
+/// Clips the quads extracted by Bevy for each node to the node's own rounded shape and the rounded shapes of its
+/// ancestors.
+#[allow(clippy::type_complexity)]
+pub(crate) fn clip_extracted_nodes(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    mut start: ResMut<RoundedClipStart>,
+    ui_stack: Extract<Res<UiStack>>,
+    node_query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            Option<&BorderRadius>,
+            Option<&CornerStyle>,
+            Option<&RoundedClip>,
+        )>,
+    >,
+) {
+    clip_extracted_quads(&mut extracted_uinodes, 0, &ui_stack, |entity, shapes| {
+        let Ok((node, transform, radius, style, clip)) = node_query.get(entity) else {
+            return;
+        };
+        let shape = corner_shape(world_rect(node, transform), node.size(), radius, style);
+        if shape.is_rounded() {
+            shapes.push(shape);
+        }
+        if let Some(clip) = clip {
+            shapes.extend(clip.shapes.iter().copied());
+        }
+    });
+    start.0 = extracted_uinodes.uinodes.len();
+}
+
+/// Clips the quads extracted by the plugin for each node to the rounded shapes of its ancestors.
+///
+/// Borders and outlines of rounded nodes are already rounded, so they aren't clipped by their own shape.
+pub(crate) fn clip_extracted_decorations(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    start: Res<RoundedClipStart>,
+    ui_stack: Extract<Res<UiStack>>,
+    clip_query: Extract<Query<&RoundedClip>>,
+) {
+    clip_extracted_quads(
+        &mut extracted_uinodes,
+        start.0,
+        &ui_stack,
+        |entity, shapes| {
+            if let Ok(clip) = clip_query.get(entity) {
+                shapes.extend(clip.shapes.iter().copied());
+            }
+        },
+    );
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use bevy::ui::UiStack;
+use serde::Serialize;
+
+use crate::global_geometry::to_window;
+use crate::BorderColor;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::OutlineColor;
+
+/// A rect in window space, in logical pixels with the origin at the top left.
+#[derive(Serialize, Copy, Clone, Debug, PartialEq)]
+pub struct ReportRect {
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+}
+
+impl From<Rect> for ReportRect {
+    fn from(rect: Rect) -> Self {
+        Self {
+            min: rect.min.to_array(),
+            max: rect.max.to_array(),
+        }
+    }
+}
+
+/// The geometry and color of a border or outline in a [`GeometryReport`].
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct EdgesReport {
+    /// The left, right, top and bottom edges, `None` if an edge has no area
+    pub edges: [Option<ReportRect>; 4],
+    /// Linear RGBA color, `None` if the node has no color for these edges
+    pub color: Option<[f32; 4]>,
+}
+
+/// One UI node's border and outline in a [`GeometryReport`].
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct NodeGeometryReport {
+    /// The node's entity, from [`Entity::to_bits`]
+    pub entity: u64,
+    /// The node's [`Name`], if it has one
+    pub name: Option<String>,
+    /// The node's border box
+    pub node: ReportRect,
+    pub border: Option<EdgesReport>,
+    pub outline: Option<EdgesReport>,
+}
+
+/// Every calculated border and outline in a world, for UI testing tools and layout linters to check the visual
+/// structure of a UI without rendering it.
+///
+/// Nodes are listed in the order they are drawn, or in entity order before the UI stack has been built.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct GeometryReport {
+    pub nodes: Vec<NodeGeometryReport>,
+}
+
+impl GeometryReport {
+    /// Collects the geometry of every UI node with a [`CalculatedBorder`] or [`CalculatedOutline`].
+    pub fn from_world(world: &mut World) -> Self {
+        let mut query = world.query_filtered::<(
+            Entity,
+            &Node,
+            &GlobalTransform,
+            Option<&Name>,
+            Option<(&CalculatedBorder, Option<&BorderColor>)>,
+            Option<(&CalculatedOutline, Option<&OutlineColor>)>,
+        ), Or<(With<CalculatedBorder>, With<CalculatedOutline>)>>();
+        let stack_order = world
+            .get_resource::<UiStack>()
+            .map(|ui_stack| ui_stack.uinodes.clone())
+            .unwrap_or_default();
+        let mut nodes: Vec<_> = query
+            .iter(world)
+            .map(|(entity, node, transform, name, border, outline)| {
+                let edges = |edges: [Option<Rect>; 4]| {
+                    edges.map(|edge| edge.map(|edge| to_window(transform, edge).into()))
+                };
+                let border_color = border.and_then(|(_, color)| color).map(|color| color.0);
+                // Outlines without an OutlineColor are drawn in the border color
+                let outline_color = outline
+                    .and_then(|(_, color)| color)
+                    .map(|color| color.0)
+                    .or(border_color);
+                NodeGeometryReport {
+                    entity: entity.to_bits(),
+                    name: name.map(|name| name.to_string()),
+                    node: to_window(transform, Rect::from_center_size(Vec2::ZERO, node.size()))
+                        .into(),
+                    border: border.map(|(border, _)| EdgesReport {
+                        edges: edges(border.edges),
+                        color: border_color.map(|color| color.as_linear_rgba_f32()),
+                    }),
+                    outline: outline.map(|(outline, _)| EdgesReport {
+                        edges: edges(outline.edges),
+                        color: outline_color.map(|color| color.as_linear_rgba_f32()),
+                    }),
+                }
+            })
+            .collect();
+        nodes.sort_by_key(|node| {
+            let entity = Entity::from_bits(node.entity);
+            (
+                stack_order
+                    .iter()
+                    .position(|&stacked| stacked == entity)
+                    .unwrap_or(usize::MAX),
+                node.entity,
+            )
+        });
+        Self { nodes }
+    }
+
+    /// The report as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("geometry reports are always serializable")
+    }
+}
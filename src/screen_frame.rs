@@ -0,0 +1,280 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+use bevy::window::PrimaryWindow;
+
+use crate::extracted_quad;
+use crate::geometry::border_edges;
+use crate::geometry::resolve_thickness;
+use crate::geometry::RoundedRect;
+use crate::shadow::push_shadow_rings;
+use crate::DecorationQuad;
+
+/// The parts of the primary window along each edge that may be hidden, like a phone's notch and rounded corners or
+/// a TV's overscan, in logical pixels.
+///
+/// Bevy doesn't report safe areas, so fill this in from the platform. A [`ScreenFrame`] with `safe_area` set and the
+/// borders of root UI nodes with a [`SafeAreaBorder`] are drawn inside the safe area.
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct SafeAreaInsets {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl SafeAreaInsets {
+    pub const fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// The same inset along every edge.
+    pub const fn all(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// The safe part of a viewport of the given size, with the origin at its top left corner.
+    pub fn safe_rect(&self, viewport_size: Vec2) -> Rect {
+        let min = Vec2::new(self.left, self.top).max(Vec2::ZERO);
+        let max = (viewport_size - Vec2::new(self.right, self.bottom).max(Vec2::ZERO)).max(min);
+        Rect { min, max }
+    }
+}
+
+/// Draws the border of a root UI node inside the [`SafeAreaInsets`], like a HUD frame that must stay clear of a
+/// phone's notch.
+///
+/// The border is drawn around the part of the node inside the safe area. Like
+/// [`BorderRelativeTo`](crate::BorderRelativeTo) this only changes how the border is drawn, so the node's content isn't
+/// moved. Borders with a [`BorderRadius`](crate::BorderRadius) keep their rounded corners at the node's edges.
+/// Nodes with a parent are unaffected.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct SafeAreaBorder;
+
+/// A frame drawn around the edges of the primary window, above the whole UI and independent of any UI node, like a
+/// red vignette flashed when the player takes damage.
+///
+/// The frame is a solid border `thickness` thick, with an optional fade inside it that eases from `color` to
+/// transparent. Insert the resource to show the frame and remove it, or make `color` transparent, to hide it.
+/// Set `safe_area` to draw the frame around the edges of the [`SafeAreaInsets`] instead of the window.
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct ScreenFrame {
+    /// Thickness of the solid frame along each edge, percentages are resolved against the width of the framed area
+    pub thickness: UiRect,
+    pub color: Color,
+    /// Width in logical pixels of the fade inside the solid frame
+    pub fade: f32,
+    /// Frame the safe area instead of the whole window
+    pub safe_area: bool,
+}
+
+impl Default for ScreenFrame {
+    fn default() -> Self {
+        Self {
+            thickness: UiRect::all(Val::Px(0.)),
+            color: Color::RED,
+            fade: 0.,
+            safe_area: false,
+        }
+    }
+}
+
+impl ScreenFrame {
+    /// A solid frame of the same thickness along every edge.
+    pub fn solid(thickness: Val, color: Color) -> Self {
+        Self {
+            thickness: UiRect::all(thickness),
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// A vignette fading in from the edges of the window over `fade` logical pixels.
+    pub fn vignette(fade: f32, color: Color) -> Self {
+        Self {
+            color,
+            fade,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_fade(mut self, fade: f32) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    pub fn with_safe_area(mut self) -> Self {
+        self.safe_area = true;
+        self
+    }
+
+    /// The quads of the frame around a UI viewport of the given size, with the origin at its top left corner.
+    pub fn quads(&self, viewport_size: Vec2) -> Vec<DecorationQuad> {
+        self.quads_around(Rect::from_corners(Vec2::ZERO, viewport_size))
+    }
+
+    /// The quads of the frame around the inside of `area`.
+    pub fn quads_around(&self, area: Rect) -> Vec<DecorationQuad> {
+        let mut quads = Vec::new();
+        let viewport_size = area.size();
+        if self.color.a() == 0. || viewport_size.cmple(Vec2::ZERO).any() {
+            return quads;
+        }
+        let thickness = [
+            self.thickness.left,
+            self.thickness.right,
+            self.thickness.top,
+            self.thickness.bottom,
+        ]
+        .map(|value| resolve_thickness(value, viewport_size.x));
+        let (edges, inner) = border_edges(viewport_size, thickness);
+        quads.extend(edges.into_iter().flatten().map(|rect| DecorationQuad {
+            rect,
+            color: self.color,
+        }));
+        if 0. < self.fade {
+            push_shadow_rings(
+                RoundedRect::new(inner, [0.; 4]),
+                -self.fade,
+                self.color,
+                &mut quads,
+            );
+        }
+        let offset = area.center();
+        for quad in quads.iter_mut() {
+            quad.rect = Rect {
+                min: quad.rect.min + offset,
+                max: quad.rect.max + offset,
+            };
+        }
+        quads
+    }
+}
+
+/// Black bars along the edges of the primary window that leave a view of a fixed aspect ratio in the middle, like
+/// the letterboxing of a cutscene.
+///
+/// Bars are drawn above and below the view when the window is wider than `aspect` and to its sides when it is
+/// narrower, above the whole UI. Use [`Letterbox::view`] to fit a camera's viewport or a UI root to the view.
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct Letterbox {
+    /// The width of the view divided by its height
+    pub aspect: f32,
+    pub color: Color,
+}
+
+impl Default for Letterbox {
+    fn default() -> Self {
+        Self {
+            aspect: 2.39,
+            color: Color::BLACK,
+        }
+    }
+}
+
+impl Letterbox {
+    pub fn new(aspect: f32, color: Color) -> Self {
+        Self { aspect, color }
+    }
+
+    /// The largest rect of the target aspect ratio centered in a viewport of the given size, with the origin at its
+    /// top left corner.
+    pub fn view(&self, viewport_size: Vec2) -> Rect {
+        let full = Rect::from_corners(Vec2::ZERO, viewport_size);
+        if !(0. < self.aspect && self.aspect.is_finite()) || viewport_size.cmple(Vec2::ZERO).any() {
+            return full;
+        }
+        let size = if self.aspect < viewport_size.x / viewport_size.y {
+            Vec2::new(viewport_size.y * self.aspect, viewport_size.y)
+        } else {
+            Vec2::new(viewport_size.x, viewport_size.x / self.aspect)
+        };
+        Rect::from_center_size(full.center(), size)
+    }
+
+    /// The bars covering the parts of a viewport of the given size outside the [`Letterbox::view`].
+    pub fn bars(&self, viewport_size: Vec2) -> Vec<Rect> {
+        let view = self.view(viewport_size);
+        let (edges, _) = border_edges(
+            viewport_size,
+            [
+                view.min.x,
+                viewport_size.x - view.max.x,
+                view.min.y,
+                viewport_size.y - view.max.y,
+            ],
+        );
+        let offset = 0.5 * viewport_size;
+        edges
+            .into_iter()
+            .flatten()
+            .map(|rect| Rect {
+                min: rect.min + offset,
+                max: rect.max + offset,
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn extract_screen_frame(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    ui_stack: Extract<Res<UiStack>>,
+    ui_scale: Extract<Res<UiScale>>,
+    screen_frame: Extract<Option<Res<ScreenFrame>>>,
+    letterbox: Extract<Option<Res<Letterbox>>>,
+    safe_area: Extract<Res<SafeAreaInsets>>,
+    window_query: Extract<Query<&Window, With<PrimaryWindow>>>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    // Draw above every UI node
+    let stack_index = ui_stack.uinodes.len();
+    let viewport_size = Vec2::new(window.width(), window.height()) / ui_scale.scale as f32;
+    let frame_quads = screen_frame
+        .as_ref()
+        .map(|screen_frame| {
+            let area = if screen_frame.safe_area {
+                safe_area.safe_rect(viewport_size)
+            } else {
+                Rect::from_corners(Vec2::ZERO, viewport_size)
+            };
+            screen_frame.quads_around(area)
+        })
+        .unwrap_or_default();
+    // The letterbox bars are drawn over the frame
+    let bar_quads: Vec<DecorationQuad> = letterbox
+        .as_ref()
+        .filter(|letterbox| letterbox.color.a() != 0.)
+        .map(|letterbox| {
+            letterbox
+                .bars(viewport_size)
+                .into_iter()
+                .map(|rect| DecorationQuad {
+                    rect,
+                    color: letterbox.color,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    for quad in frame_quads.into_iter().chain(bar_quads) {
+        extracted_uinodes.uinodes.push(extracted_quad(
+            stack_index,
+            Mat4::IDENTITY,
+            quad.rect,
+            quad.color,
+            None,
+            None,
+        ));
+    }
+}
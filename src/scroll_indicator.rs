@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+
+use crate::world_rect;
+use crate::CalculatedBorder;
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// How far content has to extend past the visible area, in logical pixels, before an indicator is shown.
+const SCROLL_TOLERANCE: f32 = 0.5;
+
+/// Recolors the top and bottom borders of a scroll container while there is more content above or below it.
+///
+/// Add this to the bordered node framing the scrolled content. The plugin compares the area enclosed by the node's
+/// border with the bounds of all of its descendants each frame, and draws the top or bottom border edge in `color`
+/// while content extends past it. Nodes without a border on that side get a strip `thickness` logical pixels thick
+/// inside the edge instead.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct ScrollIndicatorBorder {
+    pub color: Color,
+    /// Thickness of the strip drawn on sides without a border, in logical pixels
+    pub thickness: f32,
+    #[reflect(ignore)]
+    more_above: bool,
+    #[reflect(ignore)]
+    more_below: bool,
+}
+
+impl Default for ScrollIndicatorBorder {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            thickness: 2.,
+            more_above: false,
+            more_below: false,
+        }
+    }
+}
+
+impl ScrollIndicatorBorder {
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// Whether content extends above the visible area, updated each frame.
+    pub fn more_above(&self) -> bool {
+        self.more_above
+    }
+
+    /// Whether content extends below the visible area, updated each frame.
+    pub fn more_below(&self) -> bool {
+        self.more_below
+    }
+}
+
+impl UiDecoration for ScrollIndicatorBorder {
+    const LAYER: DecorationLayer = DecorationLayer::Above;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let node_rect = context.node_rect();
+        let thickness = self.thickness.max(0.);
+        let edges = context.border.map(|border| border.edges);
+        for (shown, edge, fallback) in [
+            (
+                self.more_above,
+                edges.and_then(|edges| edges[2]),
+                Rect::new(
+                    node_rect.min.x,
+                    node_rect.min.y,
+                    node_rect.max.x,
+                    node_rect.min.y + thickness,
+                ),
+            ),
+            (
+                self.more_below,
+                edges.and_then(|edges| edges[3]),
+                Rect::new(
+                    node_rect.min.x,
+                    node_rect.max.y - thickness,
+                    node_rect.max.x,
+                    node_rect.max.y,
+                ),
+            ),
+        ] {
+            if shown {
+                quads.push(DecorationQuad {
+                    rect: edge.unwrap_or(fallback),
+                    color: self.color,
+                });
+            }
+        }
+    }
+}
+
+pub(crate) fn update_scroll_indicators(
+    children_query: Query<&Children>,
+    node_query: Query<(&Node, &GlobalTransform)>,
+    mut indicator_query: Query<(
+        Entity,
+        &Node,
+        &GlobalTransform,
+        Option<&CalculatedBorder>,
+        &mut ScrollIndicatorBorder,
+    )>,
+) {
+    for (entity, node, transform, border, mut indicator) in indicator_query.iter_mut() {
+        let node_rect = world_rect(node, transform);
+        let visible = match border {
+            Some(border) => Rect {
+                min: node_rect.center() + border.inner.min,
+                max: node_rect.center() + border.inner.max,
+            },
+            None => node_rect,
+        };
+        let content = children_query
+            .iter_descendants(entity)
+            .filter_map(|descendant| node_query.get(descendant).ok())
+            .map(|(node, transform)| world_rect(node, transform))
+            .reduce(|content, rect| content.union(rect));
+        let (more_above, more_below) = content.map_or((false, false), |content| {
+            (
+                content.min.y < visible.min.y - SCROLL_TOLERANCE,
+                visible.max.y + SCROLL_TOLERANCE < content.max.y,
+            )
+        });
+        if indicator.more_above != more_above || indicator.more_below != more_below {
+            indicator.more_above = more_above;
+            indicator.more_below = more_below;
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::DecorationContext;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// Evenly spaced divider lines drawn across a UI node, like the notches of a segmented health bar.
+///
+/// The dividers split the width of the area enclosed by the node's border into `segments` equal parts, so they stay
+/// clear of the border whatever its thickness. A [`Progress`](crate::Progress) bar copies its overlay onto its fill,
+/// so the dividers are drawn over the fill as well as the empty track.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct SegmentedOverlay {
+    pub segments: u32,
+    /// Width of each divider in logical pixels
+    pub gap: f32,
+    pub color: Color,
+    /// Width in logical pixels divided into segments, measured from the left of the area enclosed by the border.
+    /// The whole width of that area if `None`.
+    pub span: Option<f32>,
+}
+
+impl Default for SegmentedOverlay {
+    fn default() -> Self {
+        Self {
+            segments: 5,
+            gap: 2.,
+            color: Color::BLACK,
+            span: None,
+        }
+    }
+}
+
+impl SegmentedOverlay {
+    pub fn new(segments: u32, gap: f32, color: Color) -> Self {
+        Self {
+            segments,
+            gap,
+            color,
+            span: None,
+        }
+    }
+
+    /// The rects of the dividers in the node's local space, clipped to the area enclosed by the border.
+    pub fn dividers(&self, context: &DecorationContext) -> Vec<Rect> {
+        let inner = context.inner_rect();
+        let span = self.span.unwrap_or(inner.width());
+        if self.segments < 2 || self.gap <= 0. || span <= 0. {
+            return Vec::new();
+        }
+        let step = span / self.segments as f32;
+        (1..self.segments)
+            .filter_map(|i| {
+                let x = inner.min.x + i as f32 * step;
+                let divider = Rect::new(
+                    x - 0.5 * self.gap,
+                    inner.min.y,
+                    x + 0.5 * self.gap,
+                    inner.max.y,
+                )
+                .intersect(inner);
+                (!divider.is_empty()).then_some(divider)
+            })
+            .collect()
+    }
+}
+
+impl UiDecoration for SegmentedOverlay {
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        quads.extend(
+            self.dividers(context)
+                .into_iter()
+                .map(|rect| DecorationQuad {
+                    rect,
+                    color: self.color,
+                }),
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+
+use crate::extracted_quad;
+use crate::geometry::edge_rects;
+use crate::world_rect;
+use crate::BorderAnimation;
+use crate::BorderAnimationFinished;
+use crate::BorderProperty;
+use crate::Easing;
+use crate::Outline;
+use crate::OutlineColor;
+use crate::OutlineHidden;
+use crate::OutlineScale;
+use crate::OutlineShown;
+
+/// Highlights the currently selected UI node with an outline, for console-style menus.
+///
+/// Insert this resource to enable the selection cursor and set `selected` from your menu navigation code.
+/// When the selection changes the outline is removed from the previously selected node and added to the newly
+/// selected one, fading between them if `transition` is set. A node's own outline is restored when the cursor leaves
+/// it, after the fade out.
+#[derive(Resource, Clone, Debug)]
+pub struct SelectionCursor {
+    /// The node that currently has the cursor
+    pub selected: Option<Entity>,
+    /// Thickness of the cursor's outline
+    pub thickness: UiRect,
+    pub color: Color,
+    /// Duration in seconds and easing of the fade between nodes, or `None` to move the cursor instantly
+    pub transition: Option<(f32, Easing)>,
+}
+
+impl Default for SelectionCursor {
+    fn default() -> Self {
+        Self {
+            selected: None,
+            thickness: UiRect::all(Val::Px(4.)),
+            color: Color::WHITE,
+            transition: Some((0.15, Easing::QuadraticOut)),
+        }
+    }
+}
+
+impl SelectionCursor {
+    pub fn new(thickness: UiRect, color: Color) -> Self {
+        Self {
+            thickness,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// Moves the cursor to `entity`.
+    pub fn select(&mut self, entity: Entity) {
+        self.selected = Some(entity);
+    }
+
+    /// Removes the cursor from the selected node.
+    pub fn clear(&mut self) {
+        self.selected = None;
+    }
+}
+
+/// The outline a node had before the [`SelectionCursor`] moved to it, restored when the cursor leaves.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct OutlineBeforeCursor(Option<(Outline, Option<OutlineColor>)>);
+
+/// Replaces the cursor's outline with the one the node had before.
+fn restore_outline(entity_commands: &mut EntityCommands, before: OutlineBeforeCursor) {
+    entity_commands.remove::<(Outline, OutlineColor, OutlineBeforeCursor)>();
+    if let Some((outline, color)) = before.0 {
+        entity_commands.insert(outline);
+        if let Some(color) = color {
+            entity_commands.insert(color);
+        }
+    }
+}
+
+/// Transitions the outline color, adding to the node's running animation rather than replacing it so the
+/// animation's other transitions still finish.
+fn animate_outline_color(
+    entity_commands: &mut EntityCommands,
+    animation: Option<&BorderAnimation>,
+    color: Color,
+    (duration, easing): (f32, Easing),
+) {
+    let target = BorderProperty::OutlineColor(color);
+    entity_commands.insert(match animation {
+        Some(animation) => animation.clone().with(target, duration, easing),
+        None => BorderAnimation::new(target, duration, easing),
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn move_selection_cursor(
+    mut commands: Commands,
+    cursor: Res<SelectionCursor>,
+    mut previous: Local<Option<Entity>>,
+    mut shown_events: EventWriter<OutlineShown>,
+    mut hidden_events: EventWriter<OutlineHidden>,
+    mut finished_events: EventReader<BorderAnimationFinished>,
+    outline_query: Query<(Option<&Outline>, Option<&OutlineColor>)>,
+    before_query: Query<&OutlineBeforeCursor>,
+    animation_query: Query<&BorderAnimation>,
+) {
+    // Nodes the cursor faded out of get their own outline back once the fade finishes
+    for event in finished_events.iter() {
+        if Some(event.entity) == cursor.selected
+            || !matches!(event.property, BorderProperty::OutlineColor(_))
+        {
+            continue;
+        }
+        if let Ok(before) = before_query.get(event.entity) {
+            restore_outline(&mut commands.entity(event.entity), *before);
+        }
+    }
+
+    if !cursor.is_changed() {
+        return;
+    }
+
+    if let Some(entity) = previous.filter(|entity| Some(*entity) != cursor.selected) {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            hidden_events.send(OutlineHidden { entity });
+            match (cursor.transition, before_query.get(entity)) {
+                (Some(transition), _) => animate_outline_color(
+                    &mut entity_commands,
+                    animation_query.get(entity).ok(),
+                    Color::NONE,
+                    transition,
+                ),
+                (None, Ok(before)) => restore_outline(&mut entity_commands, *before),
+                (None, Err(_)) => {}
+            }
+        }
+    }
+
+    if let Some(entity) = cursor.selected {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            if *previous != Some(entity) {
+                shown_events.send(OutlineShown { entity });
+            }
+            let (outline, color) = outline_query.get(entity).unwrap_or_default();
+            if !before_query.contains(entity) {
+                entity_commands.insert(OutlineBeforeCursor(
+                    outline.map(|outline| (*outline, color.copied())),
+                ));
+            }
+            entity_commands.insert(Outline::thickness(cursor.thickness));
+            match cursor.transition {
+                Some(transition) => {
+                    if color.is_none() {
+                        entity_commands.insert(OutlineColor(Color::NONE));
+                    }
+                    animate_outline_color(
+                        &mut entity_commands,
+                        animation_query.get(entity).ok(),
+                        cursor.color,
+                        transition,
+                    );
+                }
+                None => {
+                    entity_commands.insert(OutlineColor(cursor.color));
+                }
+            }
+        }
+    }
+
+    *previous = cursor.selected;
+}
+
+/// Marks a UI node as selected. Selected nodes inside a [`SelectionGroup`] are outlined with the
+/// [`SelectionGroupStyle`] until the marker is removed.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Selected;
+
+/// A container whose [`Selected`] descendants are outlined, like a panel of unit cards in an RTS.
+///
+/// Unless `multi_select` is set, selecting a node deselects the other selected nodes in the group.
+/// Nodes belong to their nearest ancestor with a `SelectionGroup`.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct SelectionGroup {
+    pub multi_select: bool,
+}
+
+impl Default for SelectionGroup {
+    fn default() -> Self {
+        Self { multi_select: true }
+    }
+}
+
+/// The outline drawn around every [`Selected`] node in a [`SelectionGroup`].
+#[derive(Resource, Copy, Clone, Debug, Reflect)]
+#[reflect(Resource, Default)]
+pub struct SelectionGroupStyle {
+    pub thickness: UiRect,
+    pub color: Color,
+}
+
+impl Default for SelectionGroupStyle {
+    fn default() -> Self {
+        Self {
+            thickness: UiRect::all(Val::Px(2.)),
+            color: Color::LIME_GREEN,
+        }
+    }
+}
+
+/// The outline a node had before it was selected, restored when it is deselected.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct OutlineBeforeSelection(Option<(Outline, Option<OutlineColor>)>);
+
+/// The nearest ancestor of `entity` with a [`SelectionGroup`].
+fn selection_group(
+    entity: Entity,
+    parent_query: &Query<&Parent>,
+    group_query: &Query<&SelectionGroup>,
+) -> Option<Entity> {
+    parent_query
+        .iter_ancestors(entity)
+        .find(|ancestor| group_query.contains(*ancestor))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_selection_groups(
+    mut commands: Commands,
+    style: Res<SelectionGroupStyle>,
+    mut deselected: RemovedComponents<Selected>,
+    mut shown_events: EventWriter<OutlineShown>,
+    mut hidden_events: EventWriter<OutlineHidden>,
+    parent_query: Query<&Parent>,
+    group_query: Query<&SelectionGroup>,
+    added_query: Query<Entity, Added<Selected>>,
+    selected_query: Query<Entity, With<Selected>>,
+    outline_query: Query<(
+        Option<&Outline>,
+        Option<&OutlineColor>,
+        Option<&OutlineBeforeSelection>,
+    )>,
+) {
+    for entity in deselected.iter() {
+        if selected_query.contains(entity) {
+            continue;
+        }
+        let Ok((.., Some(OutlineBeforeSelection(before)))) = outline_query.get(entity) else {
+            continue;
+        };
+        hidden_events.send(OutlineHidden { entity });
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<(Outline, OutlineColor, OutlineBeforeSelection)>();
+        if let Some((outline, color)) = *before {
+            entity_commands.insert(outline);
+            if let Some(color) = color {
+                entity_commands.insert(color);
+            }
+        }
+    }
+
+    let mut deselecting = Vec::new();
+    for entity in added_query.iter() {
+        let Some(group) = selection_group(entity, &parent_query, &group_query) else {
+            continue;
+        };
+        if !group_query.get(group).is_ok_and(|group| group.multi_select) {
+            deselecting.extend(selected_query.iter().filter(|other| {
+                *other != entity
+                    && !added_query.contains(*other)
+                    && selection_group(*other, &parent_query, &group_query) == Some(group)
+            }));
+        }
+        let Ok((outline, color, before)) = outline_query.get(entity) else {
+            continue;
+        };
+        shown_events.send(OutlineShown { entity });
+        if before.is_none() {
+            commands.entity(entity).insert(OutlineBeforeSelection(
+                outline.map(|outline| (*outline, color.copied())),
+            ));
+        }
+    }
+    for entity in deselecting {
+        commands.entity(entity).remove::<Selected>();
+    }
+
+    for entity in selected_query.iter() {
+        if (style.is_changed() || added_query.contains(entity))
+            && selection_group(entity, &parent_query, &group_query).is_some()
+        {
+            commands.entity(entity).insert((
+                Outline::thickness(style.thickness),
+                OutlineColor(style.color),
+            ));
+        }
+    }
+}
+
+/// A single outline that slides between UI nodes instead of jumping, for highlighting the selected item in a menu.
+///
+/// Spawn this on its own entity, it doesn't need to be a UI node. Whenever `target` changes the outline's
+/// rect is interpolated from its current position to the new target's over `duration` seconds. The outline is
+/// drawn above every UI node, clipped by the same clipping rect as its target.
+#[derive(Component, Clone, Debug)]
+pub struct SlidingOutline {
+    /// The node the outline is sliding to or surrounding
+    pub target: Option<Entity>,
+    /// Thickness of the outline in logical pixels
+    pub thickness: f32,
+    pub color: Color,
+    /// Length of the slide in seconds
+    pub duration: f32,
+    pub easing: Easing,
+    /// Where the current slide started
+    from: Option<Rect>,
+    /// The rect enclosed by the outline, in window space
+    current: Option<Rect>,
+    /// The target of the current slide
+    sliding_to: Option<Entity>,
+    /// Seconds since the current slide started
+    elapsed: f32,
+}
+
+impl Default for SlidingOutline {
+    fn default() -> Self {
+        Self {
+            target: None,
+            thickness: 4.,
+            color: Color::WHITE,
+            duration: 0.1,
+            easing: Easing::QuadraticOut,
+            from: None,
+            current: None,
+            sliding_to: None,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl SlidingOutline {
+    pub fn new(thickness: f32, color: Color) -> Self {
+        Self {
+            thickness,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// The rect enclosed by the outline in window space, `None` while it has no target.
+    pub fn current_rect(&self) -> Option<Rect> {
+        self.current
+    }
+}
+
+fn lerp_rect(start: Rect, end: Rect, t: f32) -> Rect {
+    Rect {
+        min: start.min.lerp(end.min, t),
+        max: start.max.lerp(end.max, t),
+    }
+}
+
+pub(crate) fn slide_outlines(
+    time: Res<Time>,
+    mut shown_events: EventWriter<OutlineShown>,
+    mut hidden_events: EventWriter<OutlineHidden>,
+    node_query: Query<(&Node, &GlobalTransform)>,
+    mut sliding_query: Query<&mut SlidingOutline>,
+) {
+    for mut sliding_outline in sliding_query.iter_mut() {
+        let Some(target_rect) = sliding_outline
+            .target
+            .and_then(|target| node_query.get(target).ok())
+            .map(|(node, transform)| world_rect(node, transform))
+        else {
+            if let Some(entity) = sliding_outline.sliding_to.take() {
+                hidden_events.send(OutlineHidden { entity });
+            }
+            sliding_outline.current = None;
+            continue;
+        };
+
+        if sliding_outline.sliding_to != sliding_outline.target {
+            if let Some(entity) = sliding_outline.sliding_to {
+                hidden_events.send(OutlineHidden { entity });
+            }
+            if let Some(entity) = sliding_outline.target {
+                shown_events.send(OutlineShown { entity });
+            }
+            sliding_outline.sliding_to = sliding_outline.target;
+            sliding_outline.from = sliding_outline.current;
+            sliding_outline.elapsed = 0.;
+        }
+        sliding_outline.elapsed += time.delta_seconds();
+
+        let progress = if sliding_outline.duration <= 0. {
+            1.
+        } else {
+            (sliding_outline.elapsed / sliding_outline.duration).min(1.)
+        };
+        // The target is tracked every frame so the outline follows nodes that move during the slide
+        sliding_outline.current = Some(match sliding_outline.from {
+            Some(from) if progress < 1. => {
+                lerp_rect(from, target_rect, sliding_outline.easing.ease(progress))
+            }
+            _ => target_rect,
+        });
+    }
+}
+
+pub(crate) fn extract_sliding_outlines(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    ui_stack: Extract<Res<UiStack>>,
+    outline_scale: Extract<Res<OutlineScale>>,
+    sliding_query: Extract<Query<&SlidingOutline>>,
+    clip_query: Extract<Query<&CalculatedClip>>,
+) {
+    // Draw above every UI node
+    let stack_index = ui_stack.uinodes.len();
+    for sliding_outline in sliding_query.iter() {
+        let Some(rect) = sliding_outline.current else {
+            continue;
+        };
+        if sliding_outline.color.a() == 0. || sliding_outline.thickness <= 0. {
+            continue;
+        }
+        // Clip to the target's clipping rect, so the outline is hidden with a target scrolled out of view
+        let clip = sliding_outline
+            .target
+            .and_then(|target| clip_query.get(target).ok())
+            .map(|clip| clip.clip);
+        let thickness = Vec2::splat(sliding_outline.thickness * outline_scale.0.max(0.));
+        for edge in edge_rects(
+            rect.min - thickness,
+            rect.max + thickness,
+            rect.min,
+            rect.max,
+        ) {
+            if edge.is_empty() {
+                continue;
+            }
+            extracted_uinodes.uinodes.push(extracted_quad(
+                stack_index,
+                Mat4::IDENTITY,
+                edge,
+                sliding_outline.color,
+                None,
+                clip,
+            ));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::geometry::edge_rects;
+use crate::geometry::RoundedRect;
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// The maximum number of rings used to approximate a shadow's falloff.
+const MAX_SHADOW_STEPS: usize = 16;
+
+/// A soft glow that fades out around the outside of a UI node's outline.
+///
+/// If the node has no outline, the glow surrounds the node's border box instead.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct OutlineShadow {
+    /// Color of the shadow where it meets the outline
+    pub color: Color,
+    /// Distance in logical pixels over which the shadow fades to transparent
+    pub blur: f32,
+}
+
+impl Default for OutlineShadow {
+    fn default() -> Self {
+        Self {
+            color: Color::rgba(0., 0., 0., 0.5),
+            blur: 8.,
+        }
+    }
+}
+
+impl OutlineShadow {
+    pub fn new(color: Color, blur: f32) -> Self {
+        Self { color, blur }
+    }
+}
+
+/// A soft shadow that fades in from the inside edge of a UI node's border, giving the node a recessed look.
+///
+/// If the node has no border, the shadow fades in from the edges of the node.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct InsetShadow {
+    /// Color of the shadow where it meets the border
+    pub color: Color,
+    /// Distance in logical pixels over which the shadow fades to transparent
+    pub blur: f32,
+}
+
+impl Default for InsetShadow {
+    fn default() -> Self {
+        Self {
+            color: Color::rgba(0., 0., 0., 0.5),
+            blur: 8.,
+        }
+    }
+}
+
+impl InsetShadow {
+    pub fn new(color: Color, blur: f32) -> Self {
+        Self { color, blur }
+    }
+}
+
+impl UiDecoration for OutlineShadow {
+    const LAYER: DecorationLayer = DecorationLayer::Below;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let shape = context.node_shape();
+        let (node, outer) = (shape.rect, context.outer_rect());
+        let shape = shape.outset([
+            node.min.x - outer.min.x,
+            outer.max.x - node.max.x,
+            node.min.y - outer.min.y,
+            outer.max.y - node.max.y,
+        ]);
+        push_shadow_rings(shape, self.blur.max(0.), self.color, quads);
+    }
+}
+
+impl UiDecoration for InsetShadow {
+    const LAYER: DecorationLayer = DecorationLayer::Below;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let shape = context.node_shape();
+        let (node, inner) = (shape.rect, context.inner_rect());
+        let shape = shape.inset([
+            inner.min.x - node.min.x,
+            node.max.x - inner.max.x,
+            inner.min.y - node.min.y,
+            node.max.y - inner.max.y,
+        ]);
+        push_shadow_rings(shape, -self.blur.max(0.), self.color, quads);
+    }
+}
+
+/// Approximates a soft falloff with a series of concentric rings, each made of four edge rects, or of strips
+/// following the corners if `shape` is rounded.
+///
+/// The first ring lies against `shape` and the rings move outwards if `blur` is positive or inwards if it is negative,
+/// with their corner radii growing or shrinking to match. The rings fade from `color` to transparent.
+pub(crate) fn push_shadow_rings(
+    shape: RoundedRect,
+    blur: f32,
+    color: Color,
+    quads: &mut Vec<DecorationQuad>,
+) {
+    if !blur.is_finite() || blur == 0. || color.a() == 0. {
+        return;
+    }
+    let steps = (blur.abs().ceil() as usize).clamp(1, MAX_SHADOW_STEPS);
+    let step = blur / steps as f32;
+    for k in 0..steps {
+        let t = (k as f32 + 0.5) / steps as f32;
+        let color = color.with_a(color.a() * (1. - t) * (1. - t));
+        let near = k as f32 * step;
+        let far = near + step;
+        let (outer, inner) = if 0. < blur {
+            (shape.outset([far; 4]), shape.outset([near; 4]))
+        } else {
+            (shape.inset([-near; 4]), shape.inset([-far; 4]))
+        };
+        if shape.is_rounded() {
+            quads.extend(
+                outer
+                    .ring_strips(&inner)
+                    .into_iter()
+                    .map(|rect| DecorationQuad { rect, color }),
+            );
+        } else {
+            let (outer, inner) = (outer.rect, inner.rect);
+            quads.extend(
+                edge_rects(outer.min, outer.max, inner.min, inner.max)
+                    .into_iter()
+                    .map(|rect| DecorationQuad { rect, color }),
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::border_edges_at;
+use crate::geometry::edge_rects;
+use crate::window_frame::ui_cursor_position;
+use crate::world_rect;
+use crate::BorderHitSlop;
+use crate::CalculatedBorder;
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// Which way a [`Splitter`] divides its panes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum SplitAxis {
+    /// Side by side panes, divided by the first pane's right border
+    #[default]
+    Horizontal,
+    /// Stacked panes, divided by the first pane's bottom border
+    Vertical,
+}
+
+/// An in-progress drag of a [`Splitter`]'s border.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct SplitterDrag {
+    start_cursor: f32,
+    start_size: f32,
+    /// The size the pane will have when the drag ends
+    size: f32,
+}
+
+/// Makes the border between two panes draggable, resizing the panes.
+///
+/// Add this to the first of two panes, the one on the left or top, with a border on its right or bottom edge. Dragging
+/// that border, extended by the pane's [`BorderHitSlop`] if it has one, sets the pane's width or height in its
+/// `Style::size`. Let the second pane grow to fill the remaining space.
+///
+/// With a `preview` color the pane keeps its size during the drag and an outline of its new bounds is drawn instead,
+/// then the resize is committed when the mouse button is released.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Splitter {
+    pub axis: SplitAxis,
+    /// Smallest width or height of the pane in logical pixels
+    pub min_size: f32,
+    /// Largest width or height of the pane in logical pixels
+    pub max_size: f32,
+    /// Color of the outline previewing the new size during a drag, `None` to resize the pane live
+    pub preview: Option<Color>,
+    /// Thickness of the preview outline in logical pixels
+    pub preview_thickness: f32,
+    #[reflect(ignore)]
+    drag: Option<SplitterDrag>,
+}
+
+impl Default for Splitter {
+    fn default() -> Self {
+        Self {
+            axis: SplitAxis::Horizontal,
+            min_size: 50.,
+            max_size: f32::INFINITY,
+            preview: None,
+            preview_thickness: 2.,
+            drag: None,
+        }
+    }
+}
+
+impl Splitter {
+    pub fn new(axis: SplitAxis) -> Self {
+        Self {
+            axis,
+            ..Default::default()
+        }
+    }
+
+    /// Resizes the pane only when the drag ends, showing an outline of its new bounds in `color` until then.
+    pub fn with_preview(mut self, color: Color) -> Self {
+        self.preview = Some(color);
+        self
+    }
+
+    /// Whether the border is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// The width or height the pane will have when the current drag ends.
+    pub fn pending_size(&self) -> Option<f32> {
+        self.drag.map(|drag| drag.size)
+    }
+
+    /// The index of the dragged edge in left, right, top, bottom order.
+    fn edge(&self) -> usize {
+        match self.axis {
+            SplitAxis::Horizontal => 1,
+            SplitAxis::Vertical => 3,
+        }
+    }
+}
+
+impl UiDecoration for Splitter {
+    const LAYER: DecorationLayer = DecorationLayer::Above;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let (Some(color), Some(size)) = (self.preview, self.pending_size()) else {
+            return;
+        };
+        let node_rect = context.node_rect();
+        let mut preview = node_rect;
+        match self.axis {
+            SplitAxis::Horizontal => preview.max.x = preview.min.x + size,
+            SplitAxis::Vertical => preview.max.y = preview.min.y + size,
+        }
+        let thickness = self.preview_thickness.max(0.);
+        let inner = preview.inset(-thickness);
+        if inner.is_empty() {
+            return;
+        }
+        quads.extend(
+            edge_rects(preview.min, preview.max, inner.min, inner.max)
+                .map(|rect| DecorationQuad { rect, color }),
+        );
+    }
+}
+
+/// Starts, updates and ends drags of splitter borders.
+#[allow(clippy::type_complexity)]
+pub(crate) fn drag_splitters(
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut splitter_query: Query<(
+        &mut Splitter,
+        &mut Style,
+        &Node,
+        &GlobalTransform,
+        Option<&CalculatedBorder>,
+        Option<&BorderHitSlop>,
+    )>,
+) {
+    let Some(mouse_buttons) = mouse_buttons else {
+        return;
+    };
+    let Some(cursor) = window_query.get_single().ok().and_then(ui_cursor_position) else {
+        return;
+    };
+
+    for (mut splitter, mut style, node, transform, border, hit_slop) in splitter_query.iter_mut() {
+        let edge = splitter.edge();
+        let axis_cursor = match splitter.axis {
+            SplitAxis::Horizontal => cursor.x,
+            SplitAxis::Vertical => cursor.y,
+        };
+        if mouse_buttons.just_pressed(MouseButton::Left) {
+            let rect = world_rect(node, transform);
+            let thickness = border.map(|border| border.resolved).unwrap_or_default();
+            let slop = hit_slop.map_or(0., |slop| slop.0);
+            if border_edges_at(rect, thickness, cursor, slop)[edge] {
+                let size = match splitter.axis {
+                    SplitAxis::Horizontal => rect.width(),
+                    SplitAxis::Vertical => rect.height(),
+                };
+                splitter.drag = Some(SplitterDrag {
+                    start_cursor: axis_cursor,
+                    start_size: size,
+                    size,
+                });
+            }
+        }
+
+        let Some(mut drag) = splitter.drag else {
+            continue;
+        };
+        drag.size = (drag.start_size + axis_cursor - drag.start_cursor)
+            .min(splitter.max_size)
+            .max(splitter.min_size.max(0.));
+        let released = !mouse_buttons.pressed(MouseButton::Left);
+        if splitter.preview.is_none() || released {
+            let size = Val::Px(drag.size);
+            match splitter.axis {
+                SplitAxis::Horizontal if style.size.width != size => style.size.width = size,
+                SplitAxis::Vertical if style.size.height != size => style.size.height = size,
+                _ => {}
+            }
+        }
+        splitter.drag = (!released).then_some(drag);
+    }
+}
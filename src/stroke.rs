@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::UiStack;
+
+use crate::animation::shaken_transform;
+use crate::extracted_quad;
+use crate::BorderShake;
+
+/// Strokes a path of straight segments through points in a UI node's local space, like an SVG polyline or polygon.
+///
+/// Use it for outlines that aren't rectangles, such as hexagonal buttons or minimap frames. The points are relative
+/// to the center of the node with y pointing down. The stroke is centered on the path, and the joins between
+/// segments are filled by extending each segment by half the thickness.
+///
+/// Diagonal segments are drawn as rotated quads. Bevy can't clip rotated quads, so where one crosses the edge of
+/// its clipping rect it is drawn in pieces about as long as the stroke is thick, and pieces that cross the edge
+/// are left out.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct StrokePath {
+    pub points: Vec<Vec2>,
+    /// Joins the last point back to the first
+    pub closed: bool,
+    /// Thickness of the stroke in logical pixels
+    pub thickness: f32,
+    pub color: Color,
+}
+
+impl Default for StrokePath {
+    fn default() -> Self {
+        Self {
+            points: Vec::new(),
+            closed: true,
+            thickness: 1.,
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// A straight piece of a stroke: a rect of the given size centered on `center` and rotated clockwise by `angle`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StrokeQuad {
+    pub center: Vec2,
+    /// Length along the segment and thickness across it
+    pub size: Vec2,
+    /// Clockwise rotation in radians
+    pub angle: f32,
+}
+
+impl StrokeQuad {
+    /// The rect covered by an unrotated quad, `None` if it is rotated other than by a half turn.
+    pub fn axis_aligned_rect(&self) -> Option<Rect> {
+        let (sin, cos) = self.angle.sin_cos();
+        if sin.abs() < 1e-4 {
+            Some(Rect::from_center_size(self.center, self.size))
+        } else if cos.abs() < 1e-4 {
+            Some(Rect::from_center_size(
+                self.center,
+                Vec2::new(self.size.y, self.size.x),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// The bounds of the quad, in the same space as its center.
+    pub fn bounds(&self) -> Rect {
+        let (sin, cos) = self.angle.sin_cos();
+        let half_extent = 0.5
+            * Vec2::new(
+                self.size.x * cos.abs() + self.size.y * sin.abs(),
+                self.size.x * sin.abs() + self.size.y * cos.abs(),
+            );
+        Rect::from_center_half_size(self.center, half_extent)
+    }
+}
+
+impl StrokePath {
+    /// An open path through the points.
+    pub fn polyline(points: Vec<Vec2>, thickness: f32, color: Color) -> Self {
+        Self {
+            points,
+            closed: false,
+            thickness,
+            color,
+        }
+    }
+
+    /// A closed path through the points.
+    pub fn polygon(points: Vec<Vec2>, thickness: f32, color: Color) -> Self {
+        Self {
+            points,
+            closed: true,
+            thickness,
+            color,
+        }
+    }
+
+    /// The start and end points of each segment of the path.
+    pub fn segments(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let closing = (self.closed && 2 < self.points.len())
+            .then(|| (self.points[self.points.len() - 1], self.points[0]));
+        self.points
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .chain(closing)
+    }
+
+    /// The quads covering the stroke.
+    pub fn quads(&self) -> Vec<StrokeQuad> {
+        let thickness = self.thickness.max(0.);
+        if thickness == 0. {
+            return Vec::new();
+        }
+        let segment_count = self.segments().count();
+        self.segments()
+            .enumerate()
+            .filter_map(|(i, (start, end))| {
+                let delta = end - start;
+                let length = delta.length();
+                if length <= 0. {
+                    return None;
+                }
+                // Extend into the joins, but not past the ends of an open path
+                let extend_start = if self.closed || 0 < i {
+                    0.5 * thickness
+                } else {
+                    0.
+                };
+                let extend_end = if self.closed || i + 1 < segment_count {
+                    0.5 * thickness
+                } else {
+                    0.
+                };
+                let direction = delta / length;
+                Some(StrokeQuad {
+                    center: 0.5 * (start + end) + 0.5 * (extend_end - extend_start) * direction,
+                    size: Vec2::new(length + extend_start + extend_end, thickness),
+                    angle: direction.y.atan2(direction.x),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Splits a quad into pieces along its length, each no longer than `max_length`.
+fn split_quad(quad: StrokeQuad, max_length: f32) -> impl Iterator<Item = StrokeQuad> {
+    let count = (quad.size.x / max_length).ceil().max(1.) as usize;
+    let length = quad.size.x / count as f32;
+    let direction = Vec2::from_angle(quad.angle);
+    let start = quad.center - 0.5 * quad.size.x * direction;
+    (0..count).map(move |i| StrokeQuad {
+        center: start + (i as f32 + 0.5) * length * direction,
+        size: Vec2::new(length, quad.size.y),
+        angle: quad.angle,
+    })
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn extract_stroke_paths(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    ui_stack: Extract<Res<UiStack>>,
+    uinode_query: Extract<
+        Query<
+            (
+                &Node,
+                &GlobalTransform,
+                &StrokePath,
+                Option<&BorderShake>,
+                &ComputedVisibility,
+                Option<&CalculatedClip>,
+            ),
+            Without<CalculatedSize>,
+        >,
+    >,
+) {
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        let Ok((node, global_transform, stroke, shake, visibility, clip)) =
+            uinode_query.get(*entity)
+        else {
+            continue;
+        };
+        // Skip invisible strokes
+        if !visibility.is_visible() || stroke.color.a() == 0. || node.size().x <= 0. {
+            continue;
+        }
+
+        extract_stroke_quads(
+            &mut extracted_uinodes,
+            stack_index,
+            shaken_transform(global_transform, shake),
+            stroke.quads(),
+            stroke.color,
+            clip.map(|clip| clip.clip),
+        );
+    }
+}
+
+/// Extracts the quads of a stroke in a node's local space, splitting rotated quads that cross the clip.
+pub(crate) fn extract_stroke_quads(
+    extracted_uinodes: &mut ExtractedUiNodes,
+    stack_index: usize,
+    transform: Mat4,
+    quads: Vec<StrokeQuad>,
+    color: Color,
+    clip: Option<Rect>,
+) {
+    let translation = transform.w_axis.truncate().truncate();
+    let window_bounds = |quad: &StrokeQuad| {
+        let bounds = quad.bounds();
+        Rect {
+            min: bounds.min + translation,
+            max: bounds.max + translation,
+        }
+    };
+    for quad in quads {
+        if let Some(rect) = quad.axis_aligned_rect() {
+            extracted_uinodes.uinodes.push(extracted_quad(
+                stack_index,
+                transform,
+                rect,
+                color,
+                None,
+                clip,
+            ));
+            continue;
+        }
+        let pieces: Vec<StrokeQuad> = match clip {
+            Some(clip) => {
+                let inside = |bounds: Rect| bounds.intersect(clip) == bounds;
+                let bounds = window_bounds(&quad);
+                if bounds.intersect(clip).is_empty() {
+                    continue;
+                }
+                if inside(bounds) {
+                    vec![quad]
+                } else {
+                    split_quad(quad, quad.size.y.max(1.))
+                        .filter(|piece| inside(window_bounds(piece)))
+                        .collect()
+                }
+            }
+            None => vec![quad],
+        };
+        for piece in pieces {
+            extracted_uinodes.uinodes.push(extracted_quad(
+                stack_index,
+                transform
+                    * Mat4::from_translation(piece.center.extend(0.))
+                    * Mat4::from_rotation_z(piece.angle),
+                Rect::from_center_size(Vec2::ZERO, piece.size),
+                color,
+                None,
+                None,
+            ));
+        }
+    }
+}
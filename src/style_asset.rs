@@ -0,0 +1,242 @@
+use bevy::asset::AssetLoader;
+use bevy::asset::BoxedFuture;
+use bevy::asset::LoadContext;
+use bevy::asset::LoadedAsset;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::utils::HashSet;
+use serde::Deserialize;
+
+use crate::Border;
+use crate::BorderColor;
+use crate::BorderRadius;
+use crate::InsetShadow;
+use crate::Outline;
+use crate::OutlineColor;
+use crate::OutlineShadow;
+
+/// Edge thicknesses in a [`BorderStyleAsset`].
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize)]
+pub enum Thickness {
+    /// The same thickness for every edge
+    All(Val),
+    /// A thickness for each edge, missing edges have no thickness
+    Edges {
+        #[serde(default = "zero")]
+        left: Val,
+        #[serde(default = "zero")]
+        right: Val,
+        #[serde(default = "zero")]
+        top: Val,
+        #[serde(default = "zero")]
+        bottom: Val,
+    },
+}
+
+fn zero() -> Val {
+    Val::Px(0.)
+}
+
+impl From<Thickness> for UiRect {
+    fn from(thickness: Thickness) -> Self {
+        match thickness {
+            Thickness::All(value) => UiRect::all(value),
+            Thickness::Edges {
+                left,
+                right,
+                top,
+                bottom,
+            } => UiRect {
+                left,
+                right,
+                top,
+                bottom,
+            },
+        }
+    }
+}
+
+/// Corner radii in a [`BorderStyleAsset`].
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize)]
+pub enum Radius {
+    /// The same radius for every corner
+    All(Val),
+    /// A radius for each corner, missing corners are square
+    Corners {
+        #[serde(default = "zero")]
+        top_left: Val,
+        #[serde(default = "zero")]
+        top_right: Val,
+        #[serde(default = "zero")]
+        bottom_right: Val,
+        #[serde(default = "zero")]
+        bottom_left: Val,
+    },
+}
+
+impl From<Radius> for BorderRadius {
+    fn from(radius: Radius) -> Self {
+        match radius {
+            Radius::All(value) => BorderRadius::all(value),
+            Radius::Corners {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } => BorderRadius::new(top_left, top_right, bottom_right, bottom_left),
+        }
+    }
+}
+
+/// A soft shadow in a [`BorderStyleAsset`].
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize)]
+pub struct ShadowStyle {
+    pub color: Color,
+    pub blur: f32,
+}
+
+/// A set of border properties loaded from a `.border.ron` file.
+///
+/// Each property that is present replaces the corresponding component on every entity with a [`StyleHandle`]
+/// pointing at this asset. Missing properties are left untouched.
+///
+/// ```ron
+/// (
+///     border: Some(All(Px(4.0))),
+///     border_color: Some(Rgba(red: 1.0, green: 0.0, blue: 0.0, alpha: 1.0)),
+///     outline: Some(Edges(bottom: Px(2.0))),
+///     outline_color: Some(Rgba(red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0)),
+///     radius: Some(All(Px(8.0))),
+/// )
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, TypeUuid)]
+#[uuid = "6a1e4c1e-2f0b-4a8e-9a3c-8f4f1b7f6d21"]
+#[serde(default)]
+pub struct BorderStyleAsset {
+    pub border: Option<Thickness>,
+    pub border_color: Option<Color>,
+    pub outline: Option<Thickness>,
+    pub outline_color: Option<Color>,
+    pub outline_shadow: Option<ShadowStyle>,
+    pub inset_shadow: Option<ShadowStyle>,
+    pub radius: Option<Radius>,
+}
+
+impl BorderStyleAsset {
+    /// Merges the properties present in `other` into `self`, overriding any already set.
+    pub fn merge(&mut self, other: &BorderStyleAsset) {
+        self.border = other.border.or(self.border);
+        self.border_color = other.border_color.or(self.border_color);
+        self.outline = other.outline.or(self.outline);
+        self.outline_color = other.outline_color.or(self.outline_color);
+        self.outline_shadow = other.outline_shadow.or(self.outline_shadow);
+        self.inset_shadow = other.inset_shadow.or(self.inset_shadow);
+        self.radius = other.radius.or(self.radius);
+    }
+}
+
+/// Applies the [`BorderStyleAsset`] it points at to its UI node, reapplying it whenever the asset changes.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut)]
+pub struct StyleHandle(pub Handle<BorderStyleAsset>);
+
+impl From<Handle<BorderStyleAsset>> for StyleHandle {
+    fn from(handle: Handle<BorderStyleAsset>) -> Self {
+        Self(handle)
+    }
+}
+
+#[derive(Default)]
+pub struct BorderStyleAssetLoader;
+
+impl AssetLoader for BorderStyleAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let style_asset = ron::de::from_bytes::<BorderStyleAsset>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(style_asset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["border.ron"]
+    }
+}
+
+/// Applies style assets to entities whose [`StyleHandle`] changed or whose asset was loaded or modified.
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_border_style_assets(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<BorderStyleAsset>>,
+    style_assets: Res<Assets<BorderStyleAsset>>,
+    mut style_query: Query<(Entity, Ref<StyleHandle>, &mut Style, Option<&mut Border>)>,
+) {
+    let changed_assets: HashSet<_> = asset_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => Some(handle.id()),
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect();
+
+    for (entity, style_handle, style, border) in style_query.iter_mut() {
+        if !style_handle.is_changed() && !changed_assets.contains(&style_handle.id()) {
+            continue;
+        }
+        let Some(style_asset) = style_assets.get(&style_handle.0) else {
+            continue;
+        };
+
+        apply_style(&mut commands.entity(entity), style, border, style_asset);
+    }
+}
+
+/// Replaces the components of a UI node with each property present in `style_asset`.
+pub(crate) fn apply_style(
+    entity_commands: &mut EntityCommands,
+    mut style: Mut<Style>,
+    border: Option<Mut<Border>>,
+    style_asset: &BorderStyleAsset,
+) {
+    match border {
+        // Keep the combined border component in sync rather than fighting its sync system
+        Some(mut border) => {
+            if let Some(thickness) = style_asset.border {
+                border.width = thickness.into();
+            }
+            if let Some(color) = style_asset.border_color {
+                border.color = color;
+            }
+            if let Some(radius) = style_asset.radius {
+                border.radius = radius.into();
+            }
+        }
+        None => {
+            if let Some(thickness) = style_asset.border {
+                style.border = thickness.into();
+            }
+            if let Some(color) = style_asset.border_color {
+                entity_commands.insert(BorderColor(color));
+            }
+            if let Some(radius) = style_asset.radius {
+                entity_commands.insert(BorderRadius::from(radius));
+            }
+        }
+    }
+    if let Some(thickness) = style_asset.outline {
+        entity_commands.insert(Outline::thickness(thickness.into()));
+    }
+    if let Some(color) = style_asset.outline_color {
+        entity_commands.insert(OutlineColor(color));
+    }
+    if let Some(ShadowStyle { color, blur }) = style_asset.outline_shadow {
+        entity_commands.insert(OutlineShadow::new(color, blur));
+    }
+    if let Some(ShadowStyle { color, blur }) = style_asset.inset_shadow {
+        entity_commands.insert(InsetShadow::new(color, blur));
+    }
+}
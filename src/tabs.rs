@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use crate::world_rect;
+use crate::BorderSpans;
+use crate::Easing;
+use crate::EdgeSpan;
+
+/// Underlines the active tab of a tab bar with the part of the bar's bottom border beneath it, sliding between tabs
+/// when the active tab changes.
+///
+/// Add this to the tab bar, a UI node with a bottom border whose descendants are the tabs. The plugin keeps the bar's
+/// [`BorderSpans`] up to date so that only the part of the bottom edge under the active tab is drawn. Whenever `active`
+/// changes the span is interpolated to the new tab over `duration` seconds.
+#[derive(Component, Clone, Debug)]
+pub struct TabIndicator {
+    pub tabs: Vec<Entity>,
+    /// Index of the active tab in `tabs`, `None` to hide the indicator
+    pub active: Option<usize>,
+    /// Length of the slide in seconds
+    pub duration: f32,
+    pub easing: Easing,
+    /// Where the current slide started
+    from: Option<EdgeSpan>,
+    /// The span of the bottom edge currently drawn
+    current: Option<EdgeSpan>,
+    /// The target of the current slide
+    sliding_to: Option<Entity>,
+    /// Seconds since the current slide started
+    elapsed: f32,
+}
+
+impl Default for TabIndicator {
+    fn default() -> Self {
+        Self {
+            tabs: Vec::new(),
+            active: None,
+            duration: 0.2,
+            easing: Easing::QuadraticOut,
+            from: None,
+            current: None,
+            sliding_to: None,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl TabIndicator {
+    /// An indicator under the first of the tabs.
+    pub fn new(tabs: Vec<Entity>) -> Self {
+        Self {
+            active: (!tabs.is_empty()).then_some(0),
+            tabs,
+            ..Default::default()
+        }
+    }
+
+    /// Makes the tab at `index` active.
+    pub fn select(&mut self, index: usize) {
+        self.active = Some(index);
+    }
+
+    /// The active tab's entity.
+    pub fn active_tab(&self) -> Option<Entity> {
+        self.active.and_then(|index| self.tabs.get(index).copied())
+    }
+
+    /// The part of the bar's bottom edge currently drawn, `None` while no tab is active.
+    pub fn current_span(&self) -> Option<EdgeSpan> {
+        self.current
+    }
+}
+
+fn lerp_span(start: EdgeSpan, end: EdgeSpan, t: f32) -> EdgeSpan {
+    EdgeSpan::new(
+        start.start + (end.start - start.start) * t,
+        start.end + (end.end - start.end) * t,
+    )
+}
+
+pub(crate) fn update_tab_indicators(
+    mut commands: Commands,
+    time: Res<Time>,
+    node_query: Query<(&Node, &GlobalTransform)>,
+    mut indicator_query: Query<(
+        Entity,
+        &Node,
+        &GlobalTransform,
+        &mut TabIndicator,
+        Option<&mut BorderSpans>,
+    )>,
+) {
+    for (entity, node, transform, mut indicator, spans) in indicator_query.iter_mut() {
+        let bar = world_rect(node, transform);
+        let target_span = indicator
+            .active_tab()
+            .and_then(|tab| node_query.get(tab).ok())
+            .filter(|_| 0. < bar.width())
+            .map(|(tab_node, tab_transform)| {
+                let tab = world_rect(tab_node, tab_transform);
+                EdgeSpan::new(
+                    (tab.min.x - bar.min.x) / bar.width(),
+                    (tab.max.x - bar.min.x) / bar.width(),
+                )
+            });
+
+        let current = match target_span {
+            Some(target_span) => {
+                let active_tab = indicator.active_tab();
+                if indicator.sliding_to != active_tab {
+                    indicator.sliding_to = active_tab;
+                    indicator.from = indicator.current;
+                    indicator.elapsed = 0.;
+                }
+                indicator.elapsed += time.delta_seconds();
+                let progress = if indicator.duration <= 0. {
+                    1.
+                } else {
+                    (indicator.elapsed / indicator.duration).min(1.)
+                };
+                // The target is tracked every frame so the indicator follows tabs that move during the slide
+                Some(match indicator.from {
+                    Some(from) if progress < 1. => {
+                        lerp_span(from, target_span, indicator.easing.ease(progress))
+                    }
+                    _ => target_span,
+                })
+            }
+            None => {
+                indicator.sliding_to = None;
+                None
+            }
+        };
+        indicator.current = current;
+
+        let bottom = current.unwrap_or(EdgeSpan::EMPTY);
+        match spans {
+            Some(mut spans) => {
+                if spans.bottom != bottom {
+                    spans.bottom = bottom;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(BorderSpans::bottom(bottom));
+            }
+        }
+    }
+}
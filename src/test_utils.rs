@@ -0,0 +1,218 @@
+//! Helpers for testing UI border and outline geometry without a window or GPU.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_ui_borders::*;
+//! # use bevy_ui_borders::test_utils::*;
+//! let mut app = headless_app(Vec2::new(800., 600.));
+//! let node = app
+//!     .world
+//!     .spawn((
+//!         NodeBundle {
+//!             style: Style {
+//!                 size: Size::new(Val::Px(100.), Val::Px(50.)),
+//!                 border: UiRect::all(Val::Px(5.)),
+//!                 ..Default::default()
+//!             },
+//!             ..Default::default()
+//!         },
+//!         BorderColor(Color::RED),
+//!     ))
+//!     .id();
+//! update(&mut app);
+//! assert_rect_near(
+//!     calculated_border(&app, node).inner,
+//!     Rect::new(-45., -20., 45., 20.),
+//! );
+//! ```
+
+#[cfg(all(feature = "render_tests", not(target_arch = "wasm32")))]
+mod golden;
+
+#[cfg(all(feature = "render_tests", not(target_arch = "wasm32")))]
+pub use golden::*;
+
+use bevy::app::SubApp;
+use bevy::prelude::*;
+use bevy::reflect::GetPath;
+use bevy::render::ExtractSchedule;
+use bevy::render::MainWorld;
+use bevy::render::RenderApp;
+use bevy::transform::TransformSystem;
+use bevy::ui::flex_node_system;
+use bevy::ui::ExtractedUiNode;
+use bevy::ui::ExtractedUiNodes;
+use bevy::ui::FlexSurface;
+use bevy::ui::UiStack;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+use bevy::window::WindowResolution;
+use bevy::window::WindowScaleFactorChanged;
+
+use crate::BordersPlugin;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+
+/// The largest difference between two coordinates for them to be considered equal.
+pub const EPSILON: f32 = 0.001;
+
+/// Creates an app that runs the UI layout and the plugin's geometry systems, with a primary window of the
+/// given logical size and no renderer.
+pub fn headless_app(window_size: Vec2) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(TransformPlugin)
+        .add_plugin(HierarchyPlugin);
+    app.add_plugin(AssetPlugin::default())
+        .add_asset::<Image>()
+        .add_asset::<TextureAtlas>();
+    app.add_event::<WindowScaleFactorChanged>()
+        .init_resource::<FlexSurface>()
+        .init_resource::<UiScale>()
+        .init_resource::<UiStack>()
+        .configure_set(UiSystem::Flex.in_base_set(CoreSet::PostUpdate))
+        .add_system(
+            flex_node_system
+                .in_set(UiSystem::Flex)
+                .before(TransformSystem::TransformPropagate),
+        )
+        .add_plugin(BordersPlugin::headless());
+    app.world.spawn((
+        Window {
+            resolution: WindowResolution::new(window_size.x, window_size.y),
+            ..Default::default()
+        },
+        PrimaryWindow,
+    ));
+    app
+}
+
+/// Runs one frame, then a second so that components inserted by the plugin during the first are calculated.
+pub fn update(app: &mut App) {
+    app.update();
+    app.update();
+}
+
+/// The calculated size of a UI node.
+#[track_caller]
+pub fn node_size(app: &App, entity: Entity) -> Vec2 {
+    app.world
+        .get::<Node>(entity)
+        .expect("entity is not a UI node")
+        .size()
+}
+
+/// The border geometry of a UI node.
+#[track_caller]
+pub fn calculated_border(app: &App, entity: Entity) -> CalculatedBorder {
+    *app.world
+        .get::<CalculatedBorder>(entity)
+        .expect("entity has no CalculatedBorder")
+}
+
+/// The outline geometry of a UI node.
+#[track_caller]
+pub fn calculated_outline(app: &App, entity: Entity) -> CalculatedOutline {
+    *app.world
+        .get::<CalculatedOutline>(entity)
+        .expect("entity has no CalculatedOutline")
+}
+
+/// Extracts the UI for rendering like the [`BordersPlugin`] does, without a renderer, and returns the quads extracted
+/// by the plugin in draw order.
+///
+/// Bevy's own background, image and text quads aren't included, and instanced borders and outlines are drawn by
+/// their own pipeline so they aren't either. Nodes are stacked in the order they were spawned, ignoring [`ZIndex`],
+/// and are visible unless they or an ancestor are [`Visibility::Hidden`]. The debug overlays are extracted if the
+/// app has the `DebugOutlinePlugin`.
+pub fn extracted_quads(app: &mut App) -> Vec<ExtractedUiNode> {
+    update_ui_stack(&mut app.world);
+
+    let mut render_app = App::empty();
+    render_app
+        .add_schedule(ExtractSchedule, Schedule::new())
+        .init_resource::<ExtractedUiNodes>();
+    let mut extraction = App::empty();
+    extraction.insert_sub_app(RenderApp, SubApp::new(render_app, |_, _| {}));
+    crate::add_extraction(&mut extraction);
+    #[cfg(feature = "debug")]
+    if app.world.contains_resource::<crate::DebugOutlineSettings>() {
+        crate::debug::add_debug_extraction(&mut extraction);
+    }
+
+    let render_world = &mut extraction.sub_app_mut(RenderApp).world;
+    let mut main_world = MainWorld::default();
+    std::mem::swap(&mut *main_world, &mut app.world);
+    render_world.insert_resource(main_world);
+    render_world.run_schedule(ExtractSchedule);
+    let mut main_world = render_world.remove_resource::<MainWorld>().unwrap();
+    std::mem::swap(&mut *main_world, &mut app.world);
+    render_world
+        .remove_resource::<ExtractedUiNodes>()
+        .unwrap()
+        .uinodes
+}
+
+/// Stacks the UI nodes depth first in spawn order and updates their [`ComputedVisibility`], standing in for Bevy's
+/// UI stack and visibility systems.
+fn update_ui_stack(world: &mut World) {
+    let mut roots: Vec<Entity> = world
+        .query_filtered::<Entity, (With<Node>, Without<Parent>)>()
+        .iter(world)
+        .collect();
+    roots.sort();
+    let mut uinodes = Vec::new();
+    let mut stack: Vec<(Entity, bool)> = roots.into_iter().rev().map(|root| (root, true)).collect();
+    while let Some((entity, parent_visible)) = stack.pop() {
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        if !entity_mut.contains::<Node>() {
+            continue;
+        }
+        let visible = match entity_mut.get::<Visibility>() {
+            Some(Visibility::Hidden) => false,
+            Some(Visibility::Visible) => true,
+            _ => parent_visible,
+        };
+        if let Some(mut computed) = entity_mut.get_mut::<ComputedVisibility>() {
+            // Bevy only sets the visibility of entities seen by a camera
+            *computed.path_mut::<u8>("flags.bits").unwrap() = if visible { 0b11 } else { 0 };
+        }
+        uinodes.push(entity);
+        if let Some(children) = entity_mut.get::<Children>() {
+            stack.extend(children.iter().rev().map(|child| (*child, visible)));
+        }
+    }
+    world.resource_mut::<UiStack>().uinodes = uinodes;
+}
+
+/// Asserts that two rects are equal to within [`EPSILON`].
+#[track_caller]
+pub fn assert_rect_near(actual: Rect, expected: Rect) {
+    assert!(
+        actual.min.abs_diff_eq(expected.min, EPSILON)
+            && actual.max.abs_diff_eq(expected.max, EPSILON),
+        "expected {expected:?}, found {actual:?}"
+    );
+}
+
+/// Asserts that the left, right, top and bottom edges of a border or outline match the expected rects
+/// to within [`EPSILON`], with `None` for edges that shouldn't be drawn.
+#[track_caller]
+pub fn assert_edges_near(actual: [Option<Rect>; 4], expected: [Option<Rect>; 4]) {
+    for (i, (actual_edge, expected_edge)) in actual.into_iter().zip(expected).enumerate() {
+        let edge = ["left", "right", "top", "bottom"][i];
+        match (actual_edge, expected_edge) {
+            (Some(actual_edge), Some(expected_edge)) => {
+                assert!(
+                    actual_edge.min.abs_diff_eq(expected_edge.min, EPSILON)
+                        && actual_edge.max.abs_diff_eq(expected_edge.max, EPSILON),
+                    "{edge} edge: expected {expected_edge:?}, found {actual_edge:?}"
+                );
+            }
+            (None, None) => {}
+            _ => panic!("{edge} edge: expected {expected_edge:?}, found {actual_edge:?}"),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Golden image tests for border and outline geometry.
+//!
+//! Scenes are rasterized on the CPU from each node's position, background and calculated border and outline
+//! edges, then compared against PNG files checked into the repository. Set the `BLESS_GOLDEN` environment
+//! variable to write the current output as the new golden images.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::BorderColor;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::OutlineColor;
+
+/// The largest difference allowed in any channel of a pixel before it is counted as a mismatch.
+pub const CHANNEL_TOLERANCE: u8 = 2;
+
+/// An RGBA image with 8 bits per channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl TestImage {
+    pub fn new(width: u32, height: u32, color: Color) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![to_rgba8(color); (width * height) as usize],
+        }
+    }
+
+    /// Blends `color` over every pixel whose center lies inside `rect`.
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let [r, g, b, a] = color.as_rgba_f32();
+        let min = rect.min.round().max(Vec2::ZERO).as_uvec2();
+        let max = rect
+            .max
+            .round()
+            .min(Vec2::new(self.width as f32, self.height as f32))
+            .as_uvec2();
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let pixel = &mut self.pixels[(y * self.width + x) as usize];
+                for (channel, source) in pixel.iter_mut().zip([r, g, b, 1.]) {
+                    let blended = source * a + *channel as f32 / 255. * (1. - a);
+                    *channel = (blended * 255.).round() as u8;
+                }
+            }
+        }
+    }
+
+    pub fn load_png(path: &Path) -> Result<Self, png::DecodingError> {
+        let mut decoder = png::Decoder::new(File::open(path)?);
+        decoder.set_transformations(png::Transformations::EXPAND);
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        let channels = info.color_type.samples();
+        let pixels = buffer[..info.buffer_size()]
+            .chunks_exact(channels)
+            .map(|pixel| match *pixel {
+                [r, g, b, a] => [r, g, b, a],
+                [r, g, b] => [r, g, b, 255],
+                [l, a] => [l, l, l, a],
+                [l] => [l, l, l, 255],
+                _ => unreachable!(),
+            })
+            .collect();
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    pub fn save_png(&self, path: &Path) -> Result<(), png::EncodingError> {
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(File::create(path)?), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())
+    }
+
+    /// The number of pixels that differ from `other` by more than [`CHANNEL_TOLERANCE`] in any channel.
+    pub fn count_mismatches(&self, other: &TestImage) -> usize {
+        self.pixels
+            .iter()
+            .zip(&other.pixels)
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > CHANNEL_TOLERANCE)
+            })
+            .count()
+    }
+}
+
+fn to_rgba8(color: Color) -> [u8; 4] {
+    color
+        .as_rgba_f32()
+        .map(|channel| (channel * 255.).round() as u8)
+}
+
+/// Rasterizes every UI node in the app's world into an image the size of the primary window.
+///
+/// Each node draws its background, then its border, then its outline, parents before their children.
+/// Rotation and scale are ignored.
+pub fn render_scene(app: &mut App, window_size: UVec2) -> TestImage {
+    let mut image = TestImage::new(window_size.x, window_size.y, Color::BLACK);
+    let mut roots = app
+        .world
+        .query_filtered::<Entity, (With<Node>, Without<Parent>)>();
+    let mut stack: Vec<Entity> = roots.iter(&app.world).collect();
+    stack.reverse();
+    while let Some(entity) = stack.pop() {
+        let entity_ref = app.world.entity(entity);
+        let (Some(node), Some(transform)) = (
+            entity_ref.get::<Node>(),
+            entity_ref.get::<GlobalTransform>(),
+        ) else {
+            continue;
+        };
+        let center = transform.translation().truncate();
+        let node_rect = Rect::from_center_size(center, node.size());
+        if let Some(background) = entity_ref.get::<BackgroundColor>() {
+            image.fill_rect(node_rect, background.0);
+        }
+        if let (Some(border), Some(color)) = (
+            entity_ref.get::<CalculatedBorder>(),
+            entity_ref.get::<BorderColor>(),
+        ) {
+            for edge in border.edges.iter().flatten() {
+                image.fill_rect(translate(*edge, center), color.0);
+            }
+        }
+        let outline_color = entity_ref
+            .get::<OutlineColor>()
+            .map(|color| color.0)
+            .or(entity_ref.get::<BorderColor>().map(|color| color.0));
+        if let (Some(outline), Some(color)) = (entity_ref.get::<CalculatedOutline>(), outline_color)
+        {
+            for edge in outline.edges.iter().flatten() {
+                image.fill_rect(translate(*edge, center), color);
+            }
+        }
+        if let Some(children) = entity_ref.get::<Children>() {
+            stack.extend(children.iter().rev());
+        }
+    }
+    image
+}
+
+fn translate(rect: Rect, offset: Vec2) -> Rect {
+    Rect {
+        min: rect.min + offset,
+        max: rect.max + offset,
+    }
+}
+
+/// Compares `image` with the golden image at `path`, writing the actual output next to it on a mismatch.
+///
+/// If the `BLESS_GOLDEN` environment variable is set or the golden image doesn't exist yet, `image` is saved
+/// as the golden image instead.
+#[track_caller]
+pub fn assert_matches_golden(image: &TestImage, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    if std::env::var_os("BLESS_GOLDEN").is_some() || !path.exists() {
+        image
+            .save_png(path)
+            .unwrap_or_else(|error| panic!("failed to write {}: {error}", path.display()));
+        return;
+    }
+    let golden = TestImage::load_png(path)
+        .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()));
+    let mismatches = if (golden.width, golden.height) == (image.width, image.height) {
+        image.count_mismatches(&golden)
+    } else {
+        image.pixels.len()
+    };
+    if mismatches > 0 {
+        let actual_path = actual_path(path);
+        let _ = image.save_png(&actual_path);
+        panic!(
+            "{mismatches} pixels differ from {}, output written to {}",
+            path.display(),
+            actual_path.display()
+        );
+    }
+}
+
+fn actual_path(path: &Path) -> PathBuf {
+    path.with_extension("actual.png")
+}
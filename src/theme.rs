@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+
+use crate::animation::lerp_color;
+use crate::BorderColor;
+use crate::BorderPalette;
+
+/// Whether the UI is using its light or dark theme.
+///
+/// Changing the mode copies the matching palette from [`BorderThemes`] into the [`BorderPalette`], re-coloring
+/// every node with a [`BorderColorRef`](crate::BorderColorRef) or [`OutlineColorRef`](crate::OutlineColorRef),
+/// and then restores the contrast of every [`ContrastBorder`].
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub enum ThemeMode {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl ThemeMode {
+    /// Switches between light and dark.
+    pub fn toggle(&mut self) {
+        *self = match self {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::Light,
+        };
+    }
+}
+
+/// The palettes used for each [`ThemeMode`].
+///
+/// Without this resource switching the mode leaves the [`BorderPalette`] alone and only [`ContrastBorder`]s
+/// are updated.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct BorderThemes {
+    pub light: BorderPalette,
+    pub dark: BorderPalette,
+}
+
+impl BorderThemes {
+    pub fn new(light: BorderPalette, dark: BorderPalette) -> Self {
+        Self { light, dark }
+    }
+
+    pub fn palette(&self, mode: ThemeMode) -> &BorderPalette {
+        match mode {
+            ThemeMode::Light => &self.light,
+            ThemeMode::Dark => &self.dark,
+        }
+    }
+}
+
+/// Keeps a UI node's [`BorderColor`] readable against its [`BackgroundColor`].
+///
+/// When the contrast ratio between the two falls below `min_ratio` the border color is moved towards white or black,
+/// whichever contrasts more with the background, just far enough to reach it. This is checked when the
+/// [`ThemeMode`] switches and whenever the node's colors or this component change.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct ContrastBorder {
+    /// The lowest acceptable contrast ratio, from 1 to 21. WCAG asks for at least 3 for UI component boundaries.
+    pub min_ratio: f32,
+}
+
+impl Default for ContrastBorder {
+    fn default() -> Self {
+        Self { min_ratio: 3. }
+    }
+}
+
+/// Draws a UI node's outline in white or black, whichever contrasts more with its [`BackgroundColor`], so that focus
+/// rings stay visible on any themed widget.
+///
+/// The choice is made each frame when the outline is extracted for rendering. The alpha of the node's
+/// [`OutlineColor`](crate::OutlineColor) is kept, nodes without a `BackgroundColor` keep their outline color.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct ContrastOutline;
+
+/// White or black, whichever has the higher contrast ratio against `background`.
+pub fn light_or_dark(background: Color) -> Color {
+    if contrast_ratio(Color::BLACK, background) < contrast_ratio(Color::WHITE, background) {
+        Color::WHITE
+    } else {
+        Color::BLACK
+    }
+}
+
+/// The relative luminance of a color, from 0 for black to 1 for white, ignoring alpha.
+pub fn relative_luminance(color: Color) -> f32 {
+    let [r, g, b, _] = color.as_linear_rgba_f32();
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// The WCAG contrast ratio between two colors, from 1 for identical luminance to 21 for black on white.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The color closest to `color` with at least `min_ratio` contrast against `background`, found by moving it
+/// towards white or black. The alpha of `color` is kept.
+pub fn contrasting_color(color: Color, background: Color, min_ratio: f32) -> Color {
+    if min_ratio <= contrast_ratio(color, background) {
+        return color;
+    }
+    let target = light_or_dark(background).with_a(color.a());
+    let (mut low, mut high) = (0., 1.);
+    for _ in 0..16 {
+        let t = 0.5 * (low + high);
+        if min_ratio <= contrast_ratio(lerp_color(color, target, t), background) {
+            high = t;
+        } else {
+            low = t;
+        }
+    }
+    lerp_color(color, target, high)
+}
+
+/// Copies the palette for the new [`ThemeMode`] into the [`BorderPalette`].
+pub(crate) fn apply_theme_mode(
+    mode: Res<ThemeMode>,
+    themes: Option<Res<BorderThemes>>,
+    mut palette: ResMut<BorderPalette>,
+) {
+    if !mode.is_changed() {
+        return;
+    }
+    if let Some(themes) = themes {
+        *palette = themes.palette(*mode).clone();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn ensure_border_contrast(
+    mode: Res<ThemeMode>,
+    mut border_query: Query<(Ref<ContrastBorder>, Ref<BackgroundColor>, &mut BorderColor)>,
+) {
+    for (contrast, background, mut border_color) in border_query.iter_mut() {
+        if !mode.is_changed()
+            && !contrast.is_changed()
+            && !background.is_changed()
+            && !border_color.is_changed()
+        {
+            continue;
+        }
+        let color = contrasting_color(border_color.0, background.0, contrast.min_ratio);
+        if border_color.0 != color {
+            border_color.0 = color;
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::Sides;
+use crate::UiDecoration;
+
+/// Evenly spaced tick marks along the inside of chosen edges of a UI node's border, like a ruler in an editor or the
+/// time marks of a timeline.
+///
+/// Ticks are measured along the edges of the area enclosed by the border, with the first tick at its left or top end
+/// and one every `interval` logical pixels after it. Each tick reaches `length` logical pixels in from the border.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct BorderTicks {
+    pub edges: Sides,
+    /// Distance between neighbouring ticks in logical pixels
+    pub interval: f32,
+    /// Length of each tick in logical pixels
+    pub length: f32,
+    /// Width of each tick in logical pixels
+    pub thickness: f32,
+    pub color: Color,
+}
+
+impl Default for BorderTicks {
+    fn default() -> Self {
+        Self {
+            edges: Sides::BOTTOM,
+            interval: 10.,
+            length: 4.,
+            thickness: 1.,
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl BorderTicks {
+    pub fn new(edges: Sides, interval: f32, length: f32, color: Color) -> Self {
+        Self {
+            edges,
+            interval,
+            length,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// The rects of every tick in the node's local space.
+    pub fn ticks(&self, context: &DecorationContext) -> Vec<Rect> {
+        let mut ticks = Vec::new();
+        if self.interval <= 0. || self.length <= 0. || self.thickness <= 0. {
+            return ticks;
+        }
+        let inner = context.inner_rect();
+        // Positions of the ticks along one side of the inner rect
+        let positions = |min: f32, max: f32| {
+            let count = ((max - min - self.thickness) / self.interval).floor();
+            (0..=count.max(-1.) as i32).map(move |i| min + i as f32 * self.interval)
+        };
+        for side in [Sides::LEFT, Sides::RIGHT, Sides::TOP, Sides::BOTTOM] {
+            if !self.edges.contains(side) {
+                continue;
+            }
+            let horizontal = side == Sides::TOP || side == Sides::BOTTOM;
+            let (min, max) = if horizontal {
+                (inner.min.x, inner.max.x)
+            } else {
+                (inner.min.y, inner.max.y)
+            };
+            ticks.extend(positions(min, max).map(|p| match side {
+                Sides::LEFT => Rect::new(
+                    inner.min.x,
+                    p,
+                    inner.min.x + self.length,
+                    p + self.thickness,
+                ),
+                Sides::RIGHT => Rect::new(
+                    inner.max.x - self.length,
+                    p,
+                    inner.max.x,
+                    p + self.thickness,
+                ),
+                Sides::TOP => Rect::new(
+                    p,
+                    inner.min.y,
+                    p + self.thickness,
+                    inner.min.y + self.length,
+                ),
+                _ => Rect::new(
+                    p,
+                    inner.max.y - self.length,
+                    p + self.thickness,
+                    inner.max.y,
+                ),
+            }));
+        }
+        ticks
+    }
+}
+
+impl UiDecoration for BorderTicks {
+    const LAYER: DecorationLayer = DecorationLayer::Border;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        quads.extend(self.ticks(context).into_iter().map(|rect| DecorationQuad {
+            rect,
+            color: self.color,
+        }));
+    }
+}
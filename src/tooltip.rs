@@ -0,0 +1,266 @@
+use bevy::prelude::*;
+
+use crate::DecorationContext;
+use crate::DecorationLayer;
+use crate::DecorationQuad;
+use crate::UiDecoration;
+
+/// Which side of a [`TooltipFrame`] or [`BorderNotch`](crate::BorderNotch) its tail sticks out from.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum TailSide {
+    Top,
+    /// Below the tooltip, pointing down at an anchor underneath it
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Draws a UI node as a tooltip: a bordered rounded rect with a tail on one side pointing at an anchor entity.
+///
+/// The frame replaces the node's background, so give the node a transparent [`BackgroundColor`] and no [`Style::border`].
+/// The tail is drawn outside the node, so leave a margin of at least `tail_length` on its side.
+///
+/// Curves and slopes are built from strips one logical pixel thick. The fill is drawn over the border color,
+/// so translucent fills show the border color through them.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct TooltipFrame {
+    /// The entity the tail points at. The tail is centered on its side if there is no anchor.
+    pub anchor: Option<Entity>,
+    pub side: TailSide,
+    pub fill: Color,
+    pub border_color: Color,
+    /// Thickness of the border in logical pixels
+    pub border: f32,
+    /// Corner radius in logical pixels
+    pub radius: f32,
+    /// Width of the tail where it meets the frame in logical pixels
+    pub tail_width: f32,
+    /// Distance from the frame to the tip of the tail in logical pixels
+    pub tail_length: f32,
+    /// Offset of the tail from the middle of its side, updated from the anchor's position
+    #[reflect(ignore)]
+    tail_offset: f32,
+}
+
+impl Default for TooltipFrame {
+    fn default() -> Self {
+        Self {
+            anchor: None,
+            side: TailSide::Bottom,
+            fill: Color::rgb(0.1, 0.1, 0.1),
+            border_color: Color::WHITE,
+            border: 1.,
+            radius: 4.,
+            tail_width: 12.,
+            tail_length: 6.,
+            tail_offset: 0.,
+        }
+    }
+}
+
+impl TooltipFrame {
+    /// A tooltip with its tail on `side` pointing at `anchor`.
+    pub fn new(anchor: Entity, side: TailSide) -> Self {
+        Self {
+            anchor: Some(anchor),
+            side,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_colors(mut self, fill: Color, border_color: Color) -> Self {
+        self.fill = fill;
+        self.border_color = border_color;
+        self
+    }
+
+    /// Offset of the tail from the middle of its side in logical pixels.
+    pub fn tail_offset(&self) -> f32 {
+        self.tail_offset
+    }
+
+    /// The position of the tip of the tail, in the node's local space.
+    pub fn tail_tip(&self, size: Vec2) -> Vec2 {
+        let half_size = 0.5 * size;
+        let normal = match self.side {
+            TailSide::Top | TailSide::Bottom => half_size.y,
+            TailSide::Left | TailSide::Right => half_size.x,
+        };
+        to_local(
+            self.side,
+            Vec2::new(self.tail_offset, normal + self.tail_length.max(0.)),
+        )
+    }
+
+    /// The furthest the tail can move from the middle of a side of the given length without overlapping the corners.
+    fn max_tail_offset(&self, side_length: f32) -> f32 {
+        (0.5 * side_length - self.radius.max(0.) - 0.5 * self.tail_width.max(0.)).max(0.)
+    }
+}
+
+impl UiDecoration for TooltipFrame {
+    const LAYER: DecorationLayer = DecorationLayer::Below;
+
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        let rect = context.node_rect();
+        if rect.is_empty() {
+            return;
+        }
+        let border = self.border.max(0.);
+        let radius = self.radius.max(0.).min(0.5 * rect.size().min_element());
+        push_rounded_rect(rect, radius, self.border_color, quads);
+        let inner = rect.inset(-border);
+        if !inner.is_empty() {
+            push_rounded_rect(inner, (radius - border).max(0.), self.fill, quads);
+        }
+
+        let half_size = 0.5 * rect.size();
+        let normal = match self.side {
+            TailSide::Top | TailSide::Bottom => half_size.y,
+            TailSide::Left | TailSide::Right => half_size.x,
+        };
+        let half_width = 0.5 * self.tail_width.max(0.);
+        let length = self.tail_length.max(0.);
+        if half_width <= 0. || length <= 0. {
+            return;
+        }
+        // Outer triangle in the border color
+        push_tail(
+            self.side,
+            self.tail_offset,
+            normal,
+            length,
+            half_width,
+            0.,
+            self.border_color,
+            quads,
+        );
+        // Inner triangle in the fill color, starting inside the frame to cover the border beneath the tail
+        push_tail(
+            self.side,
+            self.tail_offset,
+            normal - border,
+            length + border,
+            half_width + border,
+            border,
+            self.fill,
+            quads,
+        );
+    }
+}
+
+/// Maps a point from tail space, where `x` runs along the tail's side and `y` points away from the frame,
+/// to the node's local space.
+pub(crate) fn to_local(side: TailSide, point: Vec2) -> Vec2 {
+    match side {
+        TailSide::Top => Vec2::new(point.x, -point.y),
+        TailSide::Bottom => point,
+        TailSide::Left => Vec2::new(-point.y, point.x),
+        TailSide::Right => Vec2::new(point.y, point.x),
+    }
+}
+
+/// Pushes a rounded rect as horizontal strips, one logical pixel tall within the corners.
+pub(crate) fn push_rounded_rect(
+    rect: Rect,
+    radius: f32,
+    color: Color,
+    quads: &mut Vec<DecorationQuad>,
+) {
+    if color.a() == 0. {
+        return;
+    }
+    let middle = Rect::new(
+        rect.min.x,
+        rect.min.y + radius,
+        rect.max.x,
+        rect.max.y - radius,
+    );
+    if !middle.is_empty() {
+        quads.push(DecorationQuad {
+            rect: middle,
+            color,
+        });
+    }
+    let rows = radius.ceil() as usize;
+    for row in 0..rows {
+        let near = row as f32;
+        let far = (near + 1.).min(radius);
+        let dy = radius - 0.5 * (near + far);
+        let inset = radius - (radius * radius - dy * dy).max(0.).sqrt();
+        for (min_y, max_y) in [
+            (rect.min.y + near, rect.min.y + far),
+            (rect.max.y - far, rect.max.y - near),
+        ] {
+            quads.push(DecorationQuad {
+                rect: Rect::new(rect.min.x + inset, min_y, rect.max.x - inset, max_y),
+                color,
+            });
+        }
+    }
+}
+
+/// Pushes a triangular tail as strips parallel to its base, one logical pixel thick.
+///
+/// The base is centered at `offset` along the side, `start` from the middle of the node, and the tail narrows to
+/// its tip over `length`. `shrink` is subtracted from the half width of every strip.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn push_tail(
+    side: TailSide,
+    offset: f32,
+    start: f32,
+    length: f32,
+    half_width: f32,
+    shrink: f32,
+    color: Color,
+    quads: &mut Vec<DecorationQuad>,
+) {
+    if color.a() == 0. {
+        return;
+    }
+    let rows = length.ceil() as usize;
+    for row in 0..rows {
+        let near = row as f32;
+        let far = (near + 1.).min(length);
+        let t = 0.5 * (near + far) / length;
+        let half = half_width * (1. - t) - shrink;
+        if half <= 0. {
+            break;
+        }
+        let a = to_local(side, Vec2::new(offset - half, start + near));
+        let b = to_local(side, Vec2::new(offset + half, start + far));
+        quads.push(DecorationQuad {
+            rect: Rect::from_corners(a, b),
+            color,
+        });
+    }
+}
+
+/// Moves each tooltip's tail along its side to point at its anchor.
+pub(crate) fn update_tooltip_tails(
+    mut tooltip_query: Query<(&mut TooltipFrame, &Node, &GlobalTransform)>,
+    anchor_query: Query<&GlobalTransform>,
+) {
+    for (mut tooltip, node, transform) in tooltip_query.iter_mut() {
+        let (delta, side_length) = match tooltip
+            .anchor
+            .and_then(|anchor| anchor_query.get(anchor).ok())
+        {
+            Some(anchor) => {
+                let delta = anchor.translation().truncate() - transform.translation().truncate();
+                match tooltip.side {
+                    TailSide::Top | TailSide::Bottom => (delta.x, node.size().x),
+                    TailSide::Left | TailSide::Right => (delta.y, node.size().y),
+                }
+            }
+            None => (0., 0.),
+        };
+        let max_offset = tooltip.max_tail_offset(side_length);
+        let offset = delta.clamp(-max_offset, max_offset);
+        if tooltip.tail_offset != offset {
+            tooltip.tail_offset = offset;
+        }
+    }
+}
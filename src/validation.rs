@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+
+use crate::tooltip::push_rounded_rect;
+use crate::BadgeCorner;
+use crate::BorderColor;
+use crate::BorderPalette;
+use crate::CornerBadge;
+use crate::DecorationContext;
+use crate::DecorationQuad;
+use crate::OutlineColor;
+use crate::UiDecoration;
+
+/// Flags a form input as valid, or as having a warning or an error, by coloring its border and outline.
+///
+/// The color is the [`BorderPalette`] color named after the state, `"valid"`, `"warning"` or `"error"`, so switching
+/// [`ThemeMode`](crate::ThemeMode) with [`BorderThemes`](crate::BorderThemes) that name them re-colors every flagged
+/// input. States missing from the palette use the colors of the [`ValidationStyle`] resource.
+///
+/// The node's [`BorderColor`] is set, and its [`OutlineColor`] if it has one. Outlines without an `OutlineColor` take
+/// the border color anyway. Removing the state leaves the colors as they are and removes the icon.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+pub enum ValidationState {
+    #[default]
+    Valid,
+    Warning,
+    Error,
+}
+
+impl ValidationState {
+    /// The name of the state's color in the [`BorderPalette`].
+    pub fn palette_name(self) -> &'static str {
+        match self {
+            ValidationState::Valid => "valid",
+            ValidationState::Warning => "warning",
+            ValidationState::Error => "error",
+        }
+    }
+}
+
+/// The fallback colors and the icon used for [`ValidationState`]s.
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct ValidationStyle {
+    pub valid: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// Radius in logical pixels of the icon drawn over a corner of each flagged node, no icons are drawn if 0
+    pub icon_radius: f32,
+    pub icon_corner: BadgeCorner,
+}
+
+impl Default for ValidationStyle {
+    fn default() -> Self {
+        Self {
+            valid: Color::LIME_GREEN,
+            warning: Color::ORANGE,
+            error: Color::RED,
+            icon_radius: 0.,
+            icon_corner: BadgeCorner::TopRight,
+        }
+    }
+}
+
+impl ValidationStyle {
+    /// The color of a state, from the palette if it names one.
+    pub fn color(&self, state: ValidationState, palette: &BorderPalette) -> Color {
+        palette.get(state.palette_name()).unwrap_or(match state {
+            ValidationState::Valid => self.valid,
+            ValidationState::Warning => self.warning,
+            ValidationState::Error => self.error,
+        })
+    }
+
+    pub fn with_icons(mut self, radius: f32, corner: BadgeCorner) -> Self {
+        self.icon_radius = radius;
+        self.icon_corner = corner;
+        self
+    }
+}
+
+/// The icon drawn over a corner of a node with a [`ValidationState`], maintained by the plugin from the
+/// [`ValidationStyle`].
+///
+/// The icon is a disc in the state's color, marked with an exclamation mark for warnings and errors.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct ValidationIcon {
+    pub state: ValidationState,
+    pub color: Color,
+    pub corner: BadgeCorner,
+    /// Radius of the disc in logical pixels
+    pub radius: f32,
+}
+
+impl Default for ValidationIcon {
+    fn default() -> Self {
+        Self {
+            state: ValidationState::Valid,
+            color: Color::LIME_GREEN,
+            corner: BadgeCorner::TopRight,
+            radius: 6.,
+        }
+    }
+}
+
+impl UiDecoration for ValidationIcon {
+    fn decorate(&self, context: &DecorationContext, quads: &mut Vec<DecorationQuad>) {
+        if self.radius <= 0. || !self.radius.is_finite() {
+            return;
+        }
+        let center = CornerBadge::new(self.corner, self.radius, self.color).center(context);
+        push_rounded_rect(
+            Rect::from_center_half_size(center, Vec2::splat(self.radius)),
+            self.radius,
+            self.color,
+            quads,
+        );
+        if self.state == ValidationState::Valid {
+            return;
+        }
+        let r = self.radius;
+        let half_width = 0.125 * r;
+        for (top, bottom) in [(-0.6 * r, 0.15 * r), (0.35 * r, 0.6 * r)] {
+            quads.push(DecorationQuad {
+                rect: Rect::new(
+                    center.x - half_width,
+                    center.y + top,
+                    center.x + half_width,
+                    center.y + bottom,
+                ),
+                color: Color::WHITE,
+            });
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_validation_states(
+    mut commands: Commands,
+    style: Res<ValidationStyle>,
+    palette: Res<BorderPalette>,
+    mut removed: RemovedComponents<ValidationState>,
+    mut state_query: Query<(
+        Entity,
+        Ref<ValidationState>,
+        Option<&mut BorderColor>,
+        Option<&mut OutlineColor>,
+        Option<&mut ValidationIcon>,
+    )>,
+) {
+    for entity in removed.iter() {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.remove::<ValidationIcon>();
+        }
+    }
+    for (entity, state, border_color, outline_color, icon) in state_query.iter_mut() {
+        if !state.is_changed() && !style.is_changed() && !palette.is_changed() {
+            continue;
+        }
+        let color = style.color(*state, &palette);
+        match border_color {
+            Some(mut border_color) => {
+                if border_color.0 != color {
+                    border_color.0 = color;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(BorderColor(color));
+            }
+        }
+        if let Some(mut outline_color) = outline_color {
+            if outline_color.0 != color {
+                outline_color.0 = color;
+            }
+        }
+        let new_icon = ValidationIcon {
+            state: *state,
+            color,
+            corner: style.icon_corner,
+            radius: style.icon_radius,
+        };
+        match (icon, 0. < style.icon_radius) {
+            (Some(mut icon), true) => *icon = new_icon,
+            (None, true) => {
+                commands.entity(entity).insert(new_icon);
+            }
+            (Some(_), false) => {
+                commands.entity(entity).remove::<ValidationIcon>();
+            }
+            (None, false) => {}
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::ui::UiStack;
+use bevy::window::PrimaryWindow;
+
+use crate::border_edges_at;
+use crate::world_rect;
+use crate::BorderColor;
+use crate::BorderHitSlop;
+use crate::CalculatedBorder;
+use crate::CalculatedOutline;
+use crate::Outline;
+use crate::OutlineColor;
+
+/// Which part of a [`WindowFrame`] is being dragged.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct FrameDrag {
+    /// Moves the frame when dragging the title bar
+    moving: bool,
+    /// Edges being resized, in left, right, top, bottom order
+    edges: [bool; 4],
+    start_cursor: Vec2,
+    start_rect: Rect,
+}
+
+/// A movable, resizable panel with window chrome.
+///
+/// The frame is positioned absolutely and moved by dragging a descendant [`WindowTitleBar`]. Dragging within
+/// `resize_margin` of its edges resizes it, extended outward and inward by the frame's [`BorderHitSlop`] if it has one. A [`WindowCloseButton`] inside the frame despawns it when clicked.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct WindowFrame {
+    /// Smallest size the frame can be resized to, in logical pixels
+    pub min_size: Vec2,
+    /// Width of the resize handles along each edge in logical pixels, measured inward from the outside of the frame.
+    /// Handles are never thinner than the frame's border.
+    pub resize_margin: f32,
+    pub resizable: bool,
+    #[reflect(ignore)]
+    drag: Option<FrameDrag>,
+}
+
+impl Default for WindowFrame {
+    fn default() -> Self {
+        Self {
+            min_size: Vec2::new(100., 60.),
+            resize_margin: 6.,
+            resizable: true,
+            drag: None,
+        }
+    }
+}
+
+/// The draggable title strip of a [`WindowFrame`].
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct WindowTitleBar;
+
+/// A button that closes the [`WindowFrame`] it is inside when clicked.
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct WindowCloseButton;
+
+/// A bordered and outlined panel with a [`WindowFrame`].
+///
+/// Spawn it with [`spawn_window_frame`] to add a title bar, a slot for a close button and a content area.
+#[derive(Bundle, Clone, Debug)]
+pub struct WindowFrameBundle {
+    /// Describes the logical size of the node
+    pub node: Node,
+    /// Describes the style including flexbox settings
+    pub style: Style,
+    /// The background color of the panel
+    pub background_color: BackgroundColor,
+    /// Whether this node should block interaction with lower nodes
+    pub focus_policy: FocusPolicy,
+    /// The transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `WindowFrameBundle`, use the properties of the [`Style`] component.
+    pub transform: Transform,
+    /// The global transform of the node
+    ///
+    /// This field is automatically managed by the UI layout system.
+    /// To alter the position of the `WindowFrameBundle`, use the properties of the [`Style`] component.
+    pub global_transform: GlobalTransform,
+    /// Describes the visibility properties of the node
+    pub visibility: Visibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub computed_visibility: ComputedVisibility,
+    /// Indicates the depth at which the node should appear in the UI
+    pub z_index: ZIndex,
+    /// Tracks whether the frame is pressed
+    pub interaction: Interaction,
+    /// The color of the frame's border
+    pub border_color: BorderColor,
+    /// The thickness of the frame's outline
+    pub outline: Outline,
+    /// The color of the frame's outline
+    pub outline_color: OutlineColor,
+    /// The border geometry
+    pub calculated_border: CalculatedBorder,
+    /// The outline geometry
+    pub calculated_outline: CalculatedOutline,
+    pub window_frame: WindowFrame,
+}
+
+impl Default for WindowFrameBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            style: Style {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                border: UiRect::all(Val::Px(3.)),
+                ..Default::default()
+            },
+            background_color: Color::rgb(0.15, 0.15, 0.2).into(),
+            focus_policy: FocusPolicy::Block,
+            transform: Default::default(),
+            global_transform: Default::default(),
+            visibility: Default::default(),
+            computed_visibility: Default::default(),
+            z_index: ZIndex::Global(1),
+            interaction: Default::default(),
+            border_color: Color::rgb(0.5, 0.5, 0.6).into(),
+            outline: Outline::thickness(UiRect::all(Val::Px(1.))),
+            outline_color: Color::BLACK.into(),
+            calculated_border: Default::default(),
+            calculated_outline: Default::default(),
+            window_frame: Default::default(),
+        }
+    }
+}
+
+impl WindowFrameBundle {
+    /// A frame with its top left corner at `position` and the given size, in logical pixels.
+    pub fn new(position: Vec2, size: Vec2) -> Self {
+        let mut bundle = Self::default();
+        bundle.style.position = UiRect {
+            left: Val::Px(position.x),
+            top: Val::Px(position.y),
+            ..Default::default()
+        };
+        bundle.style.size = Size::new(Val::Px(size.x), Val::Px(size.y));
+        bundle
+    }
+}
+
+/// The entities making up a window frame spawned by [`spawn_window_frame`].
+#[derive(Copy, Clone, Debug)]
+pub struct WindowFrameParts {
+    pub frame: Entity,
+    pub title_bar: Entity,
+    /// An empty node at the right end of the title bar, for a button with a [`WindowCloseButton`]
+    pub close_button_slot: Entity,
+    /// The area below the title bar for the window's content
+    pub content: Entity,
+}
+
+/// Spawns a window frame with a title bar of the given height, a close button slot and a content area.
+pub fn spawn_window_frame(
+    commands: &mut Commands,
+    frame: WindowFrameBundle,
+    title_bar_height: f32,
+    title_bar_color: Color,
+) -> WindowFrameParts {
+    let close_button_slot = commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(title_bar_height), Val::Px(title_bar_height)),
+                margin: UiRect::left(Val::Auto),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id();
+    let title_bar = commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Auto, Val::Px(title_bar_height)),
+                    flex_shrink: 0.,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                background_color: title_bar_color.into(),
+                ..Default::default()
+            },
+            Interaction::default(),
+            WindowTitleBar,
+        ))
+        .add_child(close_button_slot)
+        .id();
+    let content = commands
+        .spawn(NodeBundle {
+            style: Style {
+                flex_grow: 1.,
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            background_color: Color::NONE.into(),
+            ..Default::default()
+        })
+        .id();
+    let frame = commands
+        .spawn(frame)
+        .push_children(&[title_bar, content])
+        .id();
+    WindowFrameParts {
+        frame,
+        title_bar,
+        close_button_slot,
+        content,
+    }
+}
+
+/// The cursor position in UI coordinates, with the origin at the top left of the primary window.
+pub(crate) fn ui_cursor_position(window: &Window) -> Option<Vec2> {
+    window
+        .cursor_position()
+        .map(|position| Vec2::new(position.x, window.height() - position.y))
+}
+
+/// Starts, updates and ends drags of window frames.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn drag_window_frames(
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    ui_stack: Res<UiStack>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    title_bar_query: Query<&Interaction, With<WindowTitleBar>>,
+    children_query: Query<&Children>,
+    mut frame_query: Query<(
+        &mut WindowFrame,
+        &mut Style,
+        &Node,
+        &GlobalTransform,
+        Option<&CalculatedBorder>,
+        Option<&BorderHitSlop>,
+    )>,
+) {
+    let Some(mouse_buttons) = mouse_buttons else {
+        return;
+    };
+    let Some(cursor) = window_query.get_single().ok().and_then(ui_cursor_position) else {
+        return;
+    };
+
+    if mouse_buttons.just_pressed(MouseButton::Left) {
+        // Only the topmost frame under the cursor starts a drag
+        for entity in ui_stack.uinodes.iter().rev() {
+            let Ok((mut frame, _, node, transform, border, hit_slop)) =
+                frame_query.get_mut(*entity)
+            else {
+                continue;
+            };
+            let rect = world_rect(node, transform);
+            let slop = hit_slop.map_or(0., |slop| slop.0.max(0.));
+            if !rect.inset(slop).contains(cursor) {
+                continue;
+            }
+            let edges = if frame.resizable {
+                let thickness = border
+                    .map(|border| border.resolved)
+                    .unwrap_or_default()
+                    .map(|thickness| thickness.max(frame.resize_margin));
+                border_edges_at(rect, thickness, cursor, slop)
+            } else {
+                [false; 4]
+            };
+            let moving = !edges.contains(&true)
+                && children_query
+                    .iter_descendants(*entity)
+                    .any(|descendant| title_bar_query.get(descendant) == Ok(&Interaction::Clicked));
+            if moving || edges.contains(&true) {
+                frame.drag = Some(FrameDrag {
+                    moving,
+                    edges,
+                    start_cursor: cursor,
+                    start_rect: rect,
+                });
+            }
+            break;
+        }
+    }
+
+    for (mut frame, mut style, ..) in frame_query.iter_mut() {
+        let Some(drag) = frame.drag else {
+            continue;
+        };
+        if !mouse_buttons.pressed(MouseButton::Left) {
+            frame.drag = None;
+            continue;
+        }
+        let delta = cursor - drag.start_cursor;
+        let mut rect = drag.start_rect;
+        if drag.moving {
+            rect.min += delta;
+            rect.max += delta;
+        } else {
+            let [left, right, top, bottom] = drag.edges;
+            if left {
+                rect.min.x = (rect.min.x + delta.x).min(rect.max.x - frame.min_size.x);
+            }
+            if right {
+                rect.max.x = (rect.max.x + delta.x).max(rect.min.x + frame.min_size.x);
+            }
+            if top {
+                rect.min.y = (rect.min.y + delta.y).min(rect.max.y - frame.min_size.y);
+            }
+            if bottom {
+                rect.max.y = (rect.max.y + delta.y).max(rect.min.y + frame.min_size.y);
+            }
+        }
+        // Frames are positioned relative to the window, so their positions are offset by the same amount
+        let offset = rect.min - drag.start_rect.min;
+        let position = Vec2::new(
+            val_px(style.position.left) + offset.x,
+            val_px(style.position.top) + offset.y,
+        );
+        if !drag.moving {
+            style.size = Size::new(Val::Px(rect.width()), Val::Px(rect.height()));
+        }
+        style.position.left = Val::Px(position.x);
+        style.position.top = Val::Px(position.y);
+        frame.drag = Some(FrameDrag {
+            start_cursor: cursor,
+            start_rect: rect,
+            ..drag
+        });
+    }
+}
+
+fn val_px(value: Val) -> f32 {
+    match value {
+        Val::Px(px) => px,
+        _ => 0.,
+    }
+}
+
+/// Despawns the window frame containing each clicked [`WindowCloseButton`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn close_window_frames(
+    mut commands: Commands,
+    button_query: Query<(Entity, &Interaction), (With<WindowCloseButton>, Changed<Interaction>)>,
+    parent_query: Query<&Parent>,
+    frame_query: Query<(), With<WindowFrame>>,
+) {
+    for (entity, interaction) in button_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        if let Some(frame) = parent_query
+            .iter_ancestors(entity)
+            .find(|ancestor| frame_query.contains(*ancestor))
+        {
+            commands.entity(frame).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Easing curves and keyframe timelines, sampled without an app.
+
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+const EASINGS: [Easing; 11] = [
+    Easing::Linear,
+    Easing::QuadraticIn,
+    Easing::QuadraticOut,
+    Easing::QuadraticInOut,
+    Easing::CubicIn,
+    Easing::CubicOut,
+    Easing::CubicInOut,
+    Easing::ElasticIn,
+    Easing::ElasticOut,
+    Easing::BounceIn,
+    Easing::BounceOut,
+];
+
+#[track_caller]
+fn assert_near(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn easings_start_at_zero_and_end_at_one() {
+    for easing in EASINGS {
+        assert_near(easing.ease(0.), 0.);
+        assert_near(easing.ease(1.), 1.);
+        // Progress outside of 0..=1 is clamped
+        assert_near(easing.ease(-1.), 0.);
+        assert_near(easing.ease(2.), 1.);
+    }
+}
+
+#[test]
+fn easing_midpoints() {
+    for (easing, expected) in [
+        (Easing::Linear, 0.5),
+        (Easing::QuadraticIn, 0.25),
+        (Easing::QuadraticOut, 0.75),
+        (Easing::QuadraticInOut, 0.5),
+        (Easing::CubicIn, 0.125),
+        (Easing::CubicOut, 0.875),
+        (Easing::CubicInOut, 0.5),
+        // Elastic curves overshoot
+        (Easing::ElasticOut, 1. + 2f32.powi(-5) * 0.5),
+        (Easing::ElasticIn, -(2f32.powi(-5) * 0.5)),
+        (Easing::BounceOut, 0.765625),
+        (Easing::BounceIn, 0.234375),
+    ] {
+        assert_near(easing.ease(0.5), expected);
+    }
+    assert_near(Easing::QuadraticInOut.ease(0.25), 0.125);
+    assert_near(Easing::CubicInOut.ease(0.75), 0.9375);
+}
+
+#[test]
+fn in_and_out_easings_mirror_each_other() {
+    for (ease_in, ease_out) in [
+        (Easing::QuadraticIn, Easing::QuadraticOut),
+        (Easing::CubicIn, Easing::CubicOut),
+        (Easing::ElasticIn, Easing::ElasticOut),
+        (Easing::BounceIn, Easing::BounceOut),
+    ] {
+        for i in 0..=10 {
+            let t = i as f32 / 10.;
+            assert_near(ease_in.ease(t), 1. - ease_out.ease(1. - t));
+        }
+    }
+}
+
+/// An outline offset timeline from 0px at 0s to 10px at 2s.
+fn timeline(repeat: KeyframeRepeat) -> BorderKeyframes {
+    BorderKeyframes::new(repeat)
+        .with(
+            2.,
+            BorderProperty::OutlineOffset(Val::Px(10.)),
+            Easing::Linear,
+        )
+        .with(
+            0.,
+            BorderProperty::OutlineOffset(Val::Px(0.)),
+            Easing::Linear,
+        )
+}
+
+#[track_caller]
+fn assert_offset_at(keyframes: &mut BorderKeyframes, elapsed: f32, expected: f32) {
+    keyframes.elapsed = elapsed;
+    match keyframes.sample().as_slice() {
+        [BorderProperty::OutlineOffset(Val::Px(offset))] => assert_near(*offset, expected),
+        values => panic!("unexpected sample {values:?}"),
+    }
+}
+
+#[test]
+fn keyframes_are_sorted_by_time() {
+    let keyframes = timeline(KeyframeRepeat::Once);
+    let times: Vec<f32> = keyframes
+        .keyframes()
+        .iter()
+        .map(|keyframe| keyframe.time)
+        .collect();
+    assert_eq!(times, [0., 2.]);
+    assert_eq!(keyframes.duration(), 2.);
+}
+
+#[test]
+fn once_holds_the_last_keyframe() {
+    let mut keyframes = timeline(KeyframeRepeat::Once);
+    assert_offset_at(&mut keyframes, 0., 0.);
+    assert_offset_at(&mut keyframes, 0.5, 2.5);
+    assert!(!keyframes.is_finished());
+    assert_offset_at(&mut keyframes, 3., 10.);
+    assert!(keyframes.is_finished());
+}
+
+#[test]
+fn loop_wraps_to_the_first_keyframe() {
+    let mut keyframes = timeline(KeyframeRepeat::Loop);
+    assert_offset_at(&mut keyframes, 1., 5.);
+    assert_offset_at(&mut keyframes, 2., 0.);
+    assert_offset_at(&mut keyframes, 2.5, 2.5);
+    assert_offset_at(&mut keyframes, 7.5, 7.5);
+    assert!(!keyframes.is_finished());
+}
+
+#[test]
+fn ping_pong_plays_backwards_then_forwards() {
+    let mut keyframes = timeline(KeyframeRepeat::PingPong);
+    assert_offset_at(&mut keyframes, 1., 5.);
+    assert_offset_at(&mut keyframes, 2., 10.);
+    assert_offset_at(&mut keyframes, 2.5, 7.5);
+    assert_offset_at(&mut keyframes, 4., 0.);
+    assert_offset_at(&mut keyframes, 4.5, 2.5);
+    assert!(!keyframes.is_finished());
+}
+
+#[test]
+fn each_property_follows_its_own_keyframes() {
+    let mut keyframes = BorderKeyframes::new(KeyframeRepeat::Once)
+        .with(
+            0.,
+            BorderProperty::OutlineOffset(Val::Px(0.)),
+            Easing::Linear,
+        )
+        .with(
+            4.,
+            BorderProperty::OutlineOffset(Val::Px(8.)),
+            Easing::Linear,
+        )
+        .with(
+            1.,
+            BorderProperty::OutlineColor(Color::BLACK),
+            Easing::Linear,
+        )
+        .with(
+            3.,
+            BorderProperty::OutlineColor(Color::WHITE),
+            Easing::QuadraticIn,
+        );
+
+    keyframes.elapsed = 0.5;
+    assert_eq!(
+        keyframes.sample(),
+        [
+            BorderProperty::OutlineOffset(Val::Px(1.)),
+            // Before its first keyframe a property holds that keyframe's value
+            BorderProperty::OutlineColor(Color::BLACK),
+        ]
+    );
+
+    // The easing of the keyframe being approached shapes the curve
+    keyframes.elapsed = 2.;
+    let [BorderProperty::OutlineOffset(_), BorderProperty::OutlineColor(color)] =
+        keyframes.sample()[..]
+    else {
+        panic!("unexpected sample");
+    };
+    assert_near(color.r(), 0.25);
+}
+
+#[test]
+fn lenses_interpolate_their_components() {
+    let mut border_color = BorderColor(Color::NONE);
+    let mut color_lens = BorderColorLens::new(Color::RED, Color::BLUE);
+    color_lens.lerp(&mut border_color, 0.);
+    assert_eq!(border_color.0, Color::RED);
+    color_lens.lerp(&mut border_color, 1.);
+    assert_eq!(border_color.0, Color::BLUE);
+
+    let mut outline_color = OutlineColor(Color::NONE);
+    OutlineColorLens::new(Color::WHITE, Color::WHITE.with_a(0.)).lerp(&mut outline_color, 0.5);
+    assert_near(outline_color.0.a(), 0.5);
+
+    // Color lenses interpolate in their color space like the animation components
+    let mut color_lens = color_lens.with_color_space(ColorSpace::Oklab);
+    color_lens.lerp(&mut border_color, 0.5);
+    assert_eq!(
+        border_color.0,
+        ColorSpace::Oklab.lerp(Color::RED, Color::BLUE, 0.5)
+    );
+
+    let start = UiRect::new(Val::Px(0.), Val::Px(4.), Val::Percent(10.), Val::Px(2.));
+    let end = UiRect::new(
+        Val::Px(8.),
+        Val::Px(0.),
+        Val::Percent(20.),
+        Val::Percent(50.),
+    );
+    // Edges whose units differ hold their start value until the end
+    let halfway = UiRect::new(Val::Px(4.), Val::Px(2.), Val::Percent(15.), Val::Px(2.));
+
+    let mut outline = Outline::default();
+    OutlineThicknessLens { start, end }.lerp(&mut outline, 0.5);
+    assert_eq!(outline.thickness, halfway);
+    OutlineThicknessLens { start, end }.lerp(&mut outline, 1.);
+    assert_eq!(outline.thickness, end);
+
+    let mut border = Border::all(Val::Px(1.), Color::WHITE);
+    BorderWidthLens { start, end }.lerp(&mut border, 0.5);
+    assert_eq!(border.width, halfway);
+    // Only the width is interpolated
+    assert_eq!(border.color, Color::WHITE);
+}
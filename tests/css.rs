@@ -0,0 +1,231 @@
+//! Parsing of the CSS subset accepted by `BorderStylesheet`.
+
+use bevy::prelude::*;
+use bevy_ui_borders::*;
+
+/// The style of the single rule in `.a { <declarations> }`.
+#[track_caller]
+fn style(declarations: &str) -> BorderStyleAsset {
+    let stylesheet = BorderStylesheet::parse(&format!(".a {{ {declarations} }}")).unwrap();
+    assert_eq!(stylesheet.rules.len(), 1);
+    stylesheet.rules[0].style.clone()
+}
+
+#[track_caller]
+fn error(source: &str) -> CssError {
+    BorderStylesheet::parse(source).unwrap_err()
+}
+
+fn invalid(property: &str, value: &str) -> CssError {
+    CssError::InvalidValue {
+        property: property.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn line_shorthands_accept_parts_in_any_order() {
+    let a = style("border: 4px solid #ffaa00");
+    let b = style("border: #ffaa00 solid 4px");
+    for style in [a, b] {
+        assert_eq!(style.border, Some(Thickness::All(Val::Px(4.))));
+        assert_eq!(style.border_color, Some(Color::hex("ffaa00").unwrap()));
+    }
+
+    let keywords = style("outline: thick white; border: none");
+    assert_eq!(keywords.outline, Some(Thickness::All(Val::Px(5.))));
+    assert_eq!(keywords.outline_color, Some(Color::WHITE));
+    assert_eq!(keywords.border, Some(Thickness::All(Val::Px(0.))));
+    assert_eq!(keywords.border_color, None);
+
+    // Without a width the shorthand is `medium`
+    assert_eq!(
+        style("outline: solid").outline,
+        Some(Thickness::All(Val::Px(3.)))
+    );
+}
+
+#[test]
+fn unsupported_line_styles_are_drawn_solid() {
+    for line_style in ["dashed", "dotted", "double"] {
+        let style = style(&format!("border: 2px {line_style} red"));
+        assert_eq!(style.border, Some(Thickness::All(Val::Px(2.))));
+        assert_eq!(style.border_color, Some(Color::RED));
+    }
+}
+
+#[test]
+fn edge_lists_follow_css_order() {
+    let edges = |top, right, bottom, left| Thickness::Edges {
+        left: Val::Px(left),
+        right: Val::Px(right),
+        top: Val::Px(top),
+        bottom: Val::Px(bottom),
+    };
+    assert_eq!(
+        style("border-width: 1px").border,
+        Some(Thickness::All(Val::Px(1.)))
+    );
+    assert_eq!(
+        style("border-width: 1px 2px").border,
+        Some(edges(1., 2., 1., 2.))
+    );
+    assert_eq!(
+        style("border-width: 1px 2px 3px").border,
+        Some(edges(1., 2., 3., 2.))
+    );
+    assert_eq!(
+        style("outline-width: 1px 2px 3px 4px").outline,
+        Some(edges(1., 2., 3., 4.))
+    );
+}
+
+#[test]
+fn radius_lists_follow_css_order() {
+    let corners = |top_left, top_right, bottom_right, bottom_left| Radius::Corners {
+        top_left: Val::Px(top_left),
+        top_right: Val::Px(top_right),
+        bottom_right: Val::Px(bottom_right),
+        bottom_left: Val::Px(bottom_left),
+    };
+    assert_eq!(
+        style("border-radius: 50%").radius,
+        Some(Radius::All(Val::Percent(50.)))
+    );
+    assert_eq!(
+        style("border-radius: 1px 2px").radius,
+        Some(corners(1., 2., 1., 2.))
+    );
+    assert_eq!(
+        style("border-radius: 1px 2px 3px").radius,
+        Some(corners(1., 2., 3., 2.))
+    );
+    assert_eq!(
+        style("border-radius: 1px 2px 3px 4px").radius,
+        Some(corners(1., 2., 3., 4.))
+    );
+    assert_eq!(
+        error(".a { border-radius: 4px / 2px }"),
+        invalid("border-radius", "4px / 2px")
+    );
+}
+
+#[test]
+fn lengths_are_px_percent_or_zero() {
+    assert_eq!(
+        style("border-width: 0 25% 1.5px 0").border,
+        Some(Thickness::Edges {
+            left: Val::Px(0.),
+            right: Val::Percent(25.),
+            top: Val::Px(0.),
+            bottom: Val::Px(1.5),
+        })
+    );
+    assert_eq!(
+        error(".a { border-width: 4 }"),
+        invalid("border-width", "4")
+    );
+    assert_eq!(
+        error(".a { border-width: 4em }"),
+        invalid("border-width", "4em")
+    );
+    assert_eq!(
+        error(".a { border-width: 1px 2px 3px 4px 5px }"),
+        invalid("border-width", "1px 2px 3px 4px 5px")
+    );
+}
+
+#[test]
+fn colors_are_hex_rgb_or_named() {
+    let color = |value: &str| style(&format!("border-color: {value}")).border_color;
+    assert_eq!(color("#ff0000"), Some(Color::hex("ff0000").unwrap()));
+    assert_eq!(color("#f00"), Some(Color::hex("f00").unwrap()));
+    assert_eq!(color("rgb(255, 0, 0)"), Some(Color::rgb_u8(255, 0, 0)));
+    assert_eq!(
+        color("rgba(0, 0, 0, 0.5)"),
+        Some(Color::rgba_u8(0, 0, 0, 127))
+    );
+    assert_eq!(color("transparent"), Some(Color::NONE));
+    assert_eq!(color("grey"), Some(Color::GRAY));
+    assert_eq!(
+        error(".a { border-color: rgb(1, 2) }"),
+        invalid("border-color", "rgb(1, 2)")
+    );
+    assert_eq!(
+        error(".a { border: 2px solid chartreuse }"),
+        invalid("border", "2px solid chartreuse")
+    );
+}
+
+#[test]
+fn box_shadows_keep_blur_and_color() {
+    let style = style("box-shadow: inset 0 0 12px rgba(0, 0, 0, 0.5); box-shadow: 1px 2px black");
+    assert_eq!(
+        style.inset_shadow,
+        Some(ShadowStyle {
+            color: Color::rgba_u8(0, 0, 0, 127),
+            blur: 12.,
+        })
+    );
+    assert_eq!(
+        style.outline_shadow,
+        Some(ShadowStyle {
+            color: Color::BLACK,
+            blur: 0.,
+        })
+    );
+    assert_eq!(
+        error(".a { box-shadow: 1px black }"),
+        invalid("box-shadow", "1px black")
+    );
+}
+
+#[test]
+fn errors_name_the_offending_part() {
+    assert_eq!(
+        error(".a { border: 1px red }\n.b { outline-width: wide }"),
+        invalid("outline-width", "wide")
+    );
+    assert_eq!(
+        error(".a { border 1px }"),
+        CssError::InvalidDeclaration("border 1px".to_string())
+    );
+    assert_eq!(
+        error(".a, #b { border: 1px }"),
+        CssError::UnsupportedSelector("#b".to_string())
+    );
+    assert_eq!(error(".a { border: 1px"), CssError::UnclosedBlock);
+    assert_eq!(error(".a { border: 1px } }"), CssError::UnclosedBlock);
+}
+
+#[test]
+fn comments_and_unknown_properties_are_ignored() {
+    let stylesheet = BorderStylesheet::parse(
+        "/* panels */ .panel, .card { border: 1px /* thin */ red; padding: 4px }",
+    )
+    .unwrap();
+    let classes: Vec<_> = stylesheet
+        .rules
+        .iter()
+        .map(|rule| rule.class.as_str())
+        .collect();
+    assert_eq!(classes, ["panel", "card"]);
+    assert_eq!(
+        stylesheet.rules[1].style.border,
+        Some(Thickness::All(Val::Px(1.)))
+    );
+}
+
+#[test]
+fn later_rules_override_earlier_ones() {
+    let stylesheet = BorderStylesheet::parse(
+        ".a { border: 1px red } .b { border-color: blue } .c { border: 2px }",
+    )
+    .unwrap();
+    let style = stylesheet.style_for(["a", "b"]);
+    assert_eq!(style.border, Some(Thickness::All(Val::Px(1.))));
+    assert_eq!(style.border_color, Some(Color::BLUE));
+    let style = stylesheet.style_for(["b", "c"]);
+    assert_eq!(style.border, Some(Thickness::All(Val::Px(2.))));
+    assert_eq!(style.border_color, Some(Color::BLUE));
+}
@@ -0,0 +1,119 @@
+//! Property checks of `geometry::border_edges` over generated node sizes and border thicknesses.
+//!
+//! Inputs come from a fixed seed so failures are reproducible, and are multiples of a quarter pixel so areas sum
+//! exactly. Sizes and thicknesses of zero, and thicknesses larger than the node, are generated often.
+
+use bevy::math::{Rect, Vec2};
+use bevy_ui_borders::geometry::border_edges;
+
+const CASES: usize = 10_000;
+
+struct Cases(u64);
+
+impl Cases {
+    fn next(&mut self) -> u64 {
+        // xorshift64
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn length(&mut self, max: u64) -> f32 {
+        match self.next() % 4 {
+            0 => 0.,
+            _ => (self.next() % (4 * max + 1)) as f32 * 0.25,
+        }
+    }
+
+    fn size(&mut self) -> Vec2 {
+        Vec2::new(self.length(200), self.length(200))
+    }
+
+    fn thickness(&mut self) -> [f32; 4] {
+        [(); 4].map(|_| self.length(120))
+    }
+}
+
+fn for_each_case(mut check: impl FnMut(Vec2, [f32; 4], [Option<Rect>; 4], Rect)) {
+    let mut cases = Cases(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..CASES {
+        let size = cases.size();
+        let thickness = cases.thickness();
+        let (edges, inner) = border_edges(size, thickness);
+        check(size, thickness, edges, inner);
+    }
+}
+
+fn area(rect: Rect) -> f32 {
+    let size = rect.size();
+    size.x * size.y
+}
+
+fn contains(outer: Rect, inner: Rect) -> bool {
+    outer.min.x <= inner.min.x
+        && outer.min.y <= inner.min.y
+        && inner.max.x <= outer.max.x
+        && inner.max.y <= outer.max.y
+}
+
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.min.x < b.max.x && b.min.x < a.max.x && a.min.y < b.max.y && b.min.y < a.max.y
+}
+
+#[test]
+fn edges_lie_within_node_bounds() {
+    for_each_case(|size, thickness, edges, inner| {
+        let node = Rect::from_center_size(Vec2::ZERO, size);
+        for edge in edges.into_iter().flatten().chain([inner]) {
+            assert!(
+                contains(node, edge),
+                "{edge:?} outside of node {node:?}, size {size}, thickness {thickness:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn edges_do_not_overlap() {
+    for_each_case(|size, thickness, edges, inner| {
+        let rects: Vec<Rect> = edges.into_iter().flatten().chain([inner]).collect();
+        for (i, a) in rects.iter().enumerate() {
+            for b in &rects[i + 1..] {
+                assert!(
+                    !overlaps(*a, *b),
+                    "{a:?} overlaps {b:?}, size {size}, thickness {thickness:?}"
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn edges_and_inner_cover_node() {
+    for_each_case(|size, thickness, edges, inner| {
+        let covered: f32 = edges.into_iter().flatten().map(area).sum::<f32>() + area(inner);
+        assert_eq!(
+            covered,
+            size.x * size.y,
+            "size {size}, thickness {thickness:?}, edges {edges:?}, inner {inner:?}"
+        );
+    });
+}
+
+#[test]
+fn degenerate_edges_are_none() {
+    for_each_case(|size, thickness, edges, _| {
+        for (edge, &t) in edges.iter().zip(&thickness) {
+            if let Some(edge) = edge {
+                assert!(0. < area(*edge), "{edge:?} has no area");
+            }
+            if t == 0. {
+                assert!(edge.is_none(), "zero thickness gave {edge:?}, size {size}");
+            }
+        }
+        if size.x == 0. || size.y == 0. {
+            assert_eq!(edges, [None; 4], "size {size}, thickness {thickness:?}");
+        }
+    });
+}
@@ -0,0 +1,387 @@
+//! The quads extracted for fills, corners, shadows and the overlays drawn by the plugin.
+
+use bevy::asset::HandleId;
+use bevy::prelude::*;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy::ui::ExtractedUiNode;
+use bevy_ui_borders::test_utils::*;
+use bevy_ui_borders::*;
+
+fn spawn_node(app: &mut App, size: Vec2, border: Val) -> Entity {
+    app.world
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(size.x), Val::Px(size.y)),
+                border: UiRect::all(border),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id()
+}
+
+fn add_image(app: &mut App, width: u32, height: u32) -> Handle<Image> {
+    let image = Image::new_fill(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[255; 4],
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    app.world.resource_mut::<Assets<Image>>().add(image)
+}
+
+/// The area covered by an unrotated quad in window space.
+fn quad_rect(quad: &ExtractedUiNode) -> Rect {
+    Rect::from_center_size(
+        quad.transform.w_axis.truncate().truncate(),
+        quad.rect.size(),
+    )
+}
+
+/// The quads of the top edge of a node at the window's origin with a 2px border, left to right.
+fn top_edge(quads: &[ExtractedUiNode]) -> Vec<Rect> {
+    let mut rects: Vec<Rect> = quads
+        .iter()
+        .map(quad_rect)
+        .filter(|rect| rect.min.y.abs() < EPSILON && (rect.height() - 2.).abs() < EPSILON)
+        .collect();
+    rects.sort_by(|a, b| a.min.x.total_cmp(&b.min.x));
+    rects
+}
+
+#[test]
+fn solid_border_extracts_one_quad_per_edge() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(41., 10.), Val::Px(2.));
+    app.world.entity_mut(node).insert(BorderColor(Color::RED));
+    update(&mut app);
+
+    let quads = extracted_quads(&mut app);
+    assert_eq!(quads.len(), 4);
+    assert!(quads.iter().all(|quad| quad.color == Color::RED));
+    assert_eq!(top_edge(&quads), [Rect::new(2., 0., 39., 2.)]);
+
+    app.world.entity_mut(node).insert(Visibility::Hidden);
+    assert!(extracted_quads(&mut app).is_empty());
+}
+
+#[test]
+fn texture_fill_tiles_follow_tile_mode() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let image = add_image(&mut app, 4, 2);
+    let node = spawn_node(&mut app, Vec2::new(41., 10.), Val::Px(2.));
+
+    // The 37px long top edge is filled with 4px wide tiles
+    let tile_widths = |app: &mut App, tile| {
+        app.world
+            .entity_mut(node)
+            .insert(BorderFill::texture(image.clone(), tile));
+        update(app);
+        let quads = extracted_quads(app);
+        assert!(quads
+            .iter()
+            .all(|quad| quad.image == image && quad.color == Color::WHITE));
+        top_edge(&quads)
+            .into_iter()
+            .map(|rect| rect.width())
+            .collect::<Vec<f32>>()
+    };
+    assert_eq!(tile_widths(&mut app, TileMode::Stretch), [37.]);
+
+    let repeat = tile_widths(&mut app, TileMode::Repeat);
+    assert_eq!(repeat.len(), 10);
+    assert!(repeat[..9].iter().all(|width| (width - 4.).abs() < EPSILON));
+    assert!((repeat[9] - 1.).abs() < EPSILON);
+
+    let round = tile_widths(&mut app, TileMode::Round);
+    assert_eq!(round.len(), 9);
+    assert!(round.iter().all(|width| (width - 37. / 9.).abs() < EPSILON));
+}
+
+#[test]
+fn fills_wait_for_their_image_to_load() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(41., 10.), Val::Px(2.));
+    let loading = Handle::weak(HandleId::random::<Image>());
+    app.world.entity_mut(node).insert((
+        BorderColor(Color::RED),
+        BorderFill::texture(loading, TileMode::Repeat),
+    ));
+    update(&mut app);
+    assert!(extracted_quads(&mut app).is_empty());
+
+    // Without a fill of its own the border is drawn plain until the default fill has loaded
+    app.world.entity_mut(node).remove::<BorderFill>();
+    let loading = Handle::weak(HandleId::random::<Image>());
+    app.insert_resource(DefaultBorderFill::texture(loading, TileMode::Repeat));
+    let quads = extracted_quads(&mut app);
+    assert_eq!(quads.len(), 4);
+    assert!(quads.iter().all(|quad| quad.color == Color::RED));
+}
+
+#[test]
+fn atlas_fill_samples_its_sprite() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let image = add_image(&mut app, 8, 2);
+    let mut atlas = TextureAtlas::new_empty(image.clone(), Vec2::new(8., 2.));
+    atlas.add_texture(Rect::new(0., 0., 4., 2.));
+    let index = atlas.add_texture(Rect::new(4., 0., 8., 2.));
+    let atlas = app.world.resource_mut::<Assets<TextureAtlas>>().add(atlas);
+    let node = spawn_node(&mut app, Vec2::new(41., 10.), Val::Px(2.));
+    app.world
+        .entity_mut(node)
+        .insert(BorderFill::atlas(atlas, index, TileMode::Stretch));
+    update(&mut app);
+
+    let quads = extracted_quads(&mut app);
+    let top = quads
+        .iter()
+        .find(|quad| quad_rect(quad) == Rect::new(2., 0., 39., 2.))
+        .unwrap();
+    assert!(top.image == image);
+    // The right half of the atlas image, scaled to the size of the quad
+    let atlas_size = top.atlas_size.unwrap();
+    assert!((top.rect.min.x / atlas_size.x - 0.5).abs() < EPSILON);
+    assert!((top.rect.max.x / atlas_size.x - 1.).abs() < EPSILON);
+}
+
+#[test]
+fn corner_pieces_are_mirrored_into_each_corner() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let image = add_image(&mut app, 4, 4);
+    let node = spawn_node(&mut app, Vec2::new(40., 20.), Val::Px(2.));
+    app.world.entity_mut(node).insert((
+        BorderColor(Color::RED),
+        BorderCorners::new(image.clone(), Vec2::new(6., 6.)),
+    ));
+    update(&mut app);
+
+    let quads = extracted_quads(&mut app);
+    // The corners are drawn above the border
+    assert_eq!(quads.len(), 8);
+    assert!(quads[..4].iter().all(|quad| quad.color == Color::RED));
+    let corners: Vec<(Rect, bool, bool)> = quads[4..]
+        .iter()
+        .map(|quad| {
+            assert!(quad.image == image);
+            (quad_rect(quad), quad.flip_x, quad.flip_y)
+        })
+        .collect();
+    assert_eq!(
+        corners,
+        [
+            (Rect::new(0., 0., 6., 6.), false, false),
+            (Rect::new(34., 0., 40., 6.), true, false),
+            (Rect::new(0., 14., 6., 20.), false, true),
+            (Rect::new(34., 14., 40., 20.), true, true),
+        ]
+    );
+}
+
+#[test]
+fn shadows_fade_out_from_the_border_box_and_inner_rect() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(40., 20.), Val::Px(2.));
+    app.world.entity_mut(node).insert((
+        BorderColor(Color::RED),
+        OutlineShadow::new(Color::BLACK, 4.),
+    ));
+    update(&mut app);
+
+    let quads = extracted_quads(&mut app);
+    // Four rings of four edges each, drawn below the border
+    assert_eq!(quads.len(), 16 + 4);
+    assert!(quads[16..].iter().all(|quad| quad.color == Color::RED));
+    let node_rect = Rect::new(0., 0., 40., 20.);
+    let bounds = node_rect.inset(4.);
+    for quad in &quads[..16] {
+        let rect = quad_rect(quad);
+        assert!(node_rect.intersect(rect).is_empty() && bounds.contains(rect.center()));
+    }
+    // Each ring is fainter than the last
+    let alphas: Vec<f32> = quads[..16].iter().map(|quad| quad.color.a()).collect();
+    assert!(alphas.windows(2).all(|pair| pair[1] <= pair[0]));
+    assert!((alphas[0] - 0.875 * 0.875).abs() < EPSILON);
+
+    app.world
+        .entity_mut(node)
+        .remove::<OutlineShadow>()
+        .insert(InsetShadow::new(Color::BLACK, 4.));
+    let quads = extracted_quads(&mut app);
+    assert_eq!(quads.len(), 16 + 4);
+    let inner = Rect::new(2., 2., 38., 18.);
+    for quad in &quads[..16] {
+        let rect = quad_rect(quad);
+        assert!(inner.contains(rect.min) && inner.contains(rect.max));
+        assert!(inner.inset(-4.).intersect(rect).is_empty());
+    }
+}
+
+#[test]
+fn shadows_follow_rounded_corners() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(40., 20.), Val::Px(2.));
+    app.world.entity_mut(node).insert((
+        BorderColor(Color::RED),
+        BorderRadius::all(Val::Px(6.)),
+        OutlineShadow::new(Color::BLACK, 4.),
+    ));
+    update(&mut app);
+
+    let shadow_rects = |app: &mut App| {
+        extracted_quads(app)
+            .iter()
+            .filter(|quad| quad.color.r() == 0.)
+            .map(quad_rect)
+            .collect::<Vec<_>>()
+    };
+    let covered = |rects: &[Rect], point: Vec2| rects.iter().any(|rect| rect.contains(point));
+    let rects = shadow_rects(&mut app);
+    // The shadow fills the node's rounded-off corner and its own corner is rounded by the radius plus the blur
+    assert!(covered(&rects, Vec2::new(0.5, 0.5)));
+    assert!(!covered(&rects, Vec2::new(-3.5, -3.5)));
+    assert!(!covered(&rects, Vec2::new(3., 3.)));
+
+    app.world
+        .entity_mut(node)
+        .remove::<OutlineShadow>()
+        .insert(InsetShadow::new(Color::BLACK, 4.));
+    let rects = shadow_rects(&mut app);
+    // The inner edge has a 4px radius, and the shadow stays inside it
+    assert!(covered(&rects, Vec2::new(3., 5.)));
+    assert!(!covered(&rects, Vec2::new(2.5, 2.5)));
+}
+
+#[test]
+fn decoration_rects_are_extracted_in_the_border_layer() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let image = add_image(&mut app, 4, 4);
+    let node = spawn_node(&mut app, Vec2::new(40., 20.), Val::Px(0.));
+    app.world.entity_mut(node).insert((
+        DecorationRects::default().with(DecorationRect::new(
+            Vec2::new(4., 2.),
+            Vec2::new(8., 2.),
+            Color::GREEN,
+        )),
+        OutlineShadow::new(Color::BLACK, 1.),
+        BorderCorners::new(image, Vec2::new(2., 2.)),
+    ));
+    update(&mut app);
+
+    let quads = extracted_quads(&mut app);
+    // Shadow ring below, then the rect, then the corner pieces above
+    assert_eq!(quads.len(), 4 + 1 + 4);
+    assert!(quads[..4].iter().all(|quad| quad.color.a() < 1.));
+    assert_eq!(quads[4].color, Color::GREEN);
+    assert_eq!(quad_rect(&quads[4]), Rect::new(4., 2., 12., 4.));
+    assert!(quads[5..].iter().all(|quad| quad.color == Color::WHITE));
+}
+
+#[test]
+fn sliding_outline_surrounds_its_target() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let a = spawn_node(&mut app, Vec2::new(40., 20.), Val::Px(0.));
+    let b = spawn_node(&mut app, Vec2::new(40., 20.), Val::Px(0.));
+    let mut outline = SlidingOutline::new(2., Color::CYAN);
+    outline.target = Some(a);
+    outline.duration = 0.;
+    let sliding = app.world.spawn(outline).id();
+    update(&mut app);
+
+    let current = |app: &App| {
+        app.world
+            .get::<SlidingOutline>(sliding)
+            .unwrap()
+            .current_rect()
+            .unwrap()
+    };
+    assert_rect_near(current(&app), Rect::new(0., 0., 40., 20.));
+    let quads = extracted_quads(&mut app);
+    assert_eq!(quads.len(), 4);
+    // Drawn above every UI node
+    assert!(quads.iter().all(|quad| quad.stack_index == 2));
+    let bounds = quads
+        .iter()
+        .map(quad_rect)
+        .reduce(|bounds, rect| bounds.union(rect))
+        .unwrap();
+    assert_rect_near(bounds, Rect::new(-2., -2., 42., 22.));
+
+    // A slide long enough that it won't finish during the test
+    let mut outline = app.world.get_mut::<SlidingOutline>(sliding).unwrap();
+    outline.target = Some(b);
+    outline.duration = 1000.;
+    update(&mut app);
+    let rect = current(&app);
+    assert!(0. <= rect.min.x && rect.min.x < 40.);
+    assert_eq!(rect.size(), Vec2::new(40., 20.));
+
+    app.world.get_mut::<SlidingOutline>(sliding).unwrap().target = None;
+    update(&mut app);
+    assert_eq!(
+        app.world
+            .get::<SlidingOutline>(sliding)
+            .unwrap()
+            .current_rect(),
+        None
+    );
+    assert!(extracted_quads(&mut app).is_empty());
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn debug_overlay_outlines_and_fills_boxes() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.add_plugin(DebugOutlinePlugin);
+    let node = app
+        .world
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(40.), Val::Px(20.)),
+                border: UiRect::all(Val::Px(2.)),
+                padding: UiRect::all(Val::Px(3.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id();
+    let colors = DebugBoxColors {
+        border: Color::RED,
+        padding: Color::GREEN,
+        content: Color::BLUE,
+    };
+    app.insert_resource(DebugOutlineSettings {
+        color: DebugOutlineColor::Single(Color::WHITE),
+        boxes: Some(colors),
+        ..Default::default()
+    });
+    update(&mut app);
+
+    let quads = extracted_quads(&mut app);
+    let count = |color| quads.iter().filter(|quad| quad.color == color).count();
+    assert_eq!(
+        (
+            count(Color::RED),
+            count(Color::GREEN),
+            count(Color::BLUE),
+            count(Color::WHITE)
+        ),
+        (4, 4, 1, 4)
+    );
+    assert!(quads.iter().all(|quad| quad.stack_index == 1));
+    let content = quads.iter().find(|quad| quad.color == Color::BLUE).unwrap();
+    assert_eq!(quad_rect(content), Rect::new(5., 5., 35., 15.));
+
+    // The overlay follows the node's visibility and the settings
+    app.world.entity_mut(node).insert(Visibility::Hidden);
+    assert!(extracted_quads(&mut app).is_empty());
+    app.world.entity_mut(node).insert(Visibility::Inherited);
+    app.world.resource_mut::<DebugOutlineSettings>().enabled = false;
+    assert!(extracted_quads(&mut app).is_empty());
+}
@@ -0,0 +1,2423 @@
+use bevy::ecs::system::CommandQueue;
+use bevy::prelude::*;
+use bevy_ui_borders::test_utils::*;
+use bevy_ui_borders::*;
+
+fn spawn_node(app: &mut App, size: Vec2, border: UiRect) -> Entity {
+    app.world
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Px(size.x), Val::Px(size.y)),
+                    border,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            BorderColor(Color::WHITE),
+        ))
+        .id()
+}
+
+#[test]
+fn border_edges_surround_inner_rect() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect::new(Val::Px(1.), Val::Px(2.), Val::Px(3.), Val::Px(4.)),
+    );
+    update(&mut app);
+
+    assert_eq!(node_size(&app, node), Vec2::new(100., 50.));
+    let border = calculated_border(&app, node);
+    assert_eq!(border.resolved, [1., 2., 3., 4.]);
+    assert_rect_near(border.inner, Rect::new(-49., -22., 48., 21.));
+    assert_edges_near(
+        border.edges,
+        [
+            Some(Rect::new(-50., -25., -49., 25.)),
+            Some(Rect::new(48., -25., 50., 25.)),
+            Some(Rect::new(-49., -25., 48., -22.)),
+            Some(Rect::new(-49., 21., 48., 25.)),
+        ],
+    );
+}
+
+#[test]
+fn zero_thickness_edges_are_none() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect {
+            left: Val::Px(5.),
+            ..Default::default()
+        },
+    );
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    assert!(border.edges[0].is_some());
+    assert_eq!(border.edges[1..], [None, None, None]);
+}
+
+#[test]
+fn non_finite_thickness_resolves_to_zero() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect::new(
+            Val::Px(f32::NAN),
+            Val::Px(2.),
+            Val::Px(f32::INFINITY),
+            Val::Percent(f32::NEG_INFINITY),
+        ),
+    );
+    app.world.entity_mut(node).insert(Outline::new(
+        Color::WHITE,
+        UiRect::new(
+            Val::Percent(f32::NAN),
+            Val::Px(f32::INFINITY),
+            Val::Px(3.),
+            Val::Px(f32::NAN),
+        ),
+    ));
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    assert_eq!(border.resolved, [0., 2., 0., 0.]);
+    assert!(border.inner.min.is_finite() && border.inner.max.is_finite());
+    assert_eq!(border.edges[0], None);
+    assert!(border.edges[1].is_some());
+    assert_eq!(border.edges[2..], [None, None]);
+
+    let outline = calculated_outline(&app, node);
+    assert_eq!(outline.edges[0..2], [None, None]);
+    assert!(outline.edges[2].is_some_and(|edge| edge.min.is_finite() && edge.max.is_finite()));
+    assert_eq!(outline.edges[3], None);
+}
+
+#[test]
+fn huge_shapes_have_bounded_strip_counts() {
+    use bevy_ui_borders::geometry::*;
+
+    // A `Val::Px(1e9)` outline around an elliptical node
+    let outer = Rect::new(-1e9, -1e9, 1e9, 1e9);
+    let inner = Rect::new(-50., -25., 50., 25.);
+    let strips = ellipse_ring_strips(outer, inner);
+    assert!(!strips.is_empty());
+    assert!(strips.len() <= 2 * MAX_BAND_ROWS);
+    assert!(strips
+        .iter()
+        .all(|strip| strip.min.y < strip.max.y && outer.contains(strip.center())));
+
+    // Coordinates where adding a pixel doesn't change the value
+    let far = Rect::new(0., 1e9, 40., 1e9 + 256.);
+    assert!(ellipse_ring_strips(far, Rect::default()).len() <= MAX_BAND_ROWS);
+
+    let rounded = RoundedRect::new(outer, [1e9; 4]);
+    assert!(rounded.strips().len() <= 2 * MAX_BAND_ROWS + 1);
+    assert!(rounded.ring_strips(&RoundedRect::new(inner, [0.; 4])).len() <= 8 * MAX_BAND_ROWS);
+}
+
+#[test]
+fn negative_thickness_is_clamped_to_zero() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect::new(Val::Px(-5.), Val::Px(2.), Val::Percent(-10.), Val::Px(4.)),
+    );
+    app.world.entity_mut(node).insert(Outline::new(
+        Color::WHITE,
+        UiRect::new(Val::Px(-3.), Val::Px(3.), Val::Px(3.), Val::Px(3.)),
+    ));
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    assert_eq!(border.resolved, [0., 2., 0., 4.]);
+    assert_rect_near(border.inner, Rect::new(-50., -25., 48., 21.));
+    assert_eq!([border.edges[0], border.edges[2]], [None, None]);
+    assert_eq!(calculated_outline(&app, node).edges[0], None);
+}
+
+#[test]
+fn percent_border_resolves_against_parent_width() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let parent = spawn_node(&mut app, Vec2::new(200., 200.), UiRect::default());
+    let child = spawn_node(
+        &mut app,
+        Vec2::new(100., 100.),
+        UiRect::all(Val::Percent(5.)),
+    );
+    app.world.entity_mut(parent).push_children(&[child]);
+    update(&mut app);
+
+    assert_rect_near(
+        calculated_border(&app, child).inner,
+        Rect::new(-40., -40., 40., 40.),
+    );
+}
+
+#[test]
+fn parent_width_fallback_resolves_root_percentages() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 100.),
+        UiRect::all(Val::Percent(5.)),
+    );
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::WHITE, Val::Percent(1.)));
+    update(&mut app);
+    // Root nodes resolve percentages against the window's width by default
+    assert_eq!(calculated_border(&app, node).resolved, [40.; 4]);
+    assert_rect_near(
+        calculated_outline(&app, node).edges[0].unwrap(),
+        Rect::new(-58., -58., -50., 58.),
+    );
+
+    *app.world.resource_mut::<ParentWidthFallback>() = ParentWidthFallback::Warn;
+    update(&mut app);
+    assert_eq!(calculated_border(&app, node).resolved, [0.; 4]);
+    assert_eq!(calculated_outline(&app, node).edges, [None; 4]);
+
+    *app.world.resource_mut::<ParentWidthFallback>() = ParentWidthFallback::SelfWidth;
+    update(&mut app);
+    assert_eq!(calculated_border(&app, node).resolved, [5.; 4]);
+    assert_rect_near(
+        calculated_outline(&app, node).edges[0].unwrap(),
+        Rect::new(-51., -51., -50., 51.),
+    );
+
+    // Resizing the window changes the width root nodes resolve against
+    *app.world.resource_mut::<ParentWidthFallback>() = ParentWidthFallback::Viewport;
+    let mut window_query = app.world.query::<&mut Window>();
+    window_query
+        .single_mut(&mut app.world)
+        .resolution
+        .set(400., 300.);
+    update(&mut app);
+    assert_eq!(calculated_border(&app, node).resolved, [20.; 4]);
+}
+
+#[test]
+fn outline_is_drawn_outside_node() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::WHITE, Val::Px(10.)));
+    update(&mut app);
+
+    assert_edges_near(
+        calculated_outline(&app, node).edges,
+        [
+            Some(Rect::new(-60., -35., -50., 35.)),
+            Some(Rect::new(50., -35., 60., 35.)),
+            Some(Rect::new(-50., -35., 50., -25.)),
+            Some(Rect::new(-50., 25., 50., 35.)),
+        ],
+    );
+}
+
+#[test]
+fn outline_offset_moves_outline_away_from_node() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world.entity_mut(node).insert((
+        Outline::all(Color::WHITE, Val::Px(10.)),
+        OutlineOffset(Val::Px(5.)),
+    ));
+    update(&mut app);
+    assert_edges_near(
+        calculated_outline(&app, node).edges,
+        [
+            Some(Rect::new(-65., -40., -55., 40.)),
+            Some(Rect::new(55., -40., 65., 40.)),
+            Some(Rect::new(-55., -40., 55., -30.)),
+            Some(Rect::new(-55., 30., 55., 40.)),
+        ],
+    );
+
+    // Negative offsets draw the outline inside the node, at most as far as its center
+    app.world
+        .entity_mut(node)
+        .insert(OutlineOffset(Val::Px(-10.)));
+    app.update();
+    let outline = calculated_outline(&app, node);
+    assert_rect_near(
+        outline.inner_rect(Vec2::new(100., 50.)),
+        Rect::new(-40., -15., 40., 15.),
+    );
+    assert_rect_near(outline.edges[0].unwrap(), Rect::new(-50., -25., -40., 25.));
+    app.world
+        .entity_mut(node)
+        .insert(OutlineOffset(Val::Px(-100.)));
+    app.update();
+    assert_eq!(calculated_outline(&app, node).offset, -25.);
+
+    app.world.entity_mut(node).insert(BorderAnimation::new(
+        BorderProperty::OutlineOffset(Val::Px(5.)),
+        0.,
+        Easing::Linear,
+    ));
+    update(&mut app);
+    assert_eq!(calculated_outline(&app, node).offset, 5.);
+
+    app.world.entity_mut(node).remove::<OutlineOffset>();
+    app.update();
+    assert_edges_near(
+        calculated_outline(&app, node).edges,
+        [
+            Some(Rect::new(-60., -35., -50., 35.)),
+            Some(Rect::new(50., -35., 60., 35.)),
+            Some(Rect::new(-50., -35., 50., -25.)),
+            Some(Rect::new(-50., 25., 50., 35.)),
+        ],
+    );
+}
+
+#[test]
+fn outline_geometry_follows_changes() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::WHITE, Val::Px(10.)));
+    update(&mut app);
+
+    app.world.get_mut::<Outline>(node).unwrap().thickness = UiRect::default();
+    app.update();
+
+    assert_edges_near(calculated_outline(&app, node).edges, [None; 4]);
+}
+
+#[test]
+fn border_component_sets_style() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world
+        .entity_mut(node)
+        .insert(Border::all(Val::Px(5.), Color::RED));
+    update(&mut app);
+
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+    assert_rect_near(
+        calculated_border(&app, node).inner,
+        Rect::new(-45., -20., 45., 20.),
+    );
+
+    // Square borders don't add a radius
+    assert!(app.world.get::<BorderRadius>(node).is_none());
+
+    app.world.get_mut::<Border>(node).unwrap().style = BorderStyle::None;
+    app.update();
+
+    assert_edges_near(calculated_border(&app, node).edges, [None; 4]);
+}
+
+#[test]
+fn border_component_syncs_radius() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    let rounded = BorderRadius::all(Val::Px(8.));
+    app.world
+        .entity_mut(node)
+        .insert(Border::all(Val::Px(5.), Color::RED).with_radius(rounded));
+    update(&mut app);
+    assert_eq!(*app.world.get::<BorderRadius>(node).unwrap(), rounded);
+
+    app.world.get_mut::<Border>(node).unwrap().radius = BorderRadius::default();
+    update(&mut app);
+    assert_eq!(
+        *app.world.get::<BorderRadius>(node).unwrap(),
+        BorderRadius::default()
+    );
+
+    // Radius animations write to the border so it doesn't overwrite them
+    app.world.entity_mut(node).insert(BorderAnimation::new(
+        BorderProperty::Radius(rounded),
+        0.,
+        Easing::Linear,
+    ));
+    update(&mut app);
+    assert_eq!(app.world.get::<Border>(node).unwrap().radius, rounded);
+    assert_eq!(*app.world.get::<BorderRadius>(node).unwrap(), rounded);
+}
+
+#[test]
+fn outline_relative_to_own_min_dimension() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world.entity_mut(node).insert((
+        Outline::all(Color::WHITE, Val::Percent(10.)),
+        OutlineRelativeTo::SelfMinDimension,
+    ));
+    update(&mut app);
+
+    assert_rect_near(
+        calculated_outline(&app, node).edges[0].unwrap(),
+        Rect::new(-55., -30., -50., 30.),
+    );
+}
+
+#[test]
+fn root_border_relative_to_viewport() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect::all(Val::Percent(1.)),
+    );
+    app.world
+        .entity_mut(node)
+        .insert(BorderRelativeTo::Viewport);
+    update(&mut app);
+
+    assert_rect_near(
+        calculated_border(&app, node).inner,
+        Rect::new(-42., -17., 42., 17.),
+    );
+}
+
+#[test]
+fn thickness_clamp_keeps_zero_edges_undrawn() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect {
+            left: Val::Px(1.),
+            right: Val::Px(40.),
+            ..Default::default()
+        },
+    );
+    app.world
+        .entity_mut(node)
+        .insert(BorderThicknessClamp::new(2., 10.));
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    assert_rect_near(border.inner, Rect::new(-48., -25., 40., 25.));
+    assert_eq!(border.edges[2..], [None, None]);
+}
+
+#[test]
+fn rounding_applies_to_resolved_thickness() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.4)));
+    app.insert_resource(BorderRounding::Ceil);
+    update(&mut app);
+
+    assert_rect_near(
+        calculated_border(&app, node).inner,
+        Rect::new(-47., -22., 47., 22.),
+    );
+
+    app.insert_resource(BorderRounding::Floor);
+    app.update();
+
+    assert_rect_near(
+        calculated_border(&app, node).inner,
+        Rect::new(-48., -23., 48., 23.),
+    );
+}
+
+#[test]
+fn rounding_uses_window_scale_factor_only() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.4)));
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::RED, Val::Px(2.4)));
+    app.insert_resource(BorderRounding::Ceil);
+    app.insert_resource(UiScale { scale: 2. });
+    update(&mut app);
+
+    // Whole physical pixels at a window scale factor of 1, however the UI is scaled
+    assert_eq!(calculated_border(&app, node).resolved, [3.; 4]);
+    let left = calculated_outline(&app, node).edges[0].unwrap();
+    assert!((left.width() - 3.).abs() < EPSILON);
+
+    app.world
+        .query::<&mut Window>()
+        .single_mut(&mut app.world)
+        .resolution
+        .set_scale_factor_override(Some(2.));
+    update(&mut app);
+    assert_eq!(calculated_border(&app, node).resolved, [2.5; 4]);
+}
+
+#[test]
+fn recalculation_request_refreshes_percent_border() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let parent = spawn_node(&mut app, Vec2::new(200., 200.), UiRect::default());
+    let child = spawn_node(
+        &mut app,
+        Vec2::new(100., 100.),
+        UiRect::all(Val::Percent(5.)),
+    );
+    app.world.entity_mut(parent).push_children(&[child]);
+    update(&mut app);
+
+    app.world.get_mut::<Style>(parent).unwrap().size.width = Val::Px(400.);
+    app.world
+        .entity_mut(child)
+        .insert(RequestBorderRecalculation);
+    app.update();
+
+    assert_rect_near(
+        calculated_border(&app, child).inner,
+        Rect::new(-30., -30., 30., 30.),
+    );
+    assert!(app.world.get::<RequestBorderRecalculation>(child).is_none());
+}
+
+#[test]
+fn percent_border_follows_parent_resize() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let parent = spawn_node(&mut app, Vec2::new(200., 200.), UiRect::default());
+    let child = spawn_node(
+        &mut app,
+        Vec2::new(100., 100.),
+        UiRect::all(Val::Percent(5.)),
+    );
+    app.world.entity_mut(parent).push_children(&[child]);
+    update(&mut app);
+
+    app.world.get_mut::<Style>(parent).unwrap().size.width = Val::Px(400.);
+    app.update();
+
+    assert_rect_near(
+        calculated_border(&app, child).inner,
+        Rect::new(-30., -30., 30., 30.),
+    );
+}
+
+#[test]
+fn removing_border_color_removes_geometry() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(5.)));
+    update(&mut app);
+
+    app.world.entity_mut(node).remove::<BorderColor>();
+    update(&mut app);
+
+    assert!(app.world.get::<CalculatedBorder>(node).is_none());
+}
+
+#[test]
+fn remove_border_and_outline_commands() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(5.)));
+    app.world.entity_mut(node).insert((
+        Outline::all(Color::WHITE, Val::Px(2.)),
+        OutlineOffset(Val::Px(2.)),
+        OutlineShape::Ellipse,
+        FocusOutline::new(Color::RED),
+        BorderSpans::default(),
+        BorderShake::new(6., 12., 1000.),
+        BorderColorRef::new("accent"),
+        BorderRadius::all(Val::Px(4.)),
+        FillTransform::outward(),
+    ));
+    update(&mut app);
+
+    app.add_system(move |mut commands: Commands| {
+        commands.entity(node).remove_border();
+    });
+    update(&mut app);
+
+    let entity = app.world.entity(node);
+    assert!(!entity.contains::<BorderColor>());
+    assert!(!entity.contains::<BorderColorRef>());
+    assert!(!entity.contains::<BorderSpans>());
+    assert!(!entity.contains::<BorderShake>());
+    assert!(!entity.contains::<CalculatedBorder>());
+    assert_eq!(
+        entity.get::<Style>().unwrap().border,
+        UiRect::all(Val::Px(0.))
+    );
+    // The outline keeps the components it shares with the border
+    assert!(entity.contains::<CalculatedOutline>());
+    assert!(entity.contains::<BorderRadius>());
+    assert!(entity.contains::<FillTransform>());
+
+    app.add_system(move |mut commands: Commands| {
+        commands.entity(node).remove_outline();
+    });
+    update(&mut app);
+
+    let entity = app.world.entity(node);
+    assert!(!entity.contains::<Outline>());
+    assert!(!entity.contains::<OutlineOffset>());
+    assert!(!entity.contains::<OutlineShape>());
+    assert!(!entity.contains::<FocusOutline>());
+    assert!(!entity.contains::<CalculatedOutline>());
+    assert!(!entity.contains::<BorderRadius>());
+    assert!(!entity.contains::<FillTransform>());
+}
+
+#[test]
+fn close_button_despawns_window_frame() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let mut queue = CommandQueue::default();
+    let parts = spawn_window_frame(
+        &mut Commands::new(&mut queue, &app.world),
+        WindowFrameBundle::new(Vec2::new(10., 10.), Vec2::new(200., 100.)),
+        20.,
+        Color::GRAY,
+    );
+    queue.apply(&mut app.world);
+    let button = app
+        .world
+        .spawn((Interaction::default(), WindowCloseButton))
+        .id();
+    app.world
+        .entity_mut(parts.close_button_slot)
+        .push_children(&[button]);
+    update(&mut app);
+
+    assert_eq!(node_size(&app, parts.frame), Vec2::new(200., 100.));
+
+    *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Clicked;
+    app.update();
+
+    assert!(app.world.get_entity(parts.frame).is_none());
+    assert!(app.world.get_entity(button).is_none());
+}
+
+#[test]
+fn tooltip_tail_points_at_anchor() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let anchor = app
+        .world
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Px(120.), Val::Auto, Val::Px(200.), Val::Auto),
+                size: Size::new(Val::Px(20.), Val::Px(20.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id();
+    let tooltip = app
+        .world
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect::new(Val::Px(100.), Val::Auto, Val::Px(100.), Val::Auto),
+                    size: Size::new(Val::Px(100.), Val::Px(40.)),
+                    ..Default::default()
+                },
+                background_color: Color::NONE.into(),
+                ..Default::default()
+            },
+            TooltipFrame::new(anchor, TailSide::Bottom),
+        ))
+        .id();
+    update(&mut app);
+
+    // The anchor's center is 20 pixels left of the tooltip's center
+    let frame = app.world.get::<TooltipFrame>(tooltip).unwrap();
+    assert!((frame.tail_offset() + 20.).abs() < EPSILON);
+    assert!((frame.tail_tip(Vec2::new(100., 40.)) - Vec2::new(-20., 26.)).length() < EPSILON);
+
+    // The tail stops short of the rounded corners
+    app.world.get_mut::<Style>(anchor).unwrap().position.left = Val::Px(0.);
+    app.update();
+    let frame = app.world.get::<TooltipFrame>(tooltip).unwrap();
+    assert!((frame.tail_offset() + 40.).abs() < EPSILON);
+}
+
+#[test]
+fn border_notch_stays_within_its_edge() {
+    let size = Vec2::new(100., 40.);
+    let notch = BorderNotch::new(TailSide::Bottom, 0.25, 16., 12.);
+    assert!((notch.tip(size) - Vec2::new(-25., 32.)).length() < EPSILON);
+
+    let notch = BorderNotch::new(TailSide::Left, 0., 16., 12.);
+    assert!((notch.tip(size) - Vec2::new(-62., -12.)).length() < EPSILON);
+}
+
+#[test]
+fn corner_badge_centers_on_border_corner() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(4.)));
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    let context = DecorationContext {
+        entity: node,
+        size: Vec2::new(100., 50.),
+        parent_width: 800.,
+        border: Some(&border),
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let badge = CornerBadge::new(BadgeCorner::TopRight, 5., Color::RED);
+    assert!((badge.center(&context) - Vec2::new(48., -23.)).length() < EPSILON);
+
+    let mut quads = Vec::new();
+    badge.decorate(&context, &mut quads);
+    let bounds = quads
+        .iter()
+        .fold(quads[0].rect, |bounds, quad| bounds.union(quad.rect));
+    assert!((bounds.min.y + 28.).abs() < EPSILON && (bounds.max.y + 18.).abs() < EPSILON);
+    assert!(43. <= bounds.min.x && bounds.max.x <= 53.);
+}
+
+#[test]
+fn flash_border_restores_colors() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(4.)));
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::BLUE, Val::Px(2.)));
+    update(&mut app);
+
+    app.add_system(move |mut commands: Commands, mut done: Local<bool>| {
+        if !std::mem::replace(&mut *done, true) {
+            commands.entity(node).flash_border(Color::RED, 60.);
+        }
+    });
+    update(&mut app);
+
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::RED);
+
+    app.world.get_mut::<BorderFlash>(node).unwrap().duration = 0.;
+    app.update();
+
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::WHITE);
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::BLUE);
+    assert!(app.world.get::<BorderFlash>(node).is_none());
+    let events = app.world.resource::<Events<BorderFlashFinished>>();
+    assert_eq!(
+        events
+            .get_reader()
+            .iter(events)
+            .map(|event| event.entity)
+            .collect::<Vec<_>>(),
+        vec![node]
+    );
+}
+
+#[test]
+fn border_shake_decays_and_leaves_layout_alone() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(4.)));
+    update(&mut app);
+    let before = calculated_border(&app, node);
+
+    let shake = BorderShake {
+        elapsed: 0.02,
+        ..BorderShake::new(10., 12.5, 0.1)
+    };
+    // A quarter of the way through an oscillation, a fifth of the way through the shake
+    assert!((shake.offset() - Vec2::new(6.4, 0.)).length() < EPSILON);
+    assert_eq!(
+        BorderShake {
+            elapsed: 0.1,
+            ..shake
+        }
+        .offset(),
+        Vec2::ZERO
+    );
+
+    app.world.entity_mut(node).insert(shake);
+    app.update();
+    assert_edges_near(calculated_border(&app, node).edges, before.edges);
+}
+
+#[test]
+fn selection_group_outlines_selected_members() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let group = spawn_node(&mut app, Vec2::new(400., 100.), UiRect::default());
+    let a = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    let b = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    app.world
+        .entity_mut(b)
+        .insert(Outline::all(Color::BLUE, Val::Px(1.)));
+    app.world
+        .entity_mut(group)
+        .insert(SelectionGroup {
+            multi_select: false,
+        })
+        .push_children(&[a, b]);
+    update(&mut app);
+
+    app.world.entity_mut(a).insert(Selected);
+    update(&mut app);
+    let style = *app.world.resource::<SelectionGroupStyle>();
+    assert_eq!(app.world.get::<OutlineColor>(a).unwrap().0, style.color);
+    assert_eq!(
+        app.world.get::<Outline>(a).unwrap().thickness,
+        style.thickness
+    );
+
+    // Selecting another member of a single-select group deselects the first
+    app.world.entity_mut(b).insert(Selected);
+    update(&mut app);
+    assert!(app.world.get::<Selected>(a).is_none());
+    assert!(app.world.get::<Outline>(a).is_none());
+    assert_eq!(app.world.get::<OutlineColor>(b).unwrap().0, style.color);
+
+    // Deselecting restores the member's own outline
+    app.world.entity_mut(b).remove::<Selected>();
+    update(&mut app);
+    assert_eq!(app.world.get::<OutlineColor>(b).unwrap().0, Color::BLUE);
+    assert_eq!(
+        app.world.get::<Outline>(b).unwrap().thickness,
+        UiRect::all(Val::Px(1.))
+    );
+}
+
+#[test]
+fn drop_target_highlight_follows_drag_state() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(20., 10.), UiRect::default());
+    let mut highlight = DropTargetHighlight::new(Color::YELLOW, 2.);
+    highlight.speed = 0.;
+    app.world
+        .entity_mut(node)
+        .insert((Interaction::default(), highlight));
+    update(&mut app);
+    assert!(!app
+        .world
+        .get::<DropTargetHighlight>(node)
+        .unwrap()
+        .is_shown());
+
+    app.world.resource_mut::<DragState>().start();
+    app.world.resource_mut::<DragState>().target = Some(node);
+    app.update();
+
+    let highlight = app.world.get::<DropTargetHighlight>(node).unwrap();
+    assert!(highlight.is_shown());
+    let context = DecorationContext {
+        entity: node,
+        size: Vec2::new(20., 10.),
+        parent_width: 800.,
+        border: None,
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let mut quads = Vec::new();
+    highlight.decorate(&context, &mut quads);
+    assert_rect_near(quads[0].rect, Rect::new(-12., -7., -6., -5.));
+    let outer = Rect::new(-12., -7., 12., 7.);
+    assert!(quads
+        .iter()
+        .all(|quad| outer.contains(quad.rect.min) && outer.contains(quad.rect.max)));
+
+    app.world.resource_mut::<DragState>().end();
+    app.update();
+    assert!(!app
+        .world
+        .get::<DropTargetHighlight>(node)
+        .unwrap()
+        .is_shown());
+}
+
+#[test]
+fn pick_highlight_restores_own_style() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert((
+        Interaction::default(),
+        Outline::all(Color::NONE, Val::Px(0.)),
+        PickHighlight::new(
+            HighlightStyle::border(Color::RED),
+            HighlightStyle::outline(Color::GOLD, UiRect::all(Val::Px(3.))),
+            HighlightStyle::default(),
+        ),
+    ));
+    update(&mut app);
+
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::Clicked;
+    app.update();
+    assert_eq!(
+        app.world.get::<PickHighlight>(node).unwrap().state(),
+        PickState::Pressed
+    );
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::WHITE);
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::GOLD);
+
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+    app.update();
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::NONE);
+    assert_eq!(
+        app.world.get::<Outline>(node).unwrap().thickness,
+        UiRect::all(Val::Px(0.))
+    );
+}
+
+#[test]
+fn global_geometry_is_in_window_space() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(5.)));
+    app.world.entity_mut(node).insert((
+        Outline::all(Color::WHITE, Val::Px(10.)),
+        GlobalBorderGeometry::default(),
+    ));
+    update(&mut app);
+
+    let geometry = *app.world.get::<GlobalBorderGeometry>(node).unwrap();
+    assert_rect_near(geometry.node, Rect::new(0., 0., 100., 50.));
+    assert_rect_near(geometry.inner, Rect::new(5., 5., 95., 45.));
+    assert_rect_near(geometry.outer, Rect::new(-10., -10., 110., 60.));
+    assert_rect_near(
+        geometry.border_edges[0].unwrap(),
+        Rect::new(0., 0., 5., 50.),
+    );
+}
+
+#[test]
+fn diagnostics_count_bordered_nodes() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.add_plugin(bevy::diagnostic::DiagnosticsPlugin)
+        .add_plugin(BorderDiagnosticsPlugin);
+    spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    let outlined = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world
+        .entity_mut(outlined)
+        .insert(Outline::all(Color::WHITE, Val::Px(2.)));
+    update(&mut app);
+    app.update();
+
+    let diagnostics = app.world.resource::<bevy::diagnostic::Diagnostics>();
+    let value = |id| {
+        diagnostics
+            .get(id)
+            .and_then(|diagnostic| diagnostic.value())
+    };
+    assert_eq!(value(BorderDiagnosticsPlugin::BORDERED_NODES), Some(2.));
+    assert_eq!(value(BorderDiagnosticsPlugin::OUTLINED_NODES), Some(1.));
+    assert_eq!(value(BorderDiagnosticsPlugin::EXTRACTED_QUADS), Some(0.));
+}
+
+#[test]
+fn rounded_rect_spans_follow_corners() {
+    let shape = RoundedRect::new(Rect::new(0., 0., 100., 50.), [10., 0., 0., 20.]);
+    assert_eq!(shape.span_at(25.), Some((0., 100.)));
+    let (left, right) = shape.span_at(0.5).unwrap();
+    assert!(5. < left && left < 10., "{left}");
+    assert_eq!(right, 100.);
+    assert_eq!(shape.span_at(51.), None);
+    assert_eq!(
+        BorderRadius::all(Val::Percent(100.)).resolve(Vec2::new(100., 50.)),
+        [25.; 4]
+    );
+
+    // A ring with straight sides is drawn as a single strip for each side, bands with curves as two per row
+    let outer = RoundedRect::new(Rect::new(0., 0., 40., 40.), [10.; 4]);
+    let inner = outer.inset([2.; 4]);
+    assert_eq!(inner.radii, [8.; 4]);
+    let strips = outer.ring_strips(&inner);
+    assert!(strips.iter().all(|strip| !strip.is_empty()));
+    assert!(strips
+        .iter()
+        .any(|strip| strip.min.y == 10. && strip.max.y == 30.));
+}
+
+#[test]
+fn rounded_overflow_clips_descendants() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let child = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::all(Val::Px(1.)));
+    let grandchild = spawn_node(&mut app, Vec2::new(10., 10.), UiRect::default());
+    app.world.entity_mut(child).add_child(grandchild);
+    let parent = spawn_node(&mut app, Vec2::new(100., 100.), UiRect::all(Val::Px(4.)));
+    app.world
+        .entity_mut(parent)
+        .insert(BorderRadius::all(Val::Px(20.)))
+        .add_child(child);
+    update(&mut app);
+
+    // Clipping needs both a radius and hidden overflow
+    assert!(app.world.get::<RoundedClip>(child).is_none());
+
+    app.world.get_mut::<Style>(parent).unwrap().overflow = Overflow::Hidden;
+    update(&mut app);
+    for entity in [child, grandchild] {
+        let clip = app.world.get::<RoundedClip>(entity).unwrap();
+        assert_eq!(clip.shapes.len(), 1);
+        assert_rect_near(clip.shapes[0].rect, Rect::new(4., 4., 96., 96.));
+        assert_eq!(clip.shapes[0].radii, [16.; 4]);
+    }
+    assert!(app.world.get::<RoundedClip>(parent).is_none());
+
+    app.world.entity_mut(parent).remove::<BorderRadius>();
+    update(&mut app);
+    assert!(app.world.get::<RoundedClip>(child).is_none());
+}
+
+#[test]
+fn background_gradient_bands_follow_rounded_corners() {
+    let radius = BorderRadius::all(Val::Px(4.));
+    let mut context = DecorationContext {
+        entity: Entity::PLACEHOLDER,
+        size: Vec2::new(20., 10.),
+        parent_width: 800.,
+        border: None,
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let gradient = BackgroundGradient::vertical(Color::WHITE, Color::BLACK);
+    let mut quads = Vec::new();
+    gradient.decorate(&context, &mut quads);
+    // One band per logical pixel, each the full width of the node
+    assert_eq!(quads.len(), 10);
+    assert_rect_near(quads[0].rect, Rect::new(-10., -5., 10., -4.));
+    assert_eq!(quads[0].color, gradient.color_at(0.05));
+
+    context.radius = Some(&radius);
+    quads.clear();
+    gradient.decorate(&context, &mut quads);
+    assert!(-10. < quads[0].rect.min.x);
+    assert!(quads
+        .iter()
+        .all(|quad| context.node_rect().contains(quad.rect.min)
+            && context.node_rect().contains(quad.rect.max)));
+}
+
+#[test]
+fn border_color_ref_follows_palette() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.insert_resource(BorderPalette::default().with("accent", Color::RED));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world
+        .entity_mut(node)
+        .insert(BorderColorRef::new("accent"));
+    app.update();
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+
+    app.world
+        .resource_mut::<BorderPalette>()
+        .insert("accent", Color::BLUE);
+    app.update();
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::BLUE);
+
+    // Unknown names leave the color alone
+    *app.world.get_mut::<BorderColorRef>(node).unwrap() = BorderColorRef::new("missing");
+    app.update();
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::BLUE);
+}
+
+#[test]
+fn theme_switch_swaps_palette_and_keeps_contrast() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.insert_resource(BorderThemes::new(
+        BorderPalette::default().with("edge", Color::BLACK),
+        BorderPalette::default().with("edge", Color::WHITE),
+    ));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert((
+        BorderColorRef::new("edge"),
+        OutlineColorRef::new("edge"),
+        BackgroundColor(Color::WHITE),
+        ContrastBorder::default(),
+    ));
+    app.update();
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::BLACK);
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::BLACK);
+
+    // The dark palette's white border disappears against the white background, so it is darkened
+    app.world.resource_mut::<ThemeMode>().toggle();
+    app.update();
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::WHITE);
+    let border = app.world.get::<BorderColor>(node).unwrap().0;
+    let ratio = contrast_ratio(border, Color::WHITE);
+    assert!((3. ..3.1).contains(&ratio), "{ratio}");
+}
+
+#[test]
+fn hit_slop_widens_border_edges() {
+    let rect = Rect::new(0., 0., 100., 50.);
+    let thickness = [1., 1., 2., 2.];
+    assert_eq!(
+        border_edges_at(rect, thickness, Vec2::new(4., 25.), 0.),
+        [false; 4]
+    );
+    assert_eq!(
+        border_edges_at(rect, thickness, Vec2::new(4., 25.), 4.),
+        [true, false, false, false]
+    );
+    // The slop extends outside the node too, and both edges are hit near a corner
+    assert_eq!(
+        border_edges_at(rect, thickness, Vec2::new(-3., 53.), 4.),
+        [true, false, false, true]
+    );
+    assert_eq!(
+        border_edges_at(rect, thickness, Vec2::new(-5., 25.), 4.),
+        [false; 4]
+    );
+}
+
+#[test]
+fn outline_scale_multiplies_outline_thickness() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::WHITE, Val::Px(3.)));
+    update(&mut app);
+    assert_rect_near(
+        calculated_outline(&app, node).edges[0].unwrap(),
+        Rect::new(-53., -28., -50., 28.),
+    );
+
+    app.insert_resource(OutlineScale(2.));
+    app.update();
+    assert_rect_near(
+        calculated_outline(&app, node).edges[0].unwrap(),
+        Rect::new(-56., -31., -50., 31.),
+    );
+    // Borders take up space in the layout so they aren't scaled
+    assert_eq!(calculated_border(&app, node).resolved, [2.; 4]);
+}
+
+#[test]
+fn border_mesh_has_a_quad_per_edge() {
+    use bevy::render::mesh::VertexAttributeValues;
+
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(5.)));
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    let mesh = border_mesh(Vec2::new(100., 50.), &border, None);
+    assert_eq!(mesh.count_vertices(), 16);
+    assert_eq!(mesh.indices().unwrap().len(), 24);
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("mesh has no positions");
+    };
+    // y points up in the mesh
+    assert!(positions.contains(&[-50., 25., 0.]));
+
+    let rounded = border_mesh(
+        Vec2::new(100., 50.),
+        &border,
+        Some(&BorderRadius::all(Val::Px(10.))),
+    );
+    assert!(16 < rounded.count_vertices());
+}
+
+#[test]
+fn stroke_path_quads_cover_segments() {
+    let square = StrokePath::polygon(
+        vec![
+            Vec2::new(-10., -10.),
+            Vec2::new(10., -10.),
+            Vec2::new(10., 10.),
+            Vec2::new(-10., 10.),
+        ],
+        2.,
+        Color::WHITE,
+    );
+    let quads = square.quads();
+    assert_eq!(quads.len(), 4);
+    // Segments are extended into the joins so the corners are filled
+    assert_rect_near(
+        quads[0].axis_aligned_rect().unwrap(),
+        Rect::new(-11., -11., 11., -9.),
+    );
+
+    let diagonal = StrokePath::polyline(vec![Vec2::ZERO, Vec2::new(10., 10.)], 2., Color::WHITE);
+    let quads = diagonal.quads();
+    assert_eq!(quads.len(), 1);
+    assert!(quads[0].axis_aligned_rect().is_none());
+    assert!((quads[0].angle - std::f32::consts::FRAC_PI_4).abs() < EPSILON);
+    assert!((quads[0].size.x - 200f32.sqrt()).abs() < EPSILON);
+}
+
+#[test]
+fn ellipse_ring_strips_stay_between_ellipses() {
+    let inner = Rect::new(-20., -20., 20., 20.);
+    let outer = Rect::new(-24., -24., 24., 24.);
+    let strips = ellipse_ring_strips(outer, inner);
+    assert!(!strips.is_empty());
+    for strip in strips {
+        let y = strip.center().y;
+        // Every strip lies inside the outer circle and outside the inner one
+        for x in [strip.min.x, strip.max.x] {
+            let distance = Vec2::new(x, y).length();
+            assert!(distance <= 24. + EPSILON);
+            assert!(20. - EPSILON <= distance);
+        }
+    }
+    // The middle row spans the full thickness on both sides
+    let middle: Vec<Rect> = ellipse_ring_strips(outer, inner)
+        .into_iter()
+        .filter(|strip| strip.min.y == 0.)
+        .collect();
+    assert_eq!(middle.len(), 2);
+    assert!((middle[0].width() - 4.).abs() < 0.1);
+}
+
+/// Asserts that the instanced border quads don't overlap and cover every point of the ring between `outer` and
+/// `inner`, returning whether any point is covered only by an inner corner quad.
+#[track_caller]
+fn assert_frame_quads_cover_ring(
+    outer: &bevy_ui_borders::geometry::RoundedRect,
+    inner: &bevy_ui_borders::geometry::RoundedRect,
+) -> bool {
+    use bevy_ui_borders::geometry::*;
+
+    let quads = frame_quads(outer.rect, inner);
+    for (i, a) in quads.iter().enumerate() {
+        for b in &quads[i + 1..] {
+            assert!(a.intersect(*b).is_empty(), "{a:?} overlaps {b:?}");
+        }
+    }
+    let inside = |shape: &RoundedRect, point: Vec2| {
+        shape
+            .span_at(point.y)
+            .is_some_and(|(x0, x1)| x0 < point.x && point.x < x1)
+    };
+    let mut needs_corner_quads = false;
+    let size = outer.rect.size();
+    for y in 0..(4. * size.y) as usize {
+        for x in 0..(4. * size.x) as usize {
+            let point = outer.rect.min + Vec2::new(x as f32 + 0.5, y as f32 + 0.5) / 4.;
+            if !inside(outer, point) || inside(inner, point) {
+                continue;
+            }
+            let quad = quads.iter().position(|quad| quad.contains(point));
+            assert!(quad.is_some(), "{point} is not covered");
+            needs_corner_quads |= quad.is_some_and(|quad| 4 <= quad);
+        }
+    }
+    needs_corner_quads
+}
+
+#[test]
+fn instanced_frame_quads_cover_rounded_rings() {
+    use bevy_ui_borders::geometry::*;
+
+    // A rounded border
+    let outer = RoundedRect::new(Rect::new(-50., -25., 50., 25.), [20., 10., 0., 25.]);
+    let inner = outer.inset([4., 6., 3., 5.]);
+    assert!(assert_frame_quads_cover_ring(&outer, &inner));
+
+    // An outline around a rounded node, whose inner shape is the node's own
+    let inner = RoundedRect::new(Rect::new(-30., -20., 30., 20.), [12.; 4]);
+    let outer = inner.outset([4.; 4]);
+    assert!(assert_frame_quads_cover_ring(&outer, &inner));
+
+    // A pill, whose corner quads meet in the middle
+    let inner = RoundedRect::new(Rect::new(-10., -5., 10., 5.), [5.; 4]);
+    let outer = inner.outset([3., 3., 2., 2.]);
+    assert!(assert_frame_quads_cover_ring(&outer, &inner));
+
+    // Square frames are covered by their edges
+    let outer = RoundedRect::new(Rect::new(-10., -10., 10., 10.), [0.; 4]);
+    let inner = outer.inset([2.; 4]);
+    assert!(!assert_frame_quads_cover_ring(&outer, &inner));
+}
+
+#[test]
+fn polygon_border_shape_fits_node() {
+    let rect = Rect::new(-50., -40., 50., 40.);
+    let points = BorderShape::hexagon().polygon_points(rect).unwrap();
+    assert_eq!(points.len(), 6);
+    // The first corner points up and the polygon fills the rect
+    assert!((points[0] - Vec2::new(0., -40.)).length() < EPSILON);
+    assert!((points[3] - Vec2::new(0., 40.)).length() < EPSILON);
+    assert!(points.iter().any(|point| (point.x - 50.).abs() < EPSILON));
+    assert!(points.iter().any(|point| (point.x + 50.).abs() < EPSILON));
+
+    let stroke = BorderShape::hexagon()
+        .stroke(Vec2::new(100., 80.), 4., Color::WHITE)
+        .unwrap();
+    assert_eq!(stroke.quads().len(), 6);
+    assert!(BorderShape::Rectangle.polygon_points(rect).is_none());
+    assert!(BorderShape::Polygon {
+        sides: 2,
+        rotation: 0.
+    }
+    .polygon_points(rect)
+    .is_none());
+}
+
+#[test]
+fn chamfered_corners_cut_at_45_degrees() {
+    let shape = RoundedRect::chamfered(Rect::new(0., 0., 100., 50.), [10.; 4]);
+    assert!(shape.is_rounded());
+    let (left, right) = shape.span_at(2.).unwrap();
+    assert!((left - 8.).abs() < EPSILON);
+    assert!((right - 92.).abs() < EPSILON);
+    assert_eq!(shape.span_at(25.), Some((0., 100.)));
+
+    // The inner bevel of a border stays parallel to the outer one
+    let inner = shape.inset([4.; 4]);
+    assert!(inner.chamfered);
+    let expected = 10. - (2. - std::f32::consts::SQRT_2) * 4.;
+    assert!((inner.radii[0] - expected).abs() < EPSILON);
+
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let child = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    let parent = spawn_node(&mut app, Vec2::new(100., 100.), UiRect::all(Val::Px(4.)));
+    app.world
+        .entity_mut(parent)
+        .insert(CornerStyle::Chamfer(Val::Px(20.)))
+        .add_child(child);
+    app.world.get_mut::<Style>(parent).unwrap().overflow = Overflow::Hidden;
+    update(&mut app);
+    let clip = app.world.get::<RoundedClip>(child).unwrap();
+    assert_eq!(clip.shapes.len(), 1);
+    assert!(clip.shapes[0].chamfered);
+}
+
+#[test]
+fn border_spans_trim_edges() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert(BorderSpans::new(
+        EdgeSpan::new(0., 0.2),
+        EdgeSpan::EMPTY,
+        EdgeSpan::new(0., 0.2),
+        EdgeSpan::centered(0.6),
+    ));
+    update(&mut app);
+
+    let border = calculated_border(&app, node);
+    // A bracket in the top left corner and the middle 60% of the bottom edge
+    assert_edges_near(
+        border.edges,
+        [
+            Some(Rect::new(-50., -25., -48., -15.)),
+            None,
+            Some(Rect::new(-48., -25., -30., -23.)),
+            Some(Rect::new(-30., 23., 30., 25.)),
+        ],
+    );
+    assert_rect_near(border.inner, Rect::new(-48., -23., 48., 23.));
+}
+
+#[test]
+fn corner_brackets_cover_the_ends_of_each_edge() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world.entity_mut(node).insert(Border {
+        style: BorderStyle::CornerBrackets { length: 10. },
+        ..Border::all(Val::Px(2.), Color::WHITE)
+    });
+    update(&mut app);
+
+    // Brackets keep the border's space in the layout
+    let border = calculated_border(&app, node);
+    assert_eq!(border.resolved, [2.; 4]);
+
+    let rect = Rect::new(-50., -25., 50., 25.);
+    let [start, end] = BorderSpans::corner_brackets(rect.size(), 10.);
+    assert_edges_near(
+        start.trim(rect, border.edges),
+        [
+            Some(Rect::new(-50., -25., -48., -15.)),
+            Some(Rect::new(48., -25., 50., -15.)),
+            Some(Rect::new(-48., -25., -40., -23.)),
+            Some(Rect::new(-48., 23., -40., 25.)),
+        ],
+    );
+    assert_edges_near(
+        end.trim(rect, border.edges),
+        [
+            Some(Rect::new(-50., 15., -48., 25.)),
+            Some(Rect::new(48., 15., 50., 25.)),
+            Some(Rect::new(40., -25., 48., -23.)),
+            Some(Rect::new(40., 23., 48., 25.)),
+        ],
+    );
+
+    // Brackets longer than half a side meet in the middle
+    let [start, end] = BorderSpans::corner_brackets(rect.size(), 40.);
+    assert_eq!(start.top.end, 0.4);
+    assert_eq!(start.left.end, 0.5);
+    assert_eq!(end.left.start, 0.5);
+}
+
+#[test]
+fn tab_indicator_underlines_active_tab() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let tabs = [
+        spawn_node(&mut app, Vec2::new(100., 40.), UiRect::default()),
+        spawn_node(&mut app, Vec2::new(100., 40.), UiRect::default()),
+    ];
+    let bar = spawn_node(&mut app, Vec2::new(200., 40.), UiRect::bottom(Val::Px(2.)));
+    app.world.entity_mut(bar).push_children(&tabs).insert({
+        let mut indicator = TabIndicator::new(tabs.to_vec());
+        indicator.duration = 0.;
+        indicator
+    });
+    update(&mut app);
+    update(&mut app);
+
+    let bottom = |app: &App| calculated_border(app, bar).edges[3];
+    assert_rect_near(bottom(&app).unwrap(), Rect::new(-100., 18., 0., 20.));
+
+    app.world.get_mut::<TabIndicator>(bar).unwrap().select(1);
+    update(&mut app);
+    assert_rect_near(bottom(&app).unwrap(), Rect::new(0., 18., 100., 20.));
+
+    app.world.get_mut::<TabIndicator>(bar).unwrap().active = None;
+    update(&mut app);
+    assert!(bottom(&app).is_none());
+}
+
+#[test]
+fn split_screen_roots_keep_their_own_geometry() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let mut halves = Vec::new();
+    for i in 0..2 {
+        let root = spawn_node(&mut app, Vec2::new(400., 600.), UiRect::all(Val::Px(4.)));
+        let panel = spawn_node(&mut app, Vec2::new(100., 20.), UiRect::all(Val::Px(2.)));
+        app.world
+            .entity_mut(panel)
+            .insert(GlobalBorderGeometry::default());
+        let mut style = app.world.get_mut::<Style>(root).unwrap();
+        style.position_type = PositionType::Absolute;
+        style.position = UiRect::left(Val::Px(400. * i as f32));
+        style.overflow = if i == 0 {
+            Overflow::Hidden
+        } else {
+            Overflow::Visible
+        };
+        app.world
+            .entity_mut(root)
+            .insert(BorderRadius::all(Val::Px(10.)))
+            .add_child(panel);
+        halves.push((root, panel));
+    }
+    update(&mut app);
+
+    for (i, (_, panel)) in halves.iter().enumerate() {
+        let half = Rect::new(400. * i as f32, 0., 400. * (i + 1) as f32, 600.);
+        let geometry = app.world.get::<GlobalBorderGeometry>(*panel).unwrap();
+        assert_eq!(geometry.outer.intersect(half), geometry.outer);
+        for edge in geometry.border_edges.into_iter().flatten() {
+            assert_eq!(edge.intersect(half), edge);
+        }
+    }
+    // Only the root that hides its overflow clips its own panel
+    let clip = app.world.get::<RoundedClip>(halves[0].1).unwrap();
+    assert_eq!(clip.shapes.len(), 1);
+    assert_rect_near(clip.shapes[0].rect, Rect::new(4., 4., 396., 596.));
+    assert!(app.world.get::<RoundedClip>(halves[1].1).is_none());
+}
+
+#[test]
+fn geometry_module_matches_calculated_border() {
+    use bevy_ui_borders::geometry::*;
+
+    let size = Vec2::new(100., 50.);
+    let thickness =
+        [10., 20., 30., 40.].map(|percent| resolve_thickness(Val::Percent(percent), 10.));
+    assert_eq!(thickness, [1., 2., 3., 4.]);
+    let (edges, inner) = border_edges(size, thickness);
+
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(
+        &mut app,
+        size,
+        UiRect::new(Val::Px(1.), Val::Px(2.), Val::Px(3.), Val::Px(4.)),
+    );
+    update(&mut app);
+    let border = calculated_border(&app, node);
+    assert_edges_near(border.edges, edges);
+    assert_rect_near(border.inner, inner);
+
+    // Borders thicker than the node don't overlap
+    let (edges, inner) = border_edges(Vec2::new(10., 10.), [8.; 4]);
+    assert_eq!(inner.size(), Vec2::ZERO);
+    assert!(edges[2].is_none() && edges[3].is_none());
+}
+
+#[test]
+fn contrast_outline_picks_light_or_dark() {
+    assert_eq!(light_or_dark(Color::NAVY), Color::WHITE);
+    assert_eq!(light_or_dark(Color::YELLOW), Color::BLACK);
+    // sRGB mid gray is lighter than the luminance where white and black contrast equally
+    assert_eq!(light_or_dark(Color::GRAY), Color::BLACK);
+}
+
+#[test]
+fn oklab_fades_keep_their_saturation() {
+    let (blue, yellow) = (Color::BLUE, Color::YELLOW);
+    for space in [ColorSpace::Srgb, ColorSpace::LinearRgb, ColorSpace::Oklab] {
+        for (t, expected) in [(0., blue), (1., yellow)] {
+            let [r, g, b, a] = space.lerp(blue, yellow, t).as_rgba_f32();
+            let [er, eg, eb, ea] = expected.as_rgba_f32();
+            for (value, expected) in [(r, er), (g, eg), (b, eb), (a, ea)] {
+                assert!(
+                    (value - expected).abs() < 1e-3,
+                    "{space:?} {value} {expected}"
+                );
+            }
+        }
+    }
+
+    // sRGB passes through gray, OKLab keeps some of the colors' saturation
+    let keyframes = |space| {
+        let mut keyframes = BorderKeyframes::new(KeyframeRepeat::Once)
+            .with(0., BorderProperty::BorderColor(blue), Easing::Linear)
+            .with(1., BorderProperty::BorderColor(yellow), Easing::Linear)
+            .with_color_space(space);
+        keyframes.elapsed = 0.5;
+        match keyframes.sample()[0] {
+            BorderProperty::BorderColor(color) => color.as_hsla_f32()[1],
+            property => panic!("{property:?}"),
+        }
+    };
+    assert!(keyframes(ColorSpace::Srgb) < 1e-3);
+    assert!(0.1 < keyframes(ColorSpace::Oklab));
+}
+
+#[test]
+fn physical_outline_units_ignore_scale_factor() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.world.resource_mut::<UiScale>().scale = 1.5;
+    let mut window_query = app.world.query::<&mut Window>();
+    window_query
+        .single_mut(&mut app.world)
+        .resolution
+        .set_scale_factor_override(Some(2.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world.entity_mut(node).insert((
+        Outline::thickness(UiRect::all(Val::Px(1.))),
+        OutlineUnits::Physical,
+    ));
+    update(&mut app);
+    let size = node_size(&app, node);
+    let outline = calculated_outline(&app, node);
+    // One physical pixel is half a logical pixel at a scale factor of 2
+    assert_rect_near(
+        outline.edges[0].unwrap(),
+        Rect::new(
+            -0.5 * size.x - 0.5,
+            -0.5 * size.y - 0.5,
+            -0.5 * size.x,
+            0.5 * size.y + 0.5,
+        ),
+    );
+
+    app.world.entity_mut(node).insert(OutlineUnits::Logical);
+    update(&mut app);
+    assert_rect_near(
+        calculated_outline(&app, node).edges[0].unwrap(),
+        Rect::new(
+            -0.5 * size.x - 1.,
+            -0.5 * size.y - 1.,
+            -0.5 * size.x,
+            0.5 * size.y + 1.,
+        ),
+    );
+}
+
+#[test]
+fn min_visible_thickness_keeps_thin_edges() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.insert_resource(MinVisibleThickness(1.));
+    let mut window_query = app.world.query::<&mut Window>();
+    window_query
+        .single_mut(&mut app.world)
+        .resolution
+        .set_scale_factor_override(Some(2.));
+    let node = spawn_node(
+        &mut app,
+        Vec2::new(100., 50.),
+        UiRect::new(Val::Px(0.1), Val::Px(2.), Val::Px(0.), Val::Px(0.2)),
+    );
+    app.world
+        .entity_mut(node)
+        .insert(Outline::thickness(UiRect::all(Val::Px(0.1))));
+    update(&mut app);
+    // Thin edges widen to one physical pixel, zero and thicker edges are unchanged
+    let border = calculated_border(&app, node);
+    assert_eq!(border.resolved, [0.5, 2., 0., 0.5]);
+    let outline = calculated_outline(&app, node);
+    assert!((outline.edges[0].unwrap().width() - 0.5).abs() < EPSILON);
+
+    app.insert_resource(BorderRounding::Floor);
+    update(&mut app);
+    assert_eq!(calculated_border(&app, node).resolved, [0.5, 2., 0., 0.5]);
+}
+
+#[test]
+fn border_on_sides_sets_style_border() {
+    assert_eq!(Sides::ALL.rect(Val::Px(3.)), UiRect::all(Val::Px(3.)));
+    assert_eq!(Sides::NONE.rect(Val::Px(3.)), UiRect::default());
+    assert_eq!(!Sides::LEFT, Sides::RIGHT | Sides::VERTICAL);
+    assert!((0..16).all(|bits| Sides::from_bits_truncate(bits).bits() == bits));
+
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world.entity_mut(node).insert(Border::on(
+        Sides::TOP | Sides::LEFT,
+        Val::Px(2.),
+        Color::RED,
+    ));
+    update(&mut app);
+    assert_eq!(
+        app.world.get::<Style>(node).unwrap().border,
+        UiRect::new(Val::Px(2.), Val::DEFAULT, Val::Px(2.), Val::DEFAULT)
+    );
+    assert_eq!(calculated_border(&app, node).resolved, [2., 0., 2., 0.]);
+}
+
+#[test]
+fn set_border_commands_keep_style_and_border_in_sync() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world
+        .entity_mut(node)
+        .insert(Border::all(Val::Px(2.), Color::WHITE));
+    update(&mut app);
+
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, &app.world)
+        .entity(node)
+        .set_border_thickness(UiRect::all(Val::Px(5.)))
+        .set_border_color(Color::RED);
+    queue.apply(&mut app.world);
+    update(&mut app);
+
+    let border = app.world.get::<Border>(node).unwrap();
+    assert_eq!(border.width, UiRect::all(Val::Px(5.)));
+    assert_eq!(border.color, Color::RED);
+    assert_eq!(
+        app.world.get::<Style>(node).unwrap().border,
+        UiRect::all(Val::Px(5.))
+    );
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+    assert_eq!(calculated_border(&app, node).resolved, [5.; 4]);
+}
+
+#[test]
+fn focus_and_selection_outlines_send_events() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let a = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    let b = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    app.world.entity_mut(a).insert((
+        Outline::all(Color::NONE, Val::Px(2.)),
+        FocusOutline::new(Color::RED).with_mode(FocusOutlineMode::Always),
+    ));
+    let mut shown = bevy::ecs::event::ManualEventReader::<OutlineShown>::default();
+    let mut hidden = bevy::ecs::event::ManualEventReader::<OutlineHidden>::default();
+    let mut read = |app: &App| {
+        let shown_events = app.world.resource::<Events<OutlineShown>>();
+        let hidden_events = app.world.resource::<Events<OutlineHidden>>();
+        (
+            shown
+                .iter(shown_events)
+                .map(|event| event.entity)
+                .collect::<Vec<_>>(),
+            hidden
+                .iter(hidden_events)
+                .map(|event| event.entity)
+                .collect::<Vec<_>>(),
+        )
+    };
+    update(&mut app);
+    assert_eq!(read(&app), (vec![], vec![]));
+
+    app.world.entity_mut(a).insert(Focused);
+    update(&mut app);
+    assert_eq!(read(&app), (vec![a], vec![]));
+    app.world.entity_mut(a).remove::<Focused>();
+    update(&mut app);
+    assert_eq!(read(&app), (vec![], vec![a]));
+
+    let mut cursor = SelectionCursor::default();
+    cursor.select(a);
+    app.insert_resource(cursor);
+    update(&mut app);
+    assert_eq!(read(&app), (vec![a], vec![]));
+    app.world.resource_mut::<SelectionCursor>().select(b);
+    update(&mut app);
+    assert_eq!(read(&app), (vec![b], vec![a]));
+}
+
+#[test]
+fn selection_cursor_restores_own_outline() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let a = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    let b = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    app.world
+        .entity_mut(a)
+        .insert(Outline::all(Color::RED, Val::Px(1.)));
+    let outline = |app: &App, entity| {
+        (
+            app.world
+                .get::<Outline>(entity)
+                .map(|outline| outline.thickness),
+            app.world.get::<OutlineColor>(entity).map(|color| color.0),
+        )
+    };
+
+    let mut cursor = SelectionCursor::new(UiRect::all(Val::Px(4.)), Color::WHITE);
+    cursor.transition = None;
+    cursor.select(a);
+    app.insert_resource(cursor);
+    update(&mut app);
+    assert_eq!(
+        outline(&app, a),
+        (Some(UiRect::all(Val::Px(4.))), Some(Color::WHITE))
+    );
+
+    app.world.resource_mut::<SelectionCursor>().select(b);
+    update(&mut app);
+    assert_eq!(
+        outline(&app, a),
+        (Some(UiRect::all(Val::Px(1.))), Some(Color::RED))
+    );
+
+    // With a transition the cursor's outline fades out before the node's own outline comes back
+    app.world.resource_mut::<SelectionCursor>().transition = Some((0., Easing::Linear));
+    app.world.resource_mut::<SelectionCursor>().select(a);
+    // A frame to start the fade, one to finish it and one to restore the outline
+    update(&mut app);
+    app.update();
+    assert_eq!(
+        outline(&app, a),
+        (Some(UiRect::all(Val::Px(4.))), Some(Color::WHITE))
+    );
+    assert_eq!(outline(&app, b), (None, None));
+    app.world.resource_mut::<SelectionCursor>().clear();
+    update(&mut app);
+    app.update();
+    assert_eq!(
+        outline(&app, a),
+        (Some(UiRect::all(Val::Px(1.))), Some(Color::RED))
+    );
+}
+
+#[test]
+fn selection_cursor_keeps_running_animations() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    app.world.entity_mut(node).insert((
+        BorderColor(Color::RED),
+        BorderAnimation::new(
+            BorderProperty::BorderColor(Color::BLUE),
+            10.,
+            Easing::Linear,
+        ),
+    ));
+    let mut cursor = SelectionCursor::new(UiRect::all(Val::Px(4.)), Color::WHITE);
+    cursor.transition = Some((10., Easing::Linear));
+    cursor.select(node);
+    app.insert_resource(cursor);
+    update(&mut app);
+
+    let targets = app
+        .world
+        .get::<BorderAnimation>(node)
+        .unwrap()
+        .transitions
+        .iter()
+        .map(|transition| transition.target)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        targets,
+        vec![
+            BorderProperty::BorderColor(Color::BLUE),
+            BorderProperty::OutlineColor(Color::WHITE)
+        ]
+    );
+}
+
+#[test]
+fn navigation_hints_point_away_from_outline() {
+    let outline = CalculatedOutline {
+        edges: [
+            Some(Rect::new(-52., -27., -50., 27.)),
+            Some(Rect::new(50., -27., 52., 27.)),
+            Some(Rect::new(-50., -27., 50., -25.)),
+            Some(Rect::new(-50., 25., 50., 27.)),
+        ],
+        ..Default::default()
+    };
+    let context = DecorationContext {
+        entity: Entity::PLACEHOLDER,
+        size: Vec2::new(100., 50.),
+        parent_width: 800.,
+        border: None,
+        outline: Some(&outline),
+        radius: None,
+        corner_style: None,
+    };
+    let hints = NavigationHints::new(Sides::TOP | Sides::RIGHT, Color::WHITE);
+    assert_eq!(
+        hints.tips(&context),
+        vec![Vec2::new(0., -33.), Vec2::new(58., 0.)]
+    );
+
+    let mut quads = Vec::new();
+    hints.decorate(&context, &mut quads);
+    let outer = context.outer_rect();
+    assert!(!quads.is_empty());
+    for quad in quads {
+        assert!(quad.rect.intersect(outer).is_empty(), "{quad:?}");
+        assert!(quad.rect.min.y >= -33. && quad.rect.max.x <= 58.);
+    }
+}
+
+#[test]
+fn outline_segments_leave_gaps_at_edge_midpoints() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    app.world
+        .entity_mut(node)
+        .insert(Outline::all(Color::WHITE, Val::Px(2.)));
+    update(&mut app);
+    let outline = calculated_outline(&app, node);
+
+    // The outer rect is 104 by 54, so a 50% gap is 52 wide on the top and bottom and 27 tall on the sides
+    let segments = OutlineSegments::gapped(0.5).split(outline.edges);
+    assert_eq!(segments.len(), 8);
+    assert_rect_near(segments[0], Rect::new(-52., -27., -50., -13.5));
+    assert_rect_near(segments[1], Rect::new(-52., 13.5, -50., 27.));
+    assert_rect_near(segments[4], Rect::new(-50., -27., -26., -25.));
+    assert_rect_near(segments[5], Rect::new(26., -27., 50., -25.));
+
+    let dashes = OutlineSegments::dashed(3);
+    assert_eq!(dashes.top.len(), 3);
+    assert!((dashes.top[2].end - 1.).abs() < EPSILON);
+    assert_eq!(dashes.split(outline.edges).len(), 12);
+}
+
+#[test]
+fn decoration_rects_are_placed_from_top_left() {
+    let context = DecorationContext {
+        entity: Entity::PLACEHOLDER,
+        size: Vec2::new(100., 50.),
+        parent_width: 800.,
+        border: None,
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let rects = DecorationRects::default()
+        .with(DecorationRect::new(
+            Vec2::ZERO,
+            Vec2::new(4., 50.),
+            Color::ORANGE,
+        ))
+        .with(DecorationRect::new(
+            Vec2::new(48., -6.),
+            Vec2::new(4., 6.),
+            Color::WHITE,
+        ));
+    let mut quads = Vec::new();
+    rects.decorate(&context, &mut quads);
+    assert_eq!(
+        quads,
+        vec![
+            DecorationQuad {
+                rect: Rect::new(-50., -25., -46., 25.),
+                color: Color::ORANGE,
+            },
+            DecorationQuad {
+                rect: Rect::new(-2., -31., 2., -25.),
+                color: Color::WHITE,
+            },
+        ]
+    );
+}
+
+#[test]
+fn accent_bar_follows_border_edge_math() {
+    let context = DecorationContext {
+        entity: Entity::PLACEHOLDER,
+        size: Vec2::new(100., 50.),
+        parent_width: 800.,
+        border: None,
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let mut quads = Vec::new();
+    AccentBar::new(Sides::LEFT, Val::Px(6.), Color::ORANGE).decorate(&context, &mut quads);
+    assert_eq!(
+        quads,
+        vec![DecorationQuad {
+            rect: Rect::new(-50., -25., -44., 25.),
+            color: Color::ORANGE,
+        }]
+    );
+
+    quads.clear();
+    AccentBar::new(Sides::TOP, Val::Percent(1.), Color::ORANGE).decorate(&context, &mut quads);
+    assert_eq!(quads.len(), 1);
+    assert_rect_near(quads[0].rect, Rect::new(-50., -25., 50., -17.));
+}
+
+#[test]
+fn scroll_indicator_follows_hidden_content() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let frame = spawn_node(&mut app, Vec2::new(100., 100.), UiRect::all(Val::Px(2.)));
+    let list = spawn_node(&mut app, Vec2::new(96., 300.), UiRect::default());
+    app.world
+        .entity_mut(frame)
+        .insert(ScrollIndicatorBorder::new(Color::GOLD))
+        .push_children(&[list]);
+    update(&mut app);
+    let indicator = *app.world.get::<ScrollIndicatorBorder>(frame).unwrap();
+    assert!(!indicator.more_above() && indicator.more_below());
+
+    app.world.get_mut::<Style>(list).unwrap().position.top = Val::Px(-100.);
+    update(&mut app);
+    let indicator = *app.world.get::<ScrollIndicatorBorder>(frame).unwrap();
+    assert!(indicator.more_above() && indicator.more_below());
+
+    app.world.get_mut::<Style>(list).unwrap().position.top = Val::Px(-204.);
+    update(&mut app);
+    let indicator = *app.world.get::<ScrollIndicatorBorder>(frame).unwrap();
+    assert!(indicator.more_above() && !indicator.more_below());
+
+    // The indicator is drawn over the frame's border edge
+    let border = calculated_border(&app, frame);
+    let context = DecorationContext {
+        entity: frame,
+        size: Vec2::new(100., 100.),
+        parent_width: 800.,
+        border: Some(&border),
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let mut quads = Vec::new();
+    indicator.decorate(&context, &mut quads);
+    assert_eq!(
+        quads,
+        vec![DecorationQuad {
+            rect: border.edges[2].unwrap(),
+            color: Color::GOLD,
+        }]
+    );
+}
+
+#[test]
+fn splitter_drag_resizes_first_pane() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.init_resource::<Input<MouseButton>>();
+    let pane = spawn_node(&mut app, Vec2::new(200., 100.), UiRect::right(Val::Px(4.)));
+    app.world
+        .entity_mut(pane)
+        .insert(Splitter::new(SplitAxis::Horizontal).with_preview(Color::WHITE));
+    update(&mut app);
+
+    let move_cursor = |app: &mut App, x: f32| {
+        let mut window_query = app.world.query::<&mut Window>();
+        let mut window = window_query.single_mut(&mut app.world);
+        // Bevy 0.10 measures the cursor from the bottom of the window
+        let y = window.height() - 50.;
+        window.set_cursor_position(Some(Vec2::new(x, y)));
+    };
+    move_cursor(&mut app, 198.);
+    app.world
+        .resource_mut::<Input<MouseButton>>()
+        .press(MouseButton::Left);
+    app.update();
+    assert!(app.world.get::<Splitter>(pane).unwrap().is_dragging());
+
+    // The preview follows the cursor while the pane keeps its size
+    move_cursor(&mut app, 258.);
+    app.world.resource_mut::<Input<MouseButton>>().clear();
+    update(&mut app);
+    assert_eq!(
+        app.world.get::<Splitter>(pane).unwrap().pending_size(),
+        Some(260.)
+    );
+    assert_eq!(node_size(&app, pane).x, 200.);
+
+    app.world
+        .resource_mut::<Input<MouseButton>>()
+        .release(MouseButton::Left);
+    update(&mut app);
+    assert!(!app.world.get::<Splitter>(pane).unwrap().is_dragging());
+    assert_eq!(node_size(&app, pane).x, 260.);
+}
+
+#[test]
+fn hover_elevation_raises_and_restores() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert((
+        Interaction::default(),
+        Outline::all(Color::WHITE, Val::Px(1.)),
+        OutlineShadow::new(Color::BLACK, 4.),
+        HoverElevation::new(2., 0.5, 6.).with_duration(0.),
+    ));
+    app.world.get_mut::<BorderColor>(node).unwrap().0 = Color::BLACK;
+    update(&mut app);
+
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(
+        app.world.get::<HoverElevation>(node).unwrap().elevation(),
+        1.
+    );
+    assert_eq!(
+        app.world.get::<Outline>(node).unwrap().thickness,
+        UiRect::all(Val::Px(3.))
+    );
+    let color = app.world.get::<BorderColor>(node).unwrap().0;
+    assert!((color.r() - 0.5).abs() < EPSILON);
+    assert_eq!(app.world.get::<OutlineShadow>(node).unwrap().blur, 10.);
+
+    *app.world.get_mut::<Interaction>(node).unwrap() = Interaction::None;
+    app.update();
+    assert_eq!(
+        app.world.get::<Outline>(node).unwrap().thickness,
+        UiRect::all(Val::Px(1.))
+    );
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::BLACK);
+    assert_eq!(app.world.get::<OutlineShadow>(node).unwrap().blur, 4.);
+}
+
+#[test]
+fn disabled_nodes_are_dashed_gray_and_not_highlighted() {
+    let style = DisabledBorder::default();
+    let gray = style.color(Color::RED);
+    assert!((gray.r() - gray.g()).abs() < EPSILON && (gray.g() - gray.b()).abs() < EPSILON);
+    assert_eq!(gray.a(), 0.5);
+
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert((
+        Interaction::Hovered,
+        Disabled,
+        PickHighlight::all(HighlightStyle::border(Color::RED)),
+    ));
+    update(&mut app);
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::WHITE);
+
+    // Five dashes and four gaps of equal length along each of the four edges
+    let dashes = style.edges(calculated_border(&app, node).edges);
+    assert_eq!(dashes.len(), 20);
+    assert_rect_near(dashes[0], Rect::new(-50., -25., -48., -25. + 50. / 9.));
+
+    app.world.entity_mut(node).remove::<Disabled>();
+    app.update();
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+}
+
+#[test]
+fn validation_state_colors_from_palette_or_style() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.insert_resource(ValidationStyle::default().with_icons(6., BadgeCorner::TopRight));
+    app.world
+        .resource_mut::<BorderPalette>()
+        .insert("error", Color::CRIMSON);
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert((
+        ValidationState::Warning,
+        Outline::all(Color::WHITE, Val::Px(1.)),
+    ));
+    update(&mut app);
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::ORANGE);
+    assert_eq!(
+        app.world.get::<OutlineColor>(node).unwrap().0,
+        Color::ORANGE
+    );
+    let icon = app.world.get::<ValidationIcon>(node).unwrap();
+    assert_eq!(icon.state, ValidationState::Warning);
+    assert_eq!(icon.radius, 6.);
+
+    *app.world.get_mut::<ValidationState>(node).unwrap() = ValidationState::Error;
+    app.update();
+    assert_eq!(
+        app.world.get::<BorderColor>(node).unwrap().0,
+        Color::CRIMSON
+    );
+
+    app.world.entity_mut(node).remove::<ValidationState>();
+    update(&mut app);
+    assert!(app.world.get::<ValidationIcon>(node).is_none());
+}
+
+#[test]
+fn caret_and_selection_are_placed_inside_the_border() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    update(&mut app);
+    let border = calculated_border(&app, node);
+    let context = DecorationContext {
+        entity: node,
+        size: Vec2::new(100., 50.),
+        parent_width: 800.,
+        border: Some(&border),
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+
+    let mut caret = TextCaret::new(16., Color::WHITE);
+    caret.move_to(Vec2::new(10., 4.));
+    let mut quads = Vec::new();
+    caret.decorate(&context, &mut quads);
+    assert_rect_near(quads[0].rect, Rect::new(-38., -19., -37., -3.));
+
+    // The second rect runs past the right side of the border and is clipped
+    let selection = SelectionHighlight::new(Color::BLUE)
+        .with(Vec2::new(10., 4.), Vec2::new(30., 16.))
+        .with(Vec2::new(0., 20.), Vec2::new(200., 16.));
+    quads.clear();
+    selection.decorate(&context, &mut quads);
+    assert_rect_near(quads[0].rect, Rect::new(-38., -19., -8., -3.));
+    assert_rect_near(quads[1].rect, Rect::new(-48., -3., 48., 13.));
+}
+
+#[test]
+fn border_ticks_are_spaced_along_inner_edges() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    update(&mut app);
+    let border = calculated_border(&app, node);
+    let context = DecorationContext {
+        entity: node,
+        size: Vec2::new(100., 50.),
+        parent_width: 800.,
+        border: Some(&border),
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+
+    // The inner rect is 96 wide and 46 tall
+    let ticks =
+        BorderTicks::new(Sides::BOTTOM | Sides::LEFT, 10., 4., Color::WHITE).ticks(&context);
+    assert_eq!(ticks.len(), 5 + 10);
+    assert_rect_near(ticks[0], Rect::new(-48., -23., -44., -22.));
+    assert_rect_near(ticks[5], Rect::new(-48., 19., -47., 23.));
+    assert_rect_near(ticks[14], Rect::new(42., 19., 43., 23.));
+}
+
+#[test]
+fn progress_fill_covers_fraction_of_inner_rect() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let bar = app
+        .world
+        .spawn(
+            ProgressFrameBundle::new(
+                Size::new(Val::Px(104.), Val::Px(24.)),
+                Val::Px(2.),
+                Color::WHITE,
+                Color::RED,
+            )
+            .with_progress(0.25),
+        )
+        .id();
+    update(&mut app);
+    update(&mut app);
+    let fill = app.world.get::<Children>(bar).unwrap()[0];
+    assert!(app.world.get::<ProgressFillNode>(fill).is_some());
+    assert_eq!(
+        app.world.get::<BackgroundColor>(fill).unwrap().0,
+        Color::RED
+    );
+    assert!((node_size(&app, fill) - Vec2::new(25., 20.)).length() < EPSILON);
+    // The fill starts at the inside of the border's left edge
+    let center = |entity| {
+        app.world
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation()
+            .truncate()
+    };
+    assert!((center(fill) - center(bar) - Vec2::new(-37.5, 0.)).length() < EPSILON);
+
+    app.world.get_mut::<Progress>(bar).unwrap().0 = 2.;
+    update(&mut app);
+    assert!((node_size(&app, fill) - Vec2::new(100., 20.)).length() < EPSILON);
+}
+
+#[test]
+fn segmented_overlay_divides_inner_rect_and_progress_fill() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let bar = app
+        .world
+        .spawn((
+            ProgressFrameBundle::new(
+                Size::new(Val::Px(104.), Val::Px(24.)),
+                Val::Px(2.),
+                Color::WHITE,
+                Color::RED,
+            )
+            .with_progress(0.4),
+            SegmentedOverlay::new(4, 2., Color::BLACK),
+        ))
+        .id();
+    update(&mut app);
+    update(&mut app);
+    let border = calculated_border(&app, bar);
+    let context = DecorationContext {
+        entity: bar,
+        size: Vec2::new(104., 24.),
+        parent_width: 800.,
+        border: Some(&border),
+        outline: None,
+        radius: None,
+        corner_style: None,
+    };
+    let dividers = app
+        .world
+        .get::<SegmentedOverlay>(bar)
+        .unwrap()
+        .dividers(&context);
+    assert_eq!(dividers.len(), 3);
+    assert_rect_near(dividers[0], Rect::new(-26., -10., -24., 10.));
+    assert_rect_near(dividers[2], Rect::new(24., -10., 26., 10.));
+
+    // The fill covers 40% of the track, so only the first divider falls inside it
+    let fill = app.world.get::<Children>(bar).unwrap()[0];
+    let fill_context = DecorationContext {
+        entity: fill,
+        size: node_size(&app, fill),
+        border: None,
+        ..context
+    };
+    let fill_dividers = app
+        .world
+        .get::<SegmentedOverlay>(fill)
+        .unwrap()
+        .dividers(&fill_context);
+    assert_eq!(fill_dividers.len(), 1);
+    assert_rect_near(fill_dividers[0], Rect::new(4., -10., 6., 10.));
+}
+
+#[test]
+fn screen_frame_covers_viewport_edges() {
+    let frame = ScreenFrame::solid(Val::Px(10.), Color::RED);
+    let quads = frame.quads(Vec2::new(800., 600.));
+    assert_eq!(quads.len(), 4);
+    assert_rect_near(quads[0].rect, Rect::new(0., 0., 10., 600.));
+    assert_rect_near(quads[1].rect, Rect::new(790., 0., 800., 600.));
+    assert_rect_near(quads[2].rect, Rect::new(10., 0., 790., 10.));
+    assert_rect_near(quads[3].rect, Rect::new(10., 590., 790., 600.));
+
+    // The fade starts inside the solid frame and its rings grow more transparent towards the middle
+    let quads = frame.with_fade(8.).quads(Vec2::new(800., 600.));
+    assert_rect_near(quads[4].rect, Rect::new(10., 10., 11., 590.));
+    assert!(quads[4].color.a() < 1.);
+    assert!(quads.last().unwrap().color.a() < quads[4].color.a());
+    assert!(ScreenFrame::vignette(8., Color::NONE)
+        .quads(Vec2::new(800., 600.))
+        .is_empty());
+}
+
+#[test]
+fn letterbox_bars_force_aspect_ratio() {
+    let letterbox = Letterbox::new(2., Color::BLACK);
+
+    // A 4:3 window gets bars above and below
+    let view = letterbox.view(Vec2::new(800., 600.));
+    assert_rect_near(view, Rect::new(0., 100., 800., 500.));
+    let bars = letterbox.bars(Vec2::new(800., 600.));
+    assert_eq!(bars.len(), 2);
+    assert_rect_near(bars[0], Rect::new(0., 0., 800., 100.));
+    assert_rect_near(bars[1], Rect::new(0., 500., 800., 600.));
+
+    // A wider window gets bars to the sides
+    let bars = letterbox.bars(Vec2::new(1000., 400.));
+    assert_eq!(bars.len(), 2);
+    assert_rect_near(bars[0], Rect::new(0., 0., 100., 400.));
+    assert_rect_near(bars[1], Rect::new(900., 0., 1000., 400.));
+}
+
+#[test]
+fn safe_area_insets_move_root_borders_and_screen_frame() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    app.insert_resource(SafeAreaInsets::new(10., 0., 20., 0.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world.entity_mut(node).insert(SafeAreaBorder);
+    update(&mut app);
+
+    // The node sits in the top left corner, so its border is drawn around the part below and right of the insets
+    let border = calculated_border(&app, node);
+    assert_rect_near(border.edges[0].unwrap(), Rect::new(-40., -5., -38., 25.));
+    assert_rect_near(border.edges[2].unwrap(), Rect::new(-38., -5., 48., -3.));
+    assert_rect_near(border.inner, Rect::new(-38., -3., 48., 23.));
+
+    app.world.entity_mut(node).remove::<SafeAreaBorder>();
+    update(&mut app);
+    assert_rect_near(
+        calculated_border(&app, node).inner,
+        Rect::new(-48., -23., 48., 23.),
+    );
+
+    let safe_rect = SafeAreaInsets::new(10., 0., 20., 0.).safe_rect(Vec2::new(800., 600.));
+    let quads = ScreenFrame::solid(Val::Px(4.), Color::RED)
+        .with_safe_area()
+        .quads_around(safe_rect);
+    assert_rect_near(quads[0].rect, Rect::new(10., 20., 14., 600.));
+}
+
+#[cfg(feature = "report")]
+#[test]
+fn geometry_report_lists_window_space_edges() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(2.)));
+    app.world
+        .entity_mut(node)
+        .insert((Name::new("panel"), Outline::all(Color::RED, Val::Px(1.))));
+    update(&mut app);
+
+    let report = GeometryReport::from_world(&mut app.world);
+    assert_eq!(report.nodes.len(), 1);
+    let panel = &report.nodes[0];
+    assert_eq!(panel.entity, node.to_bits());
+    assert_eq!(panel.name.as_deref(), Some("panel"));
+    assert_eq!(panel.node.max, [100., 50.]);
+    let border = panel.border.as_ref().unwrap();
+    assert_eq!(border.edges[0].unwrap().max, [2., 50.]);
+    assert_eq!(border.color, Some([1., 1., 1., 1.]));
+    let outline = panel.outline.as_ref().unwrap();
+    assert_eq!(outline.edges[0].unwrap().min, [-1., -1.]);
+    assert_eq!(outline.color, Some([1., 0., 0., 1.]));
+
+    let json = report.to_json();
+    assert!(json.contains("\"name\": \"panel\""));
+}
+
+#[test]
+fn radius_animates_between_corner_values() {
+    let start = BorderRadius::new(Val::Px(0.), Val::Px(10.), Val::Percent(10.), Val::Px(4.));
+    let end = BorderRadius::new(
+        Val::Px(20.),
+        Val::Px(0.),
+        Val::Percent(30.),
+        Val::Percent(50.),
+    );
+    let halfway = BorderRadius::new(Val::Px(10.), Val::Px(5.), Val::Percent(20.), Val::Px(4.));
+    assert_eq!(
+        BorderProperty::Radius(start).lerp(BorderProperty::Radius(end), 0.5),
+        BorderProperty::Radius(halfway)
+    );
+    let mut radius = start;
+    BorderRadiusLens { start, end }.lerp(&mut radius, 0.5);
+    assert_eq!(radius, halfway);
+
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::all(Val::Px(4.)));
+    app.world.entity_mut(node).insert((
+        start,
+        BorderAnimation::new(BorderProperty::Radius(end), 0., Easing::Linear),
+    ));
+    update(&mut app);
+    assert_eq!(*app.world.get::<BorderRadius>(node).unwrap(), end);
+    assert!(app.world.get::<BorderAnimation>(node).is_none());
+}
+
+#[test]
+fn focus_visible_outline_follows_input_source() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    app.world.entity_mut(node).insert((
+        Outline::all(Color::NONE, Val::Px(2.)),
+        FocusOutline::new(Color::RED),
+        Focused,
+    ));
+    let outline_color = |app: &App| app.world.get::<OutlineColor>(node).unwrap().0;
+    update(&mut app);
+    assert_eq!(outline_color(&app), Color::NONE);
+
+    let mut keys = Input::<KeyCode>::default();
+    keys.press(KeyCode::Tab);
+    app.insert_resource(keys);
+    update(&mut app);
+    assert_eq!(
+        *app.world.resource::<FocusInputSource>(),
+        FocusInputSource::Keyboard
+    );
+    assert_eq!(outline_color(&app), Color::RED);
+
+    app.world.entity_mut(node).insert(Disabled);
+    update(&mut app);
+    assert_eq!(outline_color(&app), Color::NONE);
+    app.world.entity_mut(node).remove::<Disabled>();
+
+    // A click hides the outline again, even though the key is still held
+    let mut mouse_buttons = Input::<MouseButton>::default();
+    mouse_buttons.press(MouseButton::Left);
+    app.insert_resource(mouse_buttons);
+    update(&mut app);
+    assert_eq!(
+        *app.world.resource::<FocusInputSource>(),
+        FocusInputSource::Pointer
+    );
+    assert_eq!(outline_color(&app), Color::NONE);
+}
+
+#[test]
+fn focus_outline_without_outline_color_is_hidden_until_focused() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(50., 50.), UiRect::default());
+    app.world.entity_mut(node).insert((
+        BorderColor(Color::BLUE),
+        Outline::thickness(UiRect::all(Val::Px(2.))),
+        FocusOutline::new(Color::RED).with_mode(FocusOutlineMode::Always),
+    ));
+    update(&mut app);
+    // Without an inserted `OutlineColor` the outline would be drawn in the border's color
+    assert_eq!(
+        app.world.get::<OutlineColor>(node).map(|color| color.0),
+        Some(Color::NONE)
+    );
+
+    app.world.entity_mut(node).insert(Focused);
+    update(&mut app);
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::RED);
+}
+
+#[cfg(feature = "style_asset")]
+#[test]
+fn style_asset_changes_are_reapplied() {
+    let mut app = headless_app(Vec2::new(800., 600.));
+    let node = spawn_node(&mut app, Vec2::new(100., 50.), UiRect::default());
+    let style = app
+        .world
+        .resource_mut::<Assets<BorderStyleAsset>>()
+        .add(BorderStyleAsset {
+            border: Some(Thickness::All(Val::Px(2.))),
+            border_color: Some(Color::RED),
+            ..Default::default()
+        });
+    app.world
+        .entity_mut(node)
+        .insert(StyleHandle(style.clone()));
+    update(&mut app);
+    assert_eq!(calculated_border(&app, node).resolved, [2.; 4]);
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+
+    // Editing the asset, as a hot reload does, restyles the nodes using it
+    let mut assets = app.world.resource_mut::<Assets<BorderStyleAsset>>();
+    let asset = assets.get_mut(&style).unwrap();
+    asset.border = Some(Thickness::All(Val::Px(4.)));
+    asset.outline = Some(Thickness::All(Val::Px(1.)));
+    asset.outline_color = Some(Color::BLUE);
+    update(&mut app);
+    // The outline is only calculated in the frame after the restyle inserts it
+    app.update();
+    assert_eq!(calculated_border(&app, node).resolved, [4.; 4]);
+    assert_eq!(app.world.get::<BorderColor>(node).unwrap().0, Color::RED);
+    assert_eq!(app.world.get::<OutlineColor>(node).unwrap().0, Color::BLUE);
+    assert!(calculated_outline(&app, node).edges[0].is_some());
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_ui_borders::test_utils::*;
+use bevy_ui_borders::*;
+
+const WINDOW_SIZE: UVec2 = UVec2::new(64, 64);
+
+fn golden_path(name: &str) -> String {
+    format!(
+        "{}/tests/golden_images/{name}.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn render(spawn: impl FnOnce(&mut World)) -> TestImage {
+    let mut app = headless_app(WINDOW_SIZE.as_vec2());
+    spawn(&mut app.world);
+    update(&mut app);
+    render_scene(&mut app, WINDOW_SIZE)
+}
+
+fn centered_node(size: Vec2, border: UiRect) -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(size.x), Val::Px(size.y)),
+            margin: UiRect::all(Val::Auto),
+            border,
+            ..Default::default()
+        },
+        background_color: Color::NAVY.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn uniform_border() {
+    let image = render(|world| {
+        world.spawn((
+            centered_node(Vec2::new(32., 24.), UiRect::all(Val::Px(4.))),
+            BorderColor(Color::RED),
+        ));
+    });
+    assert_matches_golden(&image, golden_path("uniform_border"));
+}
+
+#[test]
+fn uneven_border() {
+    let image = render(|world| {
+        world.spawn((
+            centered_node(
+                Vec2::new(32., 24.),
+                UiRect::new(Val::Px(1.), Val::Px(3.), Val::Px(5.), Val::Px(7.)),
+            ),
+            BorderColor(Color::GREEN),
+        ));
+    });
+    assert_matches_golden(&image, golden_path("uneven_border"));
+}
+
+#[test]
+fn border_and_translucent_outline() {
+    let image = render(|world| {
+        world.spawn((
+            centered_node(Vec2::new(32., 24.), UiRect::all(Val::Px(2.))),
+            BorderColor(Color::WHITE),
+            Outline::all(Color::rgba(1., 1., 0., 0.5), Val::Px(6.)),
+        ));
+    });
+    assert_matches_golden(&image, golden_path("border_and_translucent_outline"));
+}